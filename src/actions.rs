@@ -0,0 +1,59 @@
+use crate::audit::{AuditLog, AuditResult};
+use crate::error::{AppError, Result};
+use unifi_rs::UnifiClient;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeviceAction {
+    Restart,
+}
+
+impl DeviceAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DeviceAction::Restart => "Restart",
+        }
+    }
+}
+
+pub async fn execute_device_action(
+    client: &UnifiClient,
+    site_id: Uuid,
+    device_id: Uuid,
+    action: DeviceAction,
+) -> Result<()> {
+    tracing::info!(
+        site_id = ?site_id,
+        device_id = ?device_id,
+        action = ?action,
+        "Executing device action"
+    );
+    match action {
+        DeviceAction::Restart => client
+            .restart_device(site_id, device_id)
+            .await
+            .map(|_| ())
+            .map_err(AppError::from),
+    }
+}
+
+/// Executes a device action and records its outcome in the audit log.
+pub async fn run_device_action(
+    client: UnifiClient,
+    audit: AuditLog,
+    site_id: Uuid,
+    device_id: Uuid,
+    device_name: String,
+    action: DeviceAction,
+) -> Result<()> {
+    let result = execute_device_action(&client, site_id, device_id, action).await;
+    audit.record(
+        action.label(),
+        &device_name,
+        match &result {
+            Ok(()) => AuditResult::Success,
+            Err(e) => AuditResult::Failed(e.to_string()),
+        },
+    );
+    result
+}
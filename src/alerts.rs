@@ -0,0 +1,220 @@
+use crate::state::AppState;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use unifi_rs::device::DeviceState;
+use uuid::Uuid;
+
+pub const CPU_ALERT_THRESHOLD: f64 = 90.0;
+pub const MEMORY_ALERT_THRESHOLD: f64 = 90.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlertKind {
+    DeviceOffline,
+    HighCpu,
+    HighMemory,
+}
+
+impl AlertKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AlertKind::DeviceOffline => "Offline",
+            AlertKind::HighCpu => "High CPU",
+            AlertKind::HighMemory => "High memory",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Alert {
+    pub kind: AlertKind,
+    pub device_id: Uuid,
+    pub site_id: Option<Uuid>,
+    pub message: String,
+    pub raised_at: DateTime<Utc>,
+    pub suppressed: bool,
+    /// Set from the alerts panel; cleared when the condition clears, so the alert is
+    /// raised afresh the next time it occurs.
+    pub acknowledged: bool,
+}
+
+#[derive(Clone)]
+pub enum MaintenanceScope {
+    Global,
+    Site { site_id: Uuid, site_name: String },
+}
+
+#[derive(Clone)]
+pub struct MaintenanceWindow {
+    pub scope: MaintenanceScope,
+    pub started: Instant,
+    pub duration: Duration,
+}
+
+impl MaintenanceWindow {
+    pub fn new(scope: MaintenanceScope, duration: Duration) -> Self {
+        Self {
+            scope,
+            started: Instant::now(),
+            duration,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.started.elapsed() < self.duration
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.duration.saturating_sub(self.started.elapsed())
+    }
+
+    pub fn covers(&self, site_id: Option<Uuid>) -> bool {
+        match &self.scope {
+            MaintenanceScope::Global => true,
+            MaintenanceScope::Site { site_id: id, .. } => site_id == Some(*id),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        let remaining = self.remaining().as_secs();
+        let scope = match &self.scope {
+            MaintenanceScope::Global => "All Sites",
+            MaintenanceScope::Site { site_name, .. } => site_name.as_str(),
+        };
+        format!(
+            "Maintenance ({}) {:02}:{:02}",
+            scope,
+            remaining / 60,
+            remaining % 60
+        )
+    }
+}
+
+pub struct AlertMonitor {
+    active: HashMap<(Uuid, AlertKind), Alert>,
+    pub cpu_threshold: f64,
+    pub memory_threshold: f64,
+}
+
+impl Default for AlertMonitor {
+    fn default() -> Self {
+        Self {
+            active: HashMap::new(),
+            cpu_threshold: CPU_ALERT_THRESHOLD,
+            memory_threshold: MEMORY_ALERT_THRESHOLD,
+        }
+    }
+}
+
+impl AlertMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Re-evaluates alert conditions against freshly fetched state and returns
+    /// the alerts that should be notified. Conditions that cleared are dropped,
+    /// alerts raised inside a maintenance window stay silent until it ends, and
+    /// acknowledged alerts stay silent for as long as their condition holds.
+    pub fn evaluate(
+        &mut self,
+        state: &AppState,
+        maintenance: Option<&MaintenanceWindow>,
+    ) -> Vec<Alert> {
+        let previous = std::mem::take(&mut self.active);
+        let mut raised = Vec::new();
+
+        for device in &state.devices {
+            let site_id = state.device_sites.get(&device.id).copied();
+            let suppressed = maintenance.is_some_and(|m| m.covers(site_id));
+
+            let mut conditions = Vec::new();
+            if matches!(device.state, DeviceState::Offline) {
+                conditions.push((
+                    AlertKind::DeviceOffline,
+                    format!("{} is offline", device.name),
+                ));
+            }
+            if let Some(stats) = state.device_stats.get(&device.id) {
+                if let Some(cpu) = stats
+                    .cpu_utilization_pct
+                    .filter(|cpu| *cpu >= self.cpu_threshold)
+                {
+                    conditions.push((
+                        AlertKind::HighCpu,
+                        format!("{} CPU at {:.1}%", device.name, cpu),
+                    ));
+                }
+                if let Some(memory) = stats
+                    .memory_utilization_pct
+                    .filter(|memory| *memory >= self.memory_threshold)
+                {
+                    conditions.push((
+                        AlertKind::HighMemory,
+                        format!("{} memory at {:.1}%", device.name, memory),
+                    ));
+                }
+            }
+
+            for (kind, message) in conditions {
+                let key = (device.id, kind);
+                let (alert, notify) = match previous.get(&key) {
+                    Some(existing) => (
+                        Alert {
+                            message,
+                            suppressed: existing.suppressed && suppressed,
+                            ..existing.clone()
+                        },
+                        existing.suppressed && !suppressed && !existing.acknowledged,
+                    ),
+                    None => (
+                        Alert {
+                            kind,
+                            device_id: device.id,
+                            site_id,
+                            message,
+                            raised_at: Utc::now(),
+                            suppressed,
+                            acknowledged: false,
+                        },
+                        !suppressed,
+                    ),
+                };
+                if notify {
+                    raised.push(alert.clone());
+                }
+                self.active.insert(key, alert);
+            }
+        }
+
+        if !raised.is_empty() {
+            tracing::info!(count = raised.len(), "Raised alerts");
+        }
+        raised
+    }
+
+    pub fn active(&self) -> impl Iterator<Item = &Alert> {
+        self.active.values().filter(|a| !a.suppressed)
+    }
+
+    pub fn active_count(&self) -> usize {
+        self.active().count()
+    }
+
+    /// Active alerts, oldest first, for listing in the alerts panel.
+    pub fn sorted(&self) -> Vec<&Alert> {
+        let mut alerts: Vec<_> = self.active().collect();
+        alerts.sort_by_key(|a| (a.raised_at, a.device_id));
+        alerts
+    }
+
+    pub fn unacknowledged_count(&self) -> usize {
+        self.active().filter(|a| !a.acknowledged).count()
+    }
+
+    /// Flips the acknowledgement of an active alert and returns the new state.
+    pub fn toggle_acknowledged(&mut self, device_id: Uuid, kind: AlertKind) -> Option<bool> {
+        let alert = self.active.get_mut(&(device_id, kind))?;
+        alert.acknowledged = !alert.acknowledged;
+        Some(alert.acknowledged)
+    }
+}
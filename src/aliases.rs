@@ -0,0 +1,67 @@
+use crate::error::Result;
+use crate::files;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Friendly names for clients, keyed by MAC address. The Integration API has no
+/// endpoint for renaming clients, so aliases live next to the audit log and are
+/// only shown by this TUI.
+pub struct ClientAliases {
+    path: Option<PathBuf>,
+    by_mac: HashMap<String, String>,
+}
+
+impl ClientAliases {
+    pub fn open() -> Self {
+        let path = files::data_file("aliases");
+
+        let by_mac = path
+            .as_ref()
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .map(|content| {
+                content
+                    .lines()
+                    .filter_map(|line| line.split_once('\t'))
+                    .map(|(mac, alias)| (mac.to_lowercase(), alias.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self { path, by_mac }
+    }
+
+    pub fn get(&self, mac: &str) -> Option<&str> {
+        self.by_mac.get(&mac.to_lowercase()).map(String::as_str)
+    }
+
+    /// The alias if one is set, otherwise the controller-provided name.
+    pub fn display_name(&self, mac: &str, name: Option<&str>) -> String {
+        self.get(mac).or(name).unwrap_or("Unnamed").to_string()
+    }
+
+    /// Sets the alias for `mac`, or removes it when `alias` is blank.
+    pub fn set(&mut self, mac: &str, alias: &str) -> Result<()> {
+        let alias = alias.trim().replace(['\t', '\n'], " ");
+        if alias.is_empty() {
+            self.by_mac.remove(&mac.to_lowercase());
+        } else {
+            self.by_mac.insert(mac.to_lowercase(), alias);
+        }
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let mut lines: Vec<String> = self
+            .by_mac
+            .iter()
+            .map(|(mac, alias)| format!("{}\t{}\n", mac, alias))
+            .collect();
+        lines.sort();
+        std::fs::write(path, lines.concat())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use crate::export;
+use crate::state::AppState;
+use chrono::Utc;
+use serde_json::json;
+use std::net::SocketAddr;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time::timeout;
+
+/// Requests are a line and a few headers; anything longer is cut off, and a client
+/// that stops sending is dropped, so a connection can't hold memory or a task forever.
+const MAX_REQUEST_BYTES: u64 = 8 * 1024;
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Default)]
+struct Snapshot {
+    sites: String,
+    devices: String,
+    clients: String,
+    stats: String,
+    updated_at: Option<String>,
+}
+
+/// Read-only JSON endpoints over the state the TUI already fetched, so dashboards
+/// can reuse its polling. Requests never reach the controller; they are answered
+/// from a snapshot that is replaced after every successful refresh.
+pub struct ApiServer {
+    snapshot: Arc<RwLock<Snapshot>>,
+}
+
+impl ApiServer {
+    pub async fn bind(addr: SocketAddr) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        let snapshot = Arc::new(RwLock::new(Snapshot::default()));
+
+        let shared = snapshot.clone();
+        tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, peer)) => {
+                        let shared = shared.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = serve(stream, shared).await {
+                                tracing::debug!(peer = %peer, error = %e, "API request failed");
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        tracing::warn!(error = %e, "API accept failed");
+                        break;
+                    }
+                }
+            }
+        });
+
+        tracing::info!(addr = %addr, "Serving API");
+        Ok(Self { snapshot })
+    }
+
+    pub fn update(&self, state: &AppState) {
+        let next = Snapshot {
+            sites: export::sites(state).to_string(),
+            devices: export::devices(state).to_string(),
+            clients: export::clients(state).to_string(),
+            stats: export::stats(state).to_string(),
+            updated_at: Some(Utc::now().to_rfc3339()),
+        };
+        if let Ok(mut snapshot) = self.snapshot.write() {
+            *snapshot = next;
+        }
+    }
+}
+
+async fn serve(mut stream: TcpStream, snapshot: Arc<RwLock<Snapshot>>) -> std::io::Result<()> {
+    let (reader, mut writer) = stream.split();
+    let mut reader = BufReader::new(reader.take(MAX_REQUEST_BYTES));
+    let request_line = match timeout(READ_TIMEOUT, read_request(&mut reader)).await {
+        Ok(result) => result?,
+        Err(_) => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "request not received in time",
+            ))
+        }
+    };
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts
+        .next()
+        .unwrap_or_default()
+        .split('?')
+        .next()
+        .unwrap_or_default();
+
+    let (status, body) = {
+        let snapshot = snapshot
+            .read()
+            .map_err(|_| std::io::Error::other("API snapshot lock poisoned"))?;
+        match (method, path) {
+            ("GET", "/") => (
+                "200 OK",
+                json!({
+                    "endpoints": ["/sites", "/devices", "/clients", "/stats"],
+                    "updated_at": snapshot.updated_at,
+                })
+                .to_string(),
+            ),
+            ("GET", _) if snapshot.updated_at.is_none() => (
+                "503 Service Unavailable",
+                json!({ "error": "No data fetched yet" }).to_string(),
+            ),
+            ("GET", "/sites") => ("200 OK", snapshot.sites.clone()),
+            ("GET", "/devices") => ("200 OK", snapshot.devices.clone()),
+            ("GET", "/clients") => ("200 OK", snapshot.clients.clone()),
+            ("GET", "/stats") => ("200 OK", snapshot.stats.clone()),
+            ("GET", _) => ("404 Not Found", json!({ "error": "Not found" }).to_string()),
+            _ => (
+                "405 Method Not Allowed",
+                json!({ "error": "Only GET is supported" }).to_string(),
+            ),
+        }
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+         Connection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    writer.write_all(response.as_bytes()).await?;
+    writer.shutdown().await
+}
+
+async fn read_request(reader: &mut (impl AsyncBufRead + Unpin)) -> std::io::Result<String> {
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+    // Headers are not used, but have to be read before answering.
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header).await? == 0 || header.trim().is_empty() {
+            break;
+        }
+    }
+    Ok(request_line)
+}
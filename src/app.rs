@@ -1,8 +1,44 @@
-use crate::state::AppState;
+use crate::actions::{run_device_action, DeviceAction};
+use crate::alerts::{AlertMonitor, MaintenanceScope, MaintenanceWindow};
+use crate::api::ApiServer;
+use crate::audit::AuditLog;
+use crate::bus::{self, Action};
+use crate::config::ConfigWatcher;
+use crate::email::EmailNotifier;
+use crate::error::{AppError, ErrorTreatment};
+use crate::events::{ChangeTracker, NetworkEvent};
+use crate::export;
+use crate::hooks::Hooks;
+use crate::ipc::ControlSocket;
+use crate::mqtt::MqttPublisher;
+use crate::pane_ratios::PaneRatios;
+use crate::refresher::Refresher;
+use crate::report::{self, ReportFormat};
+use crate::scheduler::Scheduler;
+use crate::script::{Script, ScriptTarget, Scripts};
+use crate::search_history::SearchHistory;
+use crate::state::{fetch_application_version, fetch_device, AppState, DetailFetch};
+use crate::statusline;
+use crate::syslog::SyslogForwarder;
+use crate::tasks::{StateUpdate, TaskRunner};
+use crate::toast::ToastLevel;
+use crate::traceroute::Traceroute;
+use crate::ui::icons::IconStyle;
+use crate::ui::page;
+use crate::ui::panels::PanelLayout;
+use crate::ui::site_dashboard::SiteDashboardSort;
+use crate::ui::site_switcher::SiteSwitcher;
 use crate::ui::topology::topology_view::TopologyView;
+use crate::ui::widgets::chart::{ChartCursor, ChartWindow};
 use crate::ui::widgets::DeviceStatsView;
+use crate::weblink;
+use chrono::Local;
 use ratatui::widgets::TableState;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use unifi_rs::models::client::ClientOverview;
+use unifi_rs::site::SiteOverview;
+use unifi_rs::UnifiClientBuilder;
 use uuid::Uuid;
 
 #[derive(PartialEq, Clone)]
@@ -10,13 +46,64 @@ pub enum Mode {
     Overview,
     DeviceDetail,
     ClientDetail,
+    FirmwareUpdates,
+    SiteDashboard,
     #[allow(dead_code)]
     Help,
 }
 
+/// Tabs of the overview, in the order they appear in the tab bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tab {
+    #[default]
+    Dashboard,
+    Sites,
+    Devices,
+    Clients,
+    Topology,
+    Stats,
+}
+
+impl Tab {
+    pub const ALL: [Tab; 6] = [
+        Tab::Dashboard,
+        Tab::Sites,
+        Tab::Devices,
+        Tab::Clients,
+        Tab::Topology,
+        Tab::Stats,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Tab::Dashboard => "Dashboard",
+            Tab::Sites => "Sites",
+            Tab::Devices => "Devices",
+            Tab::Clients => "Clients",
+            Tab::Topology => "Topology",
+            Tab::Stats => "Stats",
+        }
+    }
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn next(self) -> Tab {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(self) -> Tab {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
 #[derive(PartialEq, Clone)]
 pub enum DialogType {
     Confirmation,
+    TypedConfirmation {
+        expected: String,
+    },
     #[allow(dead_code)] // Not used yet
     Message,
     #[allow(dead_code)] // Not used yet
@@ -30,64 +117,400 @@ pub enum SortOrder {
     None,
 }
 
+// Callbacks run on the UI loop, so anything that talks to the controller has to be
+// enqueued on `App::tasks` rather than awaited here.
 pub type Callback = Box<dyn FnOnce(&mut App) -> anyhow::Result<()> + Send>;
 
 pub struct Dialog {
     pub title: String,
     pub message: String,
     pub dialog_type: DialogType,
+    pub input: String,
     pub callback: Option<Callback>,
 }
 
+pub struct AliasPrompt {
+    pub mac_address: String,
+    pub input: String,
+}
+
+pub struct SchedulePrompt {
+    pub site_id: Uuid,
+    pub device_id: Uuid,
+    pub device_name: String,
+    pub action: DeviceAction,
+    pub input: String,
+}
+
+const INLINE_CONFIRM_TIMEOUT: Duration = Duration::from_secs(3);
+
+pub const LOW_POWER_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How long the Sites tab cursor has to rest on a site before it is prefetched, so
+/// scrolling through the list doesn't fetch every site on the way.
+const PREFETCH_DELAY: Duration = Duration::from_millis(400);
+
+/// The site under the Sites tab cursor and whether its data has been prefetched.
+struct SitePrefetch {
+    site_id: Uuid,
+    since: Instant,
+    requested: bool,
+}
+
+/// A low-risk action armed by its first key press. Pressing the same key on the
+/// same target before it expires runs the action; the dialog is kept for
+/// anything destructive.
+pub struct InlineConfirm {
+    pub prompt: String,
+    key: char,
+    target: Uuid,
+    expires_at: Instant,
+}
+
+/// Locks the screen after `timeout` without key or mouse input, for terminals left
+/// unattended. Without a passphrase any key unlocks it again.
+pub struct IdleLock {
+    pub timeout: Duration,
+    pub passphrase: Option<String>,
+}
+
+/// Shown instead of the UI while locked; `failed` is set after a wrong passphrase.
+#[derive(Default)]
+pub struct LockScreen {
+    pub input: String,
+    pub failed: bool,
+}
+
+/// Wallboard mode: the tab bar and breadcrumb are hidden and the views in
+/// [`Kiosk::TABS`] take turns every `interval`.
+pub struct Kiosk {
+    pub interval: Duration,
+    pub switched_at: Instant,
+}
+
+impl Kiosk {
+    pub const TABS: [Tab; 3] = [Tab::Dashboard, Tab::Stats, Tab::Topology];
+
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            switched_at: Instant::now(),
+        }
+    }
+}
+
+/// Controller settings from the command line, kept so the client can be rebuilt
+/// with a new API key after an authentication failure.
+pub struct Connection {
+    pub url: String,
+    pub insecure: bool,
+}
+
+/// The row a table had selected and the item on it when last synced. A changed row
+/// means the user moved; the same row holding another item means the list was
+/// rebuilt or re-sorted underneath, so the selection follows the item instead.
+#[derive(Debug, Default)]
+struct TrackedSelection {
+    row: Option<usize>,
+    id: Option<Uuid>,
+}
+
+impl TrackedSelection {
+    fn sync(&mut self, table: &mut TableState, ids: &[Option<Uuid>]) {
+        if table.selected() == self.row {
+            if let Some(row) = self
+                .id
+                .and_then(|id| ids.iter().position(|i| *i == Some(id)))
+            {
+                table.select(Some(row));
+            }
+        }
+        if table.selected().is_some_and(|row| row >= ids.len()) {
+            table.select(ids.len().checked_sub(1));
+        }
+        self.row = table.selected();
+        self.id = self.row.and_then(|row| ids.get(row).copied().flatten());
+    }
+}
+
 pub struct App {
     pub state: AppState,
-    pub current_tab: usize,
+    pub current_tab: Tab,
     pub mode: Mode,
     pub dialog: Option<Dialog>,
     pub search_mode: bool,
     pub search_query: String,
+    /// Typed prefix while jumping to a row, see [`App::jump_to_prefix`].
+    pub jump_query: Option<String>,
+    pub search_history: SearchHistory,
     pub show_help: bool,
     pub device_sort_column: usize,
     pub device_sort_order: SortOrder,
     pub client_sort_column: usize,
     pub client_sort_order: SortOrder,
+    pub site_sort_order: SortOrder,
     pub sites_table_state: TableState,
     pub devices_table_state: TableState,
     pub device_stats_view: Option<DeviceStatsView>,
     pub clients_table_state: TableState,
+    device_selection: TrackedSelection,
+    client_selection: TrackedSelection,
+    pub firmware_table_state: TableState,
+    pub site_dashboard_table_state: TableState,
+    pub site_dashboard_sort: SiteDashboardSort,
     pub selected_device_id: Option<Uuid>,
     pub selected_client_id: Option<Uuid>,
     pub topology_view: TopologyView,
+    pub scheduler: Scheduler,
+    pub schedule_prompt: Option<SchedulePrompt>,
+    pub alias_prompt: Option<AliasPrompt>,
+    pub show_jobs: bool,
+    pub jobs_table_state: TableState,
+    pub alerts: AlertMonitor,
+    pub show_alerts: bool,
+    pub alerts_table_state: TableState,
+    pub maintenance: Option<MaintenanceWindow>,
+    pub maintenance_duration: Duration,
+    pub audit: AuditLog,
+    pub show_activity: bool,
+    pub show_errors: bool,
+    pub show_controller: bool,
+    pub show_row_popup: bool,
+    pub traceroute: Option<Traceroute>,
+    pub ssh_user: String,
+    pub icons: IconStyle,
+    pub config: ConfigWatcher,
+    pub scripts: Scripts,
+    pub dashboard_layout: PanelLayout,
+    pub stats_layout: PanelLayout,
+    pub pane_ratios: PaneRatios,
+    pub scripts_file: ConfigWatcher,
+    pub stats_cursor: Option<ChartCursor>,
+    pub stats_window: ChartWindow,
+    pub stats_focus: usize,
+    pub chart_fullscreen: bool,
+    pub stats_breakdown: bool,
+    pub stats_by_medium: bool,
+    pub show_client_vendor: bool,
+    pub screen_reader: bool,
+    /// `--low-power`: slower polling and redraws, details only for an open device.
+    pub low_power: bool,
+    pub pending_ssh: Option<String>,
+    pub snapshot_requested: bool,
+    pub undo_stack: Vec<Action>,
+    pub control_socket: Option<ControlSocket>,
+    pub api: Option<ApiServer>,
+    pub mqtt: Option<MqttPublisher>,
+    pub syslog: Option<SyslogForwarder>,
+    pub email: Option<EmailNotifier>,
+    pub hooks: Hooks,
+    pub changes: ChangeTracker,
+    pub error_scroll: u16,
+    pub tasks: TaskRunner,
+    refresher: Refresher,
+    site_prefetch: Option<SitePrefetch>,
+    pub connection: Option<Connection>,
+    pub reauth_prompt: Option<String>,
+    pub banner: Option<String>,
+    pub inline_confirm: Option<InlineConfirm>,
+    pub idle_lock: Option<IdleLock>,
+    pub kiosk: Option<Kiosk>,
+    pub lock_screen: Option<LockScreen>,
+    pub last_input: Instant,
+    pub site_switcher: Option<SiteSwitcher>,
     pub should_quit: bool,
+    /// Set by anything that changes what is on screen; the loop only draws when it is
+    /// set or the status clock is due.
+    pub needs_redraw: bool,
 }
 
 impl App {
     pub async fn new(state: AppState) -> anyhow::Result<Self> {
         Ok(Self {
             state,
-            current_tab: 0,
+            current_tab: Tab::Dashboard,
             mode: Mode::Overview,
             dialog: None,
             search_mode: false,
             search_query: String::new(),
+            jump_query: None,
+            search_history: SearchHistory::open(),
             show_help: false,
             device_sort_column: 0,
             device_sort_order: SortOrder::None,
             client_sort_column: 0,
             client_sort_order: SortOrder::None,
+            site_sort_order: SortOrder::None,
             sites_table_state: TableState::default(),
             devices_table_state: TableState::default(),
             clients_table_state: TableState::default(),
+            device_selection: TrackedSelection::default(),
+            client_selection: TrackedSelection::default(),
+            firmware_table_state: TableState::default(),
+            site_dashboard_table_state: TableState::default(),
+            site_dashboard_sort: SiteDashboardSort::default(),
             selected_device_id: None,
             selected_client_id: None,
             device_stats_view: None,
             topology_view: TopologyView::new(),
+            scheduler: Scheduler::new(),
+            schedule_prompt: None,
+            alias_prompt: None,
+            show_jobs: false,
+            jobs_table_state: TableState::default(),
+            alerts: AlertMonitor::new(),
+            show_alerts: false,
+            alerts_table_state: TableState::default(),
+            maintenance: None,
+            maintenance_duration: Duration::from_secs(60 * 60),
+            audit: AuditLog::open(),
+            show_activity: false,
+            show_errors: false,
+            show_controller: false,
+            show_row_popup: false,
+            traceroute: None,
+            ssh_user: "root".to_string(),
+            icons: IconStyle::default(),
+            config: ConfigWatcher::open(),
+            scripts: Scripts::default(),
+            dashboard_layout: PanelLayout::dashboard(),
+            stats_layout: PanelLayout::stats(),
+            pane_ratios: PaneRatios::open(),
+            scripts_file: ConfigWatcher::open_file("scripts"),
+            stats_cursor: None,
+            stats_window: ChartWindow::default(),
+            stats_focus: 0,
+            chart_fullscreen: false,
+            stats_breakdown: false,
+            stats_by_medium: false,
+            show_client_vendor: false,
+            screen_reader: false,
+            low_power: false,
+            pending_ssh: None,
+            snapshot_requested: false,
+            undo_stack: Vec::new(),
+            control_socket: None,
+            api: None,
+            mqtt: None,
+            syslog: None,
+            email: None,
+            hooks: Hooks::default(),
+            changes: ChangeTracker::new(),
+            error_scroll: 0,
+            tasks: TaskRunner::new(),
+            refresher: Refresher::new(),
+            site_prefetch: None,
+            connection: None,
+            reauth_prompt: None,
+            banner: None,
+            inline_confirm: None,
+            idle_lock: None,
+            kiosk: None,
+            lock_screen: None,
+            last_input: Instant::now(),
+            site_switcher: None,
             should_quit: false,
+            needs_redraw: true,
         })
     }
 
-    pub async fn refresh(&mut self) -> anyhow::Result<()> {
-        self.state.refresh_data().await?;
+    /// Starts a background fetch when one is due and applies it once it has arrived.
+    pub fn refresh(&mut self) -> crate::error::Result<()> {
+        if self.maintenance.as_ref().is_some_and(|m| !m.is_active()) {
+            tracing::info!("Maintenance window ended");
+            self.maintenance = None;
+            self.state
+                .notify(ToastLevel::Info, "Maintenance window ended, alerts resumed");
+        }
+
+        self.prefetch_selected_site();
+        // A site entered from the Sites tab is shown from its prefetched data right
+        // away; the prefetch skips device details, so a full refresh is due after it.
+        let prefetched = self.state.site_id().and_then(|id| {
+            self.refresher
+                .take_prefetched(id, self.state.refresh_interval)
+        });
+        let from_prefetch = prefetched.is_some();
+        let (site_id, snapshot) = match prefetched {
+            Some(snapshot) => {
+                tracing::debug!(site_id = ?self.state.site_id(), "Using prefetched site data");
+                (self.state.site_id(), Ok(snapshot))
+            }
+            None => {
+                if !self.refresher.is_busy() && self.state.start_refresh() {
+                    let details = if self.low_power {
+                        DetailFetch::Open(self.selected_device_id)
+                    } else {
+                        DetailFetch::All
+                    };
+                    self.refresher.request(
+                        self.state.source.clone(),
+                        self.state.site_id(),
+                        details,
+                    );
+                }
+                let Some(fetched) = self.refresher.try_recv() else {
+                    return Ok(());
+                };
+                fetched
+            }
+        };
+        self.needs_redraw = true;
+        // Data for a site context that was left while it was being fetched is not
+        // applied; the switch already made a new refresh due.
+        if site_id != self.state.site_id() {
+            tracing::debug!(site_id = ?site_id, "Dropping refresh for a previous site context");
+            return Ok(());
+        }
+        self.state.finish_refresh(snapshot)?;
+        if from_prefetch {
+            self.state.last_update -= self.state.refresh_interval;
+        }
+        statusline::write_cache(&statusline::render(&self.state));
+        if let Some(api) = &self.api {
+            api.update(&self.state);
+        }
+        self.banner = self.state.is_backing_off().then(|| {
+            format!(
+                "Rate limited — refreshing every {}s",
+                self.state.refresh_interval.as_secs()
+            )
+        });
+
+        let alerts = self.alerts.evaluate(&self.state, self.maintenance.as_ref());
+        let events: Vec<_> = self
+            .changes
+            .diff(&self.state)
+            .into_iter()
+            .chain(alerts.iter().map(NetworkEvent::from_alert))
+            .collect();
+        self.state.record_device_events(&events);
+        if let Some(mqtt) = self.mqtt.as_mut() {
+            mqtt.publish_events(&events);
+            mqtt.publish_metrics(&self.state);
+        }
+        if let Some(syslog) = &self.syslog {
+            syslog.forward(&events);
+        }
+        self.hooks.run(&events);
+        if let Some(email) = self.email.as_mut() {
+            email.notify(&alerts);
+            for error in email.drain_errors() {
+                self.state.log_error(error);
+            }
+        }
+
+        for alert in alerts {
+            tracing::warn!(
+                kind = ?alert.kind,
+                device_id = ?alert.device_id,
+                site_id = ?alert.site_id,
+                raised_at = %alert.raised_at,
+                "{}",
+                alert.message
+            );
+            self.state.notify(ToastLevel::Warning, alert.message);
+        }
 
         if !self.search_query.is_empty() {
             self.state.search(&self.search_query);
@@ -99,15 +522,42 @@ impl App {
         if !matches!(self.client_sort_order, SortOrder::None) {
             self.sort_clients();
         }
+        if !matches!(self.site_sort_order, SortOrder::None) {
+            self.sort_sites();
+        }
 
-        self.topology_view.update_from_state(
-            &self.state.filtered_devices,
-            &self.state.filtered_clients,
-            &self.state.device_details,
-        );
+        for page in page::all() {
+            page.refresh(self);
+        }
         Ok(())
     }
 
+    /// Keeps the device and client tables on the same item after refreshes, sorting
+    /// and filtering. Called once per frame, before rendering.
+    pub fn sync_selection(&mut self) {
+        let device_ids: Vec<_> = self
+            .state
+            .filtered_devices
+            .iter()
+            .map(|d| Some(d.id))
+            .collect();
+        self.device_selection
+            .sync(&mut self.devices_table_state, &device_ids);
+
+        let client_ids: Vec<_> = self
+            .state
+            .filtered_clients
+            .iter()
+            .map(|client| match client {
+                ClientOverview::Wired(c) => Some(c.base.id),
+                ClientOverview::Wireless(c) => Some(c.base.id),
+                _ => None,
+            })
+            .collect();
+        self.client_selection
+            .sync(&mut self.clients_table_state, &client_ids);
+    }
+
     pub fn sort_devices(&mut self) {
         if matches!(self.device_sort_order, SortOrder::None) {
             return;
@@ -130,20 +580,47 @@ impl App {
         });
     }
 
+    /// Sorts sites by name. Favorites stay on top either way.
+    pub fn sort_sites(&mut self) {
+        if matches!(self.site_sort_order, SortOrder::None) {
+            return;
+        }
+
+        let favorites = &self.state.favorites;
+        let name = |site: &SiteOverview| site.name.clone().unwrap_or_default().to_lowercase();
+        self.state.filtered_sites.sort_by(|a, b| {
+            let pinned = |site: &SiteOverview| favorites.position(site.id).unwrap_or(usize::MAX);
+            let cmp = match self.site_sort_order {
+                SortOrder::Descending => name(b).cmp(&name(a)),
+                _ => name(a).cmp(&name(b)),
+            };
+            pinned(a).cmp(&pinned(b)).then(cmp)
+        });
+    }
+
     pub fn sort_clients(&mut self) {
         if matches!(self.client_sort_order, SortOrder::None) {
             return;
         }
 
+        let aliases = &self.state.aliases;
         self.state.filtered_clients.sort_by(|a, b| {
             let get_fields = |client: &ClientOverview| match client {
                 ClientOverview::Wired(c) => (
-                    c.base.name.as_deref().unwrap_or("").to_string(),
+                    aliases
+                        .get(&c.mac_address)
+                        .or(c.base.name.as_deref())
+                        .unwrap_or("")
+                        .to_string(),
                     c.base.ip_address.as_deref().unwrap_or("").to_string(),
                     c.mac_address.to_string(),
                 ),
                 ClientOverview::Wireless(c) => (
-                    c.base.name.as_deref().unwrap_or("").to_string(),
+                    aliases
+                        .get(&c.mac_address)
+                        .or(c.base.name.as_deref())
+                        .unwrap_or("")
+                        .to_string(),
                     c.base.ip_address.as_deref().unwrap_or("").to_string(),
                     c.mac_address.to_string(),
                 ),
@@ -169,11 +646,18 @@ impl App {
     }
 
     pub fn next_tab(&mut self) {
-        self.current_tab = (self.current_tab + 1) % 5;
+        self.current_tab = self.current_tab.next();
     }
 
     pub fn previous_tab(&mut self) {
-        self.current_tab = (self.current_tab + 3) % 5;
+        self.current_tab = self.current_tab.previous();
+    }
+
+    /// Applies an action through the reducer, remembering how to revert it.
+    pub fn dispatch(&mut self, action: Action) {
+        if let Some(inverse) = bus::reduce(self, action) {
+            bus::record_undo(&mut self.undo_stack, inverse);
+        }
     }
 
     pub fn toggle_help(&mut self) {
@@ -185,12 +669,106 @@ impl App {
     pub fn enter_search_mode(&mut self) {
         self.search_mode = true;
         self.search_query.clear();
+        self.search_history.reset();
     }
 
     pub fn exit_search_mode(&mut self) {
         self.search_mode = false;
     }
 
+    /// Rows matching the search in the current tab, or across all of them on tabs
+    /// without a searchable table.
+    pub fn search_match_count(&self) -> usize {
+        match self.current_tab {
+            Tab::Sites => self.state.filtered_sites.len(),
+            Tab::Devices => self.state.filtered_devices.len(),
+            Tab::Clients => self.state.filtered_clients.len(),
+            Tab::Dashboard | Tab::Topology | Tab::Stats => {
+                self.state.filtered_sites.len()
+                    + self.state.filtered_devices.len()
+                    + self.state.filtered_clients.len()
+            }
+        }
+    }
+
+    /// Moves the selection in the current table to the next or previous match.
+    /// Tables only hold matching rows while searching, so this steps through them.
+    pub fn jump_to_match(&mut self, forward: bool) {
+        let (len, table_state) = match self.current_tab {
+            Tab::Sites => (self.state.filtered_sites.len(), &mut self.sites_table_state),
+            Tab::Devices => (
+                self.state.filtered_devices.len(),
+                &mut self.devices_table_state,
+            ),
+            Tab::Clients => (
+                self.state.filtered_clients.len(),
+                &mut self.clients_table_state,
+            ),
+            Tab::Dashboard | Tab::Topology | Tab::Stats => return,
+        };
+        if len == 0 {
+            return;
+        }
+        let i = match table_state.selected() {
+            Some(i) if forward => (i + 1) % len,
+            Some(i) => (i + len - 1) % len,
+            None => 0,
+        };
+        table_state.select(Some(i));
+    }
+
+    pub fn start_jump(&mut self) {
+        self.search_mode = false;
+        self.jump_query = Some(String::new());
+    }
+
+    /// Selects the first row in the current table whose name starts with the typed
+    /// prefix, ignoring case. Unlike search, no rows are hidden.
+    pub fn jump_to_prefix(&mut self) {
+        let Some(prefix) = self.jump_query.as_deref().map(str::to_lowercase) else {
+            return;
+        };
+        if prefix.is_empty() {
+            return;
+        }
+        let aliases = &self.state.aliases;
+        let (row, table_state) = match self.current_tab {
+            Tab::Sites => (
+                self.state.filtered_sites.iter().position(|site| {
+                    let name = site.name.as_deref().unwrap_or_default();
+                    name.to_lowercase().starts_with(&prefix)
+                }),
+                &mut self.sites_table_state,
+            ),
+            Tab::Devices => (
+                self.state
+                    .filtered_devices
+                    .iter()
+                    .position(|device| device.name.to_lowercase().starts_with(&prefix)),
+                &mut self.devices_table_state,
+            ),
+            Tab::Clients => (
+                self.state.filtered_clients.iter().position(|client| {
+                    let name = match client {
+                        ClientOverview::Wired(c) => {
+                            aliases.display_name(&c.mac_address, c.base.name.as_deref())
+                        }
+                        ClientOverview::Wireless(c) => {
+                            aliases.display_name(&c.mac_address, c.base.name.as_deref())
+                        }
+                        _ => return false,
+                    };
+                    name.to_lowercase().starts_with(&prefix)
+                }),
+                &mut self.clients_table_state,
+            ),
+            Tab::Dashboard | Tab::Topology | Tab::Stats => return,
+        };
+        if let Some(row) = row {
+            table_state.select(Some(row));
+        }
+    }
+
     pub fn clear_search(&mut self) {
         self.search_mode = false;
         self.search_query.clear();
@@ -202,6 +780,10 @@ impl App {
         if let Some(id) = device_id {
             self.mode = Mode::DeviceDetail;
             self.device_stats_view = Some(DeviceStatsView::new(id, 0));
+            // Low-power refreshes skip device details until a detail view asks for them.
+            if self.low_power {
+                self.state.last_update -= self.state.refresh_interval;
+            }
         } else {
             self.device_stats_view = None;
         }
@@ -214,9 +796,799 @@ impl App {
         }
     }
 
+    pub fn show_site_dashboard(&mut self) {
+        self.mode = Mode::SiteDashboard;
+        let has_sites = !self.state.sites.is_empty();
+        self.site_dashboard_table_state
+            .select(has_sites.then_some(0));
+    }
+
+    pub fn show_firmware_updates(&mut self) {
+        self.mode = Mode::FirmwareUpdates;
+        let has_updates = !self.state.pending_firmware_updates().is_empty();
+        self.firmware_table_state.select(has_updates.then_some(0));
+    }
+
+    pub fn open_schedule_prompt(&mut self, device_id: Uuid) {
+        let Some(site_id) = self.state.device_sites.get(&device_id).copied() else {
+            return;
+        };
+        let Some(device) = self.state.devices.iter().find(|d| d.id == device_id) else {
+            return;
+        };
+
+        self.schedule_prompt = Some(SchedulePrompt {
+            site_id,
+            device_id,
+            device_name: device.name.clone(),
+            action: DeviceAction::Restart,
+            input: String::new(),
+        });
+    }
+
+    pub fn open_alias_prompt(&mut self, client_id: Uuid) {
+        let Some(mac_address) = self.state.clients.iter().find_map(|c| match c {
+            ClientOverview::Wired(w) if w.base.id == client_id => Some(&w.mac_address),
+            ClientOverview::Wireless(w) if w.base.id == client_id => Some(&w.mac_address),
+            _ => None,
+        }) else {
+            return;
+        };
+
+        self.alias_prompt = Some(AliasPrompt {
+            mac_address: mac_address.clone(),
+            input: self
+                .state
+                .aliases
+                .get(mac_address)
+                .unwrap_or_default()
+                .to_string(),
+        });
+    }
+
+    pub fn toggle_jobs(&mut self) {
+        self.show_jobs = !self.show_jobs;
+        if self.show_jobs {
+            let has_jobs = !self.scheduler.jobs().is_empty();
+            self.jobs_table_state.select(has_jobs.then_some(0));
+        }
+    }
+
+    pub fn toggle_alerts(&mut self) {
+        self.show_alerts = !self.show_alerts;
+        if self.show_alerts {
+            let has_alerts = self.alerts.active_count() > 0;
+            self.alerts_table_state.select(has_alerts.then_some(0));
+        }
+    }
+
+    pub fn toggle_activity(&mut self) {
+        self.show_activity = !self.show_activity;
+    }
+
+    pub fn open_traceroute(&mut self, target: String, label: String) {
+        tracing::info!(target = %target, "Starting traceroute");
+        self.traceroute = Some(Traceroute::start(target, label));
+    }
+
+    /// Queues an SSH session to the device; the main loop suspends the TUI to run it.
+    pub fn request_ssh(&mut self, device_id: Uuid) {
+        if let Some(device) = self.state.devices.iter().find(|d| d.id == device_id) {
+            self.pending_ssh = Some(format!("{}@{}", self.ssh_user, device.ip_address));
+        }
+    }
+
+    pub fn open_device_in_browser(&mut self, device_id: Uuid) {
+        let Some(device) = self.state.devices.iter().find(|d| d.id == device_id) else {
+            return;
+        };
+        let mac_address = device.mac_address.clone();
+        self.open_web_ui(&mac_address);
+    }
+
+    pub fn open_client_in_browser(&mut self, client_id: Uuid) {
+        let Some(mac_address) = self.state.clients.iter().find_map(|c| match c {
+            ClientOverview::Wired(w) if w.base.id == client_id => Some(w.mac_address.clone()),
+            ClientOverview::Wireless(w) if w.base.id == client_id => Some(w.mac_address.clone()),
+            _ => None,
+        }) else {
+            return;
+        };
+        self.open_web_ui(&mac_address);
+    }
+
+    /// Opens the controller's web UI. It can't be opened on the device or client
+    /// itself (see [`weblink::network_app_url`]), so the toast names the MAC to look up.
+    fn open_web_ui(&mut self, mac_address: &str) {
+        let Some(url) = self
+            .connection
+            .as_ref()
+            .and_then(|c| weblink::network_app_url(&c.url))
+        else {
+            self.state
+                .set_error("No controller URL to open the web UI from".to_string());
+            return;
+        };
+
+        tracing::info!(url = %url, "Opening web UI");
+        match weblink::open(&url) {
+            Ok(()) => self.state.notify(
+                ToastLevel::Info,
+                format!("Opened {}, search for {} there", url, mac_address),
+            ),
+            Err(e) => self
+                .state
+                .set_error(format!("Failed to open {}: {}", url, e)),
+        }
+    }
+
+    pub fn export_report(&mut self) {
+        let alerts: Vec<_> = self.alerts.active().collect();
+        let contents = report::render(&self.state, &alerts, ReportFormat::Markdown);
+        match report::save(&contents, ReportFormat::Markdown) {
+            Ok(path) => {
+                tracing::info!(path = %path.display(), "Exported health report");
+                self.state.notify(
+                    ToastLevel::Success,
+                    format!("Report saved to {}", path.display()),
+                );
+            }
+            Err(e) => self
+                .state
+                .set_error(format!("Failed to export report: {}", e)),
+        }
+    }
+
+    /// The view whose panes `<` and `>` resize, as stored in [`PaneRatios`].
+    pub fn pane_view(&self) -> Option<&'static str> {
+        match (&self.mode, self.current_tab) {
+            (Mode::Overview, Tab::Dashboard) => Some("dashboard"),
+            (Mode::Overview, Tab::Stats) => Some("stats"),
+            (Mode::ClientDetail, _) => Some("client_detail"),
+            _ => None,
+        }
+    }
+
+    pub fn resize_panes(&mut self, grow: bool) {
+        let Some(view) = self.pane_view() else {
+            return;
+        };
+        if let Err(e) = self.pane_ratios.resize(view, grow) {
+            self.state
+                .log_error(format!("Failed to save pane sizes: {}", e));
+        }
+    }
+
+    pub fn toggle_controller(&mut self) {
+        self.show_controller = !self.show_controller;
+    }
+
+    pub fn toggle_errors(&mut self) {
+        self.show_errors = !self.show_errors;
+        self.error_scroll = 0;
+    }
+
+    pub fn toggle_favorite_site(&mut self, site_id: Uuid) {
+        match self.state.favorites.toggle(site_id) {
+            Ok(pinned) => {
+                self.state.sort_sites();
+                self.state.search(&self.search_query);
+                self.sort_sites();
+                let index = self
+                    .state
+                    .filtered_sites
+                    .iter()
+                    .position(|s| s.id == site_id);
+                self.sites_table_state.select(index);
+                let message = if pinned {
+                    "Site pinned to favorites"
+                } else {
+                    "Site removed from favorites"
+                };
+                self.state.notify(ToastLevel::Info, message);
+            }
+            Err(e) => self
+                .state
+                .set_error(format!("Failed to save favorites: {}", e)),
+        }
+    }
+
+    /// Switches to the `index`th favorite site (zero-based), bound to keys 1-9.
+    pub fn select_favorite_site(&mut self, index: usize) {
+        let Some(site) = self
+            .state
+            .favorites
+            .get(index)
+            .and_then(|id| self.state.sites.iter().find(|s| s.id == id))
+        else {
+            self.state.notify(
+                ToastLevel::Warning,
+                format!("No favorite site {}", index + 1),
+            );
+            return;
+        };
+        let (site_id, name) = (site.id, site.name.clone());
+
+        self.state.set_site_context(Some(site_id));
+        let row = self
+            .state
+            .filtered_sites
+            .iter()
+            .position(|s| s.id == site_id);
+        self.sites_table_state.select(row);
+        self.state.notify(
+            ToastLevel::Info,
+            format!("Switched to {}", name.as_deref().unwrap_or("Unnamed")),
+        );
+    }
+
+    pub fn open_site_switcher(&mut self) {
+        self.search_mode = false;
+        self.site_switcher = Some(SiteSwitcher::default());
+    }
+
+    /// Changes the site context from anywhere. Detail views belong to the old
+    /// context, so they are closed.
+    pub fn switch_site(&mut self, site_id: Option<Uuid>) {
+        self.state.set_site_context(site_id);
+        if matches!(self.mode, Mode::DeviceDetail | Mode::ClientDetail) {
+            self.back_to_overview();
+        }
+        let row = site_id.and_then(|id| self.state.filtered_sites.iter().position(|s| s.id == id));
+        self.sites_table_state.select(row);
+
+        let name = self
+            .state
+            .selected_site
+            .as_ref()
+            .map_or("all sites".to_string(), |s| s.site_name.clone());
+        self.state
+            .notify(ToastLevel::Info, format!("Switched to {}", name));
+    }
+
+    /// Moves the kiosk on to its next view once the current one has been shown for
+    /// the interval.
+    pub fn advance_kiosk(&mut self) {
+        let Some(kiosk) = self.kiosk.as_mut() else {
+            return;
+        };
+        if kiosk.switched_at.elapsed() < kiosk.interval {
+            return;
+        }
+        kiosk.switched_at = Instant::now();
+        self.needs_redraw = true;
+        let next = Kiosk::TABS
+            .iter()
+            .position(|tab| *tab == self.current_tab)
+            .map_or(0, |i| (i + 1) % Kiosk::TABS.len());
+        self.current_tab = Kiosk::TABS[next];
+        self.mode = Mode::Overview;
+    }
+
+    pub fn check_idle_lock(&mut self) {
+        if self.lock_screen.is_none()
+            && self
+                .idle_lock
+                .as_ref()
+                .is_some_and(|lock| self.last_input.elapsed() >= lock.timeout)
+        {
+            tracing::info!("Locking the screen after the idle timeout");
+            self.lock_screen = Some(LockScreen::default());
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Unlocks if no passphrase is set or the one typed on the lock screen matches.
+    pub fn try_unlock(&mut self) {
+        let Some(screen) = self.lock_screen.as_mut() else {
+            return;
+        };
+        let expected = self
+            .idle_lock
+            .as_ref()
+            .and_then(|lock| lock.passphrase.as_deref());
+        if expected.is_some_and(|expected| screen.input != expected) {
+            tracing::warn!("Wrong passphrase on the lock screen");
+            screen.input.clear();
+            screen.failed = true;
+            return;
+        }
+        self.lock_screen = None;
+        self.last_input = Instant::now();
+    }
+
+    pub fn toggle_maintenance(&mut self) {
+        if self.maintenance.take().is_some() {
+            tracing::info!("Maintenance mode disabled");
+            self.state.notify(
+                ToastLevel::Info,
+                "Maintenance mode disabled, alerts resumed",
+            );
+            return;
+        }
+
+        let scope = match &self.state.selected_site {
+            Some(site) => MaintenanceScope::Site {
+                site_id: site.site_id,
+                site_name: site.site_name.clone(),
+            },
+            None => MaintenanceScope::Global,
+        };
+        let window = MaintenanceWindow::new(scope, self.maintenance_duration);
+        tracing::info!(duration = ?self.maintenance_duration, "Maintenance mode enabled");
+        self.state.notify(
+            ToastLevel::Info,
+            format!("{} started, alerts suppressed", window.label()),
+        );
+        self.maintenance = Some(window);
+    }
+
+    /// Prefetches the site the Sites tab cursor has rested on, unless it is already
+    /// the current context. Skipped in `--low-power` to keep API traffic down.
+    fn prefetch_selected_site(&mut self) {
+        let selected =
+            (!self.low_power && self.mode == Mode::Overview && self.current_tab == Tab::Sites)
+                .then(|| self.sites_table_state.selected())
+                .flatten()
+                .and_then(|i| self.state.filtered_sites.get(i))
+                .map(|site| site.id)
+                .filter(|id| Some(*id) != self.state.site_id());
+
+        match (&mut self.site_prefetch, selected) {
+            (Some(prefetch), Some(site_id)) if prefetch.site_id == site_id => {
+                if !prefetch.requested && prefetch.since.elapsed() >= PREFETCH_DELAY {
+                    prefetch.requested = true;
+                    tracing::debug!(site_id = ?site_id, "Prefetching site data");
+                    self.refresher.prefetch(self.state.source.clone(), site_id);
+                }
+            }
+            (_, selected) => {
+                self.site_prefetch = selected.map(|site_id| SitePrefetch {
+                    site_id,
+                    since: Instant::now(),
+                    requested: false,
+                })
+            }
+        }
+    }
+
+    /// Sets the normal refresh interval, which `--low-power` keeps at least at
+    /// [`LOW_POWER_REFRESH_INTERVAL`].
+    pub fn set_refresh_interval(&mut self, interval: Duration) {
+        let interval = if self.low_power {
+            interval.max(LOW_POWER_REFRESH_INTERVAL)
+        } else {
+            interval
+        };
+        self.state.set_base_refresh_interval(interval);
+    }
+
+    /// Applies the config file on startup and whenever it changes on disk. An invalid
+    /// file is reported and the settings in effect are kept.
+    pub fn reload_config(&mut self) {
+        let Some(result) = self.config.poll() else {
+            return;
+        };
+        self.needs_redraw = true;
+        let path = self
+            .config
+            .path()
+            .map(|p| p.display().to_string())
+            .unwrap_or_default();
+
+        match result {
+            Ok(config) => {
+                if let Some(interval) = config.refresh_interval {
+                    self.set_refresh_interval(interval);
+                }
+                if let Some(threshold) = config.cpu_alert_threshold {
+                    self.alerts.cpu_threshold = threshold;
+                }
+                if let Some(threshold) = config.memory_alert_threshold {
+                    self.alerts.memory_threshold = threshold;
+                }
+                if let Some(icons) = config.icons {
+                    self.icons = icons;
+                }
+                if let Some(layout) = &config.dashboard_layout {
+                    self.dashboard_layout = layout.clone();
+                }
+                if let Some(layout) = &config.stats_layout {
+                    self.stats_layout = layout.clone();
+                }
+                for (hook, command) in [
+                    (
+                        &mut self.hooks.device_offline,
+                        config.on_device_offline.clone(),
+                    ),
+                    (
+                        &mut self.hooks.client_connected,
+                        config.on_client_connected.clone(),
+                    ),
+                    (&mut self.hooks.alert, config.on_alert.clone()),
+                ] {
+                    if let Some(command) = command {
+                        *hook = (!command.is_empty()).then_some(command);
+                    }
+                }
+                tracing::info!(path = %path, ?config, "Loaded config");
+                self.state
+                    .notify(ToastLevel::Success, format!("Loaded config from {}", path));
+            }
+            Err(e) => {
+                tracing::warn!(path = %path, error = %e, "Invalid config");
+                self.state.notify(
+                    ToastLevel::Error,
+                    format!("Invalid config, keeping current settings: {}", e),
+                );
+            }
+        }
+    }
+
+    pub fn reload_scripts(&mut self) {
+        let Some(result) = self.scripts_file.poll_content() else {
+            return;
+        };
+        self.needs_redraw = true;
+        let path = self
+            .scripts_file
+            .path()
+            .map(|p| p.display().to_string())
+            .unwrap_or_default();
+
+        match result.and_then(|content| Scripts::parse(&content)) {
+            Ok(scripts) => {
+                // Active filters pick up the new expression, or are dropped with it.
+                let find = |filters: &[Script], active: &Option<Script>| {
+                    let name = &active.as_ref()?.name;
+                    filters.iter().find(|f| &f.name == name).cloned()
+                };
+                self.state.device_filter = find(&scripts.device_filters, &self.state.device_filter);
+                self.state.client_filter = find(&scripts.client_filters, &self.state.client_filter);
+                self.scripts = scripts;
+                self.state.search(&self.search_query);
+                tracing::info!(path = %path, "Loaded scripts");
+                self.state
+                    .notify(ToastLevel::Success, format!("Loaded scripts from {}", path));
+            }
+            Err(e) => {
+                tracing::warn!(path = %path, error = %e, "Invalid scripts");
+                self.state.notify(
+                    ToastLevel::Error,
+                    format!("Invalid scripts, keeping current ones: {}", e),
+                );
+            }
+        }
+    }
+
+    /// Moves to the next scripted filter for devices or clients, and back to no
+    /// filter after the last one.
+    pub fn cycle_script_filter(&mut self, target: ScriptTarget) {
+        let filters = self.scripts.filters(target);
+        if filters.is_empty() {
+            self.state
+                .notify(ToastLevel::Info, "No filters defined in the scripts file");
+            return;
+        }
+        let active = match target {
+            ScriptTarget::Device => &mut self.state.device_filter,
+            ScriptTarget::Client => &mut self.state.client_filter,
+        };
+        let next = match active
+            .as_ref()
+            .and_then(|a| filters.iter().position(|f| f.name == a.name))
+        {
+            Some(i) => filters.get(i + 1).cloned(),
+            None => filters.first().cloned(),
+        };
+        *active = next;
+
+        self.state.search(&self.search_query);
+        match target {
+            ScriptTarget::Device => self.sort_devices(),
+            ScriptTarget::Client => self.sort_clients(),
+        }
+    }
+
+    pub fn run_due_jobs(&mut self) {
+        for job in self.scheduler.take_due(Local::now()) {
+            self.spawn_device_action(job.site_id, job.device_id, job.device_name, job.action);
+        }
+    }
+
+    pub fn spawn_device_action(
+        &mut self,
+        site_id: Uuid,
+        device_id: Uuid,
+        device_name: String,
+        action: DeviceAction,
+    ) {
+        let client = self.state.client.clone();
+        let audit = self.audit.clone();
+        let label = format!("{} {}", action.label(), device_name);
+        self.state
+            .notify(ToastLevel::Info, format!("{} started", label));
+        self.tasks.spawn(label, async move {
+            run_device_action(
+                client,
+                audit,
+                site_id,
+                device_id,
+                device_name.clone(),
+                action,
+            )
+            .await
+            .map(|_| format!("{} requested for {}", action.label(), device_name))
+        });
+    }
+
+    /// Refetches one device off the UI loop; the detail view shows it as refreshing
+    /// until the task's update lands.
+    pub fn refresh_device(&mut self, device_id: Uuid) {
+        let Some(site_id) = self.state.device_sites.get(&device_id).copied() else {
+            self.state
+                .set_error(format!("No site known for device {}", device_id));
+            return;
+        };
+        if !self.state.refreshing_devices.insert(device_id) {
+            return;
+        }
+        let name = self
+            .state
+            .device_details
+            .get(&device_id)
+            .map_or_else(|| device_id.to_string(), |details| details.name.clone());
+        let source = self.state.source.clone();
+        self.state
+            .notify(ToastLevel::Info, format!("Refreshing {}…", name));
+        self.tasks
+            .spawn_with_update(format!("Refresh {}", name), async move {
+                match fetch_device(source, site_id, device_id).await {
+                    Ok((details, stats)) => {
+                        let update: StateUpdate = Box::new(move |state: &mut AppState| {
+                            state.refreshing_devices.remove(&device_id);
+                            state.apply_device(details, stats);
+                        });
+                        (Ok(format!("Refreshed {}", name)), Some(update))
+                    }
+                    Err(e) => {
+                        let update: StateUpdate = Box::new(move |state: &mut AppState| {
+                            state.refreshing_devices.remove(&device_id);
+                        });
+                        (Err(e), Some(update))
+                    }
+                }
+            });
+    }
+
+    pub fn refresh_controller_info(&mut self) {
+        let source = self.state.source.clone();
+        self.tasks.spawn_with_update("Controller info", async move {
+            match fetch_application_version(source).await {
+                Ok(version) => {
+                    let message = format!("Controller is running {}", version);
+                    let update: StateUpdate = Box::new(move |state: &mut AppState| {
+                        state.set_controller_info(version);
+                    });
+                    (Ok(message), Some(update))
+                }
+                Err(e) => (Err(e), None),
+            }
+        });
+    }
+
+    pub fn process_task_outcomes(&mut self) {
+        self.state.toasts.prune();
+
+        for outcome in self.tasks.drain() {
+            self.needs_redraw = true;
+            if let Some(update) = outcome.update {
+                update(&mut self.state);
+            }
+            match outcome.result {
+                Ok(message) => {
+                    tracing::info!(task = %outcome.label, "{}", message);
+                    self.state.notify(ToastLevel::Success, message);
+                }
+                Err(e) => self.handle_task_error(&outcome.label, e),
+            }
+        }
+    }
+
+    // Actions report through the same toast and error console as refreshes, with the
+    // controller's message and a hint, so nothing fails silently behind the TUI.
+    fn handle_task_error(&mut self, label: &str, error: AppError) {
+        let message = match error.hint() {
+            Some(hint) => format!("{} failed: {} ({})", label, error, hint),
+            None => format!("{} failed: {}", label, error),
+        };
+        self.state.set_error(message);
+
+        if error.treatment() == ErrorTreatment::Reauthenticate
+            && self.connection.is_some()
+            && self.reauth_prompt.is_none()
+        {
+            self.reauth_prompt = Some(String::new());
+        }
+    }
+
+    pub fn process_ipc_requests(&mut self) {
+        let Some(socket) = self.control_socket.as_mut() else {
+            return;
+        };
+
+        for request in socket.drain() {
+            self.needs_redraw = true;
+            tracing::info!(command = %request.command, "Control socket command");
+            let (name, argument) = request
+                .command
+                .split_once(' ')
+                .map_or((request.command.as_str(), ""), |(name, arg)| {
+                    (name, arg.trim())
+                });
+
+            let response = match name {
+                "refresh" => {
+                    self.state.last_update -= self.state.refresh_interval;
+                    "ok".to_string()
+                }
+                "select-site" if argument.is_empty() || argument == "all" => {
+                    self.switch_site(None);
+                    "ok".to_string()
+                }
+                "select-site" => {
+                    let site = self.state.sites.iter().find(|s| {
+                        s.id.to_string() == argument
+                            || s.name
+                                .as_deref()
+                                .is_some_and(|n| n.eq_ignore_ascii_case(argument))
+                    });
+                    match site.map(|s| s.id) {
+                        Some(site_id) => {
+                            self.switch_site(Some(site_id));
+                            "ok".to_string()
+                        }
+                        None => format!("error: no site named {}", argument),
+                    }
+                }
+                "export-json" => export::all(&self.state).to_string(),
+                _ => format!(
+                    "error: unknown command {} (expected refresh, select-site, export-json)",
+                    name
+                ),
+            };
+            request.respond(response);
+        }
+    }
+
+    pub fn handle_refresh_error(&mut self, error: AppError) {
+        let message = match error.hint() {
+            Some(hint) => format!("Error refreshing data: {} ({})", error, hint),
+            None => format!("Error refreshing data: {}", error),
+        };
+
+        // Rate limiting is expected to repeat while it lasts, so it only shows up
+        // in the banner and the error console rather than as a toast every cycle.
+        if error.treatment() == ErrorTreatment::Backoff {
+            self.state.log_error(message);
+        } else {
+            self.state.set_error(message);
+        }
+
+        match error.treatment() {
+            ErrorTreatment::Reauthenticate => {
+                if self.connection.is_some() && self.reauth_prompt.is_none() {
+                    self.reauth_prompt = Some(String::new());
+                }
+            }
+            ErrorTreatment::Backoff => {
+                let interval = self.state.back_off();
+                self.banner = Some(format!(
+                    "Rate limited — backing off to {}s",
+                    interval.as_secs()
+                ));
+            }
+            ErrorTreatment::Retry | ErrorTreatment::Report => {}
+        }
+    }
+
+    pub fn reauthenticate(&mut self, api_key: String) {
+        let Some(connection) = &self.connection else {
+            return;
+        };
+
+        match UnifiClientBuilder::new(connection.url.clone())
+            .api_key(api_key)
+            .verify_ssl(!connection.insecure)
+            .build()
+        {
+            Ok(client) => {
+                tracing::info!("Rebuilt controller client with a new API key");
+                self.state.source = Arc::new(client.clone());
+                self.state.client = client;
+                self.state.last_update -= self.state.refresh_interval;
+                self.state
+                    .notify(ToastLevel::Info, "API key updated, refreshing");
+            }
+            Err(e) => {
+                self.state
+                    .set_error(format!("Failed to rebuild client: {}", e));
+            }
+        }
+    }
+
+    /// Returns true when `key` was already pressed for `target` within the timeout.
+    /// Otherwise arms the confirmation and shows `prompt` in the status bar.
+    pub fn confirm_inline(&mut self, key: char, target: Uuid, prompt: String) -> bool {
+        let now = Instant::now();
+        let confirmed = self
+            .inline_confirm
+            .take()
+            .is_some_and(|c| c.key == key && c.target == target && now < c.expires_at);
+        if !confirmed {
+            self.inline_confirm = Some(InlineConfirm {
+                prompt,
+                key,
+                target,
+                expires_at: now + INLINE_CONFIRM_TIMEOUT,
+            });
+        }
+        confirmed
+    }
+
+    pub fn pending_confirm(&self) -> Option<&InlineConfirm> {
+        self.inline_confirm
+            .as_ref()
+            .filter(|c| Instant::now() < c.expires_at)
+    }
+
     pub fn back_to_overview(&mut self) {
         self.mode = Mode::Overview;
+        self.chart_fullscreen = false;
         self.selected_device_id = None;
         self.selected_client_id = None;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selection_follows_item_when_list_is_reordered() {
+        let ids: Vec<_> = (0..3).map(|_| Some(Uuid::new_v4())).collect();
+        let mut table = TableState::default();
+        let mut selection = TrackedSelection::default();
+        table.select(Some(1));
+        selection.sync(&mut table, &ids);
+
+        let reordered = vec![ids[2], ids[0], ids[1]];
+        selection.sync(&mut table, &reordered);
+        assert_eq!(table.selected(), Some(2));
+    }
+
+    #[test]
+    fn user_movement_is_kept() {
+        let ids: Vec<_> = (0..3).map(|_| Some(Uuid::new_v4())).collect();
+        let mut table = TableState::default();
+        let mut selection = TrackedSelection::default();
+        table.select(Some(0));
+        selection.sync(&mut table, &ids);
+
+        table.select(Some(2));
+        selection.sync(&mut table, &ids);
+        assert_eq!(table.selected(), Some(2));
+    }
+
+    #[test]
+    fn removed_item_keeps_row_within_the_list() {
+        let ids: Vec<_> = (0..3).map(|_| Some(Uuid::new_v4())).collect();
+        let mut table = TableState::default();
+        let mut selection = TrackedSelection::default();
+        table.select(Some(2));
+        selection.sync(&mut table, &ids);
+
+        selection.sync(&mut table, &ids[..2]);
+        assert_eq!(table.selected(), Some(1));
+    }
+}
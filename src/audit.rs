@@ -0,0 +1,145 @@
+use crate::files;
+use chrono::{DateTime, Utc};
+use std::collections::VecDeque;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+const MAX_ENTRIES: usize = 200;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuditResult {
+    Success,
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    pub timestamp: DateTime<Utc>,
+    pub user: String,
+    pub action: String,
+    pub target: String,
+    pub result: AuditResult,
+}
+
+impl AuditEntry {
+    fn to_line(&self) -> String {
+        let result = match &self.result {
+            AuditResult::Success => "success".to_string(),
+            AuditResult::Failed(reason) => format!("failed: {}", reason),
+        };
+        [
+            self.timestamp.to_rfc3339(),
+            self.user.clone(),
+            self.action.clone(),
+            self.target.clone(),
+            result,
+        ]
+        .iter()
+        .map(|field| field.replace(['\t', '\n'], " "))
+        .collect::<Vec<_>>()
+        .join("\t")
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(5, '\t');
+        let timestamp = DateTime::parse_from_rfc3339(fields.next()?)
+            .ok()?
+            .with_timezone(&Utc);
+        let user = fields.next()?.to_string();
+        let action = fields.next()?.to_string();
+        let target = fields.next()?.to_string();
+        let result = match fields.next()? {
+            "success" => AuditResult::Success,
+            other => {
+                AuditResult::Failed(other.strip_prefix("failed: ").unwrap_or(other).to_string())
+            }
+        };
+        Some(Self {
+            timestamp,
+            user,
+            action,
+            target,
+            result,
+        })
+    }
+}
+
+/// Append-only record of every mutating action taken through the TUI. Cloned
+/// handles share the same in-memory history so spawned actions can record
+/// their outcome once they complete.
+#[derive(Clone)]
+pub struct AuditLog {
+    path: Option<PathBuf>,
+    entries: Arc<Mutex<VecDeque<AuditEntry>>>,
+}
+
+impl AuditLog {
+    pub fn open() -> Self {
+        let path = files::data_file("audit.log");
+
+        let mut entries: VecDeque<AuditEntry> = path
+            .as_ref()
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .map(|content| content.lines().filter_map(AuditEntry::from_line).collect())
+            .unwrap_or_default();
+        while entries.len() > MAX_ENTRIES {
+            entries.pop_front();
+        }
+
+        Self {
+            path,
+            entries: Arc::new(Mutex::new(entries)),
+        }
+    }
+
+    pub fn record(&self, action: &str, target: &str, result: AuditResult) {
+        let entry = AuditEntry {
+            timestamp: Utc::now(),
+            user: current_user(),
+            action: action.to_string(),
+            target: target.to_string(),
+            result,
+        };
+
+        tracing::info!(
+            action = %entry.action,
+            target = %entry.target,
+            result = ?entry.result,
+            "Audit entry recorded"
+        );
+
+        if let Some(path) = &self.path {
+            let written = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .and_then(|mut file| writeln!(file, "{}", entry.to_line()));
+            if let Err(e) = written {
+                tracing::error!(error = %e, path = ?path, "Failed to write audit log");
+            }
+        }
+
+        if let Ok(mut entries) = self.entries.lock() {
+            if entries.len() >= MAX_ENTRIES {
+                entries.pop_front();
+            }
+            entries.push_back(entry);
+        }
+    }
+
+    /// Entries ordered newest first.
+    pub fn recent(&self) -> Vec<AuditEntry> {
+        self.entries
+            .lock()
+            .map(|entries| entries.iter().rev().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+fn current_user() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "unknown".to_string())
+}
@@ -0,0 +1,171 @@
+use crate::error::Result;
+use crate::files;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Instant;
+use unifi_rs::device::{DeviceOverview, DeviceState};
+use uuid::Uuid;
+
+const RETENTION_DAYS: i64 = 7;
+const SAVE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+/// Devices going offline this often within a day are flagged as bouncing.
+pub const BOUNCE_THRESHOLD: usize = 3;
+
+/// A stretch of time during which a device was seen in the same state.
+struct Segment {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    online: bool,
+}
+
+/// Per-device online history built from the states seen on each refresh, so time
+/// the TUI was not running counts as neither up nor down. Kept for a week and stored
+/// as `device_id start end up|down` lines next to the aliases.
+pub struct Availability {
+    path: Option<PathBuf>,
+    segments: HashMap<Uuid, Vec<Segment>>,
+    last_saved: Option<Instant>,
+}
+
+impl Availability {
+    pub fn open() -> Self {
+        let path = files::data_file("availability");
+
+        let mut segments: HashMap<Uuid, Vec<Segment>> = HashMap::new();
+        if let Some(content) = path.as_ref().and_then(|p| std::fs::read_to_string(p).ok()) {
+            for line in content.lines() {
+                let mut fields = line.split_whitespace();
+                let (Some(id), Some(start), Some(end), Some(state)) =
+                    (fields.next(), fields.next(), fields.next(), fields.next())
+                else {
+                    continue;
+                };
+                let (Ok(id), Ok(start), Ok(end)) = (
+                    id.parse::<Uuid>(),
+                    DateTime::parse_from_rfc3339(start),
+                    DateTime::parse_from_rfc3339(end),
+                ) else {
+                    continue;
+                };
+                segments.entry(id).or_default().push(Segment {
+                    start: start.with_timezone(&Utc),
+                    end: end.with_timezone(&Utc),
+                    online: state == "up",
+                });
+            }
+        }
+
+        Self {
+            path,
+            segments,
+            last_saved: None,
+        }
+    }
+
+    /// Records the current state of `devices`. Observations further apart than
+    /// `max_gap` are not joined, leaving the time in between unaccounted for.
+    pub fn record(&mut self, devices: &[DeviceOverview], max_gap: Duration) -> Result<()> {
+        let now = Utc::now();
+        let mut changed = false;
+
+        for device in devices {
+            let online = matches!(device.state, DeviceState::Online);
+            let segments = self.segments.entry(device.id).or_default();
+            match segments.last_mut() {
+                Some(last) if now - last.end <= max_gap => {
+                    if last.online == online {
+                        last.end = now;
+                    } else {
+                        // The state changed somewhere since the last observation; count
+                        // the time from there in the new state.
+                        let start = last.end;
+                        segments.push(Segment {
+                            start,
+                            end: now,
+                            online,
+                        });
+                        changed = true;
+                    }
+                }
+                _ => {
+                    segments.push(Segment {
+                        start: now,
+                        end: now,
+                        online,
+                    });
+                    changed = true;
+                }
+            }
+        }
+
+        let cutoff = now - Duration::days(RETENTION_DAYS);
+        for segments in self.segments.values_mut() {
+            segments.retain(|segment| segment.end >= cutoff);
+        }
+        self.segments.retain(|_, segments| !segments.is_empty());
+
+        if changed
+            || self
+                .last_saved
+                .is_none_or(|at| at.elapsed() >= SAVE_INTERVAL)
+        {
+            self.save()?;
+            self.last_saved = Some(Instant::now());
+        }
+        Ok(())
+    }
+
+    /// Share of the observed time within `window` that the device was online.
+    pub fn percent(&self, device_id: Uuid, window: Duration) -> Option<f64> {
+        let since = Utc::now() - window;
+        let (mut up, mut total) = (0, 0);
+        for segment in self.segments.get(&device_id)? {
+            let start = segment.start.max(since);
+            if segment.end <= start {
+                continue;
+            }
+            let seconds = (segment.end - start).num_seconds();
+            total += seconds;
+            if segment.online {
+                up += seconds;
+            }
+        }
+        (total > 0).then(|| up as f64 * 100.0 / total as f64)
+    }
+
+    /// Number of times the device was seen going offline within `window`.
+    pub fn bounces(&self, device_id: Uuid, window: Duration) -> usize {
+        let since = Utc::now() - window;
+        self.segments.get(&device_id).map_or(0, |segments| {
+            segments
+                .windows(2)
+                .filter(|pair| pair[0].online && !pair[1].online && pair[1].start >= since)
+                .count()
+        })
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let contents: String = self
+            .segments
+            .iter()
+            .flat_map(|(id, segments)| {
+                segments.iter().map(move |segment| {
+                    format!(
+                        "{} {} {} {}\n",
+                        id,
+                        segment.start.to_rfc3339(),
+                        segment.end.to_rfc3339(),
+                        if segment.online { "up" } else { "down" }
+                    )
+                })
+            })
+            .collect();
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+use crate::app::{App, Mode, Tab};
+use crate::toast::ToastLevel;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+const UNDO_LIMIT: usize = 50;
+
+/// Changes to the app that input handlers request instead of mutating `App`
+/// directly. Actions are applied by [`reduce`], which also works out how to revert
+/// the ones that can be undone.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    Quit,
+    ToggleHelp,
+    OpenSiteSwitcher,
+    EnterSearch,
+    ClearSearch,
+    SetSearch(String),
+    JumpToMatch { forward: bool },
+    StartJump,
+    ToggleRowPopup,
+    ResizePanes { grow: bool },
+    NextTab,
+    PreviousTab,
+    ToggleSiteDashboard,
+    ToggleJobs,
+    ToggleAlerts,
+    ToggleActivity,
+    ToggleController,
+    ToggleErrors,
+    ToggleMaintenance,
+    SelectFavoriteSite(usize),
+    ExportReport,
+    RequestSnapshot,
+    RefreshNow,
+    Undo,
+}
+
+impl Action {
+    /// The action bound to a key on every screen, if any.
+    pub fn from_key(app: &App, key: KeyEvent) -> Option<Self> {
+        let action = match key.code {
+            KeyCode::Char('q') => Action::Quit,
+            KeyCode::Char('?') => Action::ToggleHelp,
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Action::OpenSiteSwitcher
+            }
+            KeyCode::Char('z') if key.modifiers.contains(KeyModifiers::CONTROL) => Action::Undo,
+            KeyCode::Char('/') => Action::EnterSearch,
+            KeyCode::Esc if !app.search_mode && !app.search_query.is_empty() => Action::ClearSearch,
+            KeyCode::Tab => Action::NextTab,
+            KeyCode::BackTab => Action::PreviousTab,
+            // While a search is active these take over from per-tab bindings such as the alias.
+            KeyCode::Char(c @ ('n' | 'N'))
+                if !app.search_mode
+                    && !app.search_query.is_empty()
+                    && app.mode == Mode::Overview
+                    && matches!(app.current_tab, Tab::Sites | Tab::Devices | Tab::Clients) =>
+            {
+                Action::JumpToMatch { forward: c == 'n' }
+            }
+            KeyCode::Char('\'')
+                if !app.search_mode
+                    && app.mode == Mode::Overview
+                    && matches!(app.current_tab, Tab::Sites | Tab::Devices | Tab::Clients) =>
+            {
+                Action::StartJump
+            }
+            KeyCode::Char('i')
+                if !app.search_mode
+                    && app.mode == Mode::Overview
+                    && matches!(app.current_tab, Tab::Sites | Tab::Devices | Tab::Clients) =>
+            {
+                Action::ToggleRowPopup
+            }
+            KeyCode::Char(c @ ('<' | '>')) if !app.search_mode && app.pane_view().is_some() => {
+                Action::ResizePanes { grow: c == '>' }
+            }
+            KeyCode::Char('J') if !app.search_mode => Action::ToggleJobs,
+            KeyCode::Char('W') if !app.search_mode => Action::ToggleAlerts,
+            KeyCode::Char('A') if !app.search_mode => Action::ToggleActivity,
+            KeyCode::Char('R') if !app.search_mode => Action::ExportReport,
+            KeyCode::Char('P') if !app.search_mode => Action::RequestSnapshot,
+            KeyCode::Char('I') if !app.search_mode => Action::ToggleController,
+            KeyCode::Char('!') if !app.search_mode => Action::ToggleErrors,
+            KeyCode::Char(c @ '1'..='9') if !app.search_mode => {
+                Action::SelectFavoriteSite(c as usize - '1' as usize)
+            }
+            KeyCode::Char('D') if !app.search_mode => Action::ToggleSiteDashboard,
+            KeyCode::Char('M') if !app.search_mode => Action::ToggleMaintenance,
+            KeyCode::F(5) => Action::RefreshNow,
+            _ => return None,
+        };
+        Some(action)
+    }
+}
+
+/// Applies an action to the app. Returns the action that reverts it when it can be
+/// undone.
+pub fn reduce(app: &mut App, action: Action) -> Option<Action> {
+    match action {
+        Action::Quit => app.should_quit = true,
+        Action::ToggleHelp => app.toggle_help(),
+        Action::OpenSiteSwitcher => app.open_site_switcher(),
+        Action::EnterSearch => app.enter_search_mode(),
+        Action::ClearSearch => {
+            let previous = app.search_query.clone();
+            app.clear_search();
+            return Some(Action::SetSearch(previous));
+        }
+        Action::SetSearch(query) => {
+            let previous = std::mem::replace(&mut app.search_query, query);
+            if app.search_query.is_empty() {
+                app.state.apply_filters();
+            } else {
+                app.state.search(&app.search_query);
+            }
+            return Some(Action::SetSearch(previous));
+        }
+        Action::JumpToMatch { forward } => app.jump_to_match(forward),
+        Action::StartJump => app.start_jump(),
+        Action::ToggleRowPopup => app.show_row_popup = !app.show_row_popup,
+        Action::ResizePanes { grow } => app.resize_panes(grow),
+        Action::NextTab => {
+            app.next_tab();
+            return Some(Action::PreviousTab);
+        }
+        Action::PreviousTab => {
+            app.previous_tab();
+            return Some(Action::NextTab);
+        }
+        Action::ToggleSiteDashboard => {
+            if app.mode == Mode::SiteDashboard {
+                app.mode = Mode::Overview;
+            } else if app.mode == Mode::Overview {
+                app.show_site_dashboard();
+            } else {
+                // Leaving a detail view can't be reverted without its selection.
+                app.show_site_dashboard();
+                return None;
+            }
+            return Some(Action::ToggleSiteDashboard);
+        }
+        Action::ToggleJobs => app.toggle_jobs(),
+        Action::ToggleAlerts => app.toggle_alerts(),
+        Action::ToggleActivity => app.toggle_activity(),
+        Action::ToggleController => app.toggle_controller(),
+        Action::ToggleErrors => app.toggle_errors(),
+        Action::ToggleMaintenance => app.toggle_maintenance(),
+        Action::SelectFavoriteSite(index) => app.select_favorite_site(index),
+        Action::ExportReport => app.export_report(),
+        Action::RequestSnapshot => app.snapshot_requested = true,
+        Action::RefreshNow => app.state.last_update -= app.state.refresh_interval,
+        Action::Undo => match app.undo_stack.pop() {
+            Some(inverse) => {
+                reduce(app, inverse);
+            }
+            None => app.state.notify(ToastLevel::Info, "Nothing to undo"),
+        },
+    }
+    None
+}
+
+/// Keeps the most recent inverse actions for `Ctrl+Z`.
+pub fn record_undo(stack: &mut Vec<Action>, inverse: Action) {
+    if stack.len() == UNDO_LIMIT {
+        stack.remove(0);
+    }
+    stack.push(inverse);
+}
@@ -0,0 +1,164 @@
+use crate::ui::icons::IconStyle;
+use crate::ui::panels::PanelLayout;
+use clap::ValueEnum;
+use directories::ProjectDirs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Settings read from the `config` file, one `key = value` per line. Lines starting
+/// with `#` are comments; a `#` anywhere else is part of the value, so hook commands
+/// can contain URL fragments and shell comments. Keys that are not set keep their
+/// current value.
+#[derive(Debug, Default)]
+pub struct Config {
+    pub refresh_interval: Option<Duration>,
+    pub cpu_alert_threshold: Option<f64>,
+    pub memory_alert_threshold: Option<f64>,
+    pub icons: Option<IconStyle>,
+    pub on_device_offline: Option<String>,
+    pub on_client_connected: Option<String>,
+    pub on_alert: Option<String>,
+    pub dashboard_layout: Option<PanelLayout>,
+    pub stats_layout: Option<PanelLayout>,
+}
+
+impl Config {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+        for (number, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("line {}: expected `key = value`", number + 1));
+            };
+            let (key, value) = (key.trim(), value.trim());
+            let invalid = || format!("line {}: invalid value `{}` for {}", number + 1, value, key);
+            match key {
+                "refresh_interval" => {
+                    let seconds: u64 = value.parse().map_err(|_| invalid())?;
+                    config.refresh_interval =
+                        Some(Duration::from_secs(seconds).max(MIN_REFRESH_INTERVAL));
+                }
+                "cpu_alert_threshold" => {
+                    config.cpu_alert_threshold = Some(parse_percent(value).ok_or_else(invalid)?);
+                }
+                "memory_alert_threshold" => {
+                    config.memory_alert_threshold = Some(parse_percent(value).ok_or_else(invalid)?);
+                }
+                "icons" => {
+                    config.icons = Some(IconStyle::from_str(value, true).map_err(|_| invalid())?);
+                }
+                "dashboard_layout" => {
+                    config.dashboard_layout = Some(
+                        PanelLayout::parse(value)
+                            .map_err(|e| format!("line {}: {}", number + 1, e))?,
+                    );
+                }
+                "stats_layout" => {
+                    config.stats_layout = Some(
+                        PanelLayout::parse(value)
+                            .map_err(|e| format!("line {}: {}", number + 1, e))?,
+                    );
+                }
+                // An empty command removes the hook.
+                "on_device_offline" => config.on_device_offline = Some(value.to_string()),
+                "on_client_connected" => config.on_client_connected = Some(value.to_string()),
+                "on_alert" => config.on_alert = Some(value.to_string()),
+                _ => return Err(format!("line {}: unknown setting {}", number + 1, key)),
+            }
+        }
+        Ok(config)
+    }
+}
+
+fn parse_percent(value: &str) -> Option<f64> {
+    value
+        .trim_end_matches('%')
+        .parse()
+        .ok()
+        .filter(|pct| (0.0..=100.0).contains(pct))
+}
+
+/// Re-reads a file in the config directory whenever its modification time changes.
+/// The file is polled rather than watched, which is cheap at this interval and works
+/// the same on every platform and filesystem.
+pub struct ConfigWatcher {
+    path: Option<PathBuf>,
+    modified: Option<SystemTime>,
+    last_check: Option<Instant>,
+}
+
+impl ConfigWatcher {
+    pub fn open() -> Self {
+        Self::open_file("config")
+    }
+
+    pub fn open_file(name: &str) -> Self {
+        let path = ProjectDirs::from("com", "unifi-tui", "unifi-tui")
+            .map(|dirs| dirs.config_dir().join(name));
+        Self {
+            path,
+            modified: None,
+            last_check: None,
+        }
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// The parsed config if the file changed since the last call (or exists on the
+    /// first call), and `None` while it is unchanged or missing.
+    pub fn poll(&mut self) -> Option<Result<Config, String>> {
+        self.poll_content()
+            .map(|content| content.and_then(|content| Config::parse(&content)))
+    }
+
+    /// Like [`poll`](Self::poll), but returns the file's content unparsed.
+    pub fn poll_content(&mut self) -> Option<Result<String, String>> {
+        if self
+            .last_check
+            .is_some_and(|at| at.elapsed() < CHECK_INTERVAL)
+        {
+            return None;
+        }
+        self.last_check = Some(Instant::now());
+
+        let path = self.path.as_ref()?;
+        let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+        if modified.is_none() || modified == self.modified {
+            self.modified = modified;
+            return None;
+        }
+        self.modified = modified;
+
+        Some(std::fs::read_to_string(path).map_err(|e| e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_only_starts_a_comment_at_the_start_of_a_line() {
+        let config = Config::parse(
+            "# alerts\n  # indented comment\non_alert = curl -s https://example.com/hook#alerts\n",
+        )
+        .expect("config");
+        assert_eq!(
+            config.on_alert.as_deref(),
+            Some("curl -s https://example.com/hook#alerts")
+        );
+    }
+
+    #[test]
+    fn trailing_text_is_part_of_the_value() {
+        assert!(Config::parse("refresh_interval = 10 # seconds").is_err());
+    }
+}
@@ -0,0 +1,323 @@
+use crate::alerts::Alert;
+use base64::Engine;
+use chrono::{Local, Utc};
+use clap::{Args, ValueEnum};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tokio::time::timeout;
+use tokio_native_tls::{native_tls, TlsConnector};
+
+/// How long one delivery may take end to end, so a server that stops answering
+/// can't stall every later alert.
+const SEND_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum SmtpSecurity {
+    /// Upgrade a plain connection with STARTTLS (usually port 587)
+    Starttls,
+    /// TLS from the first byte (usually port 465)
+    Tls,
+    /// No encryption (usually port 25)
+    None,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum EmailMode {
+    /// One email per refresh that raised alerts
+    Immediate,
+    /// Collect alerts and send them together every `--email-digest-minutes`
+    Digest,
+}
+
+#[derive(Args)]
+#[command(next_help_heading = "Email")]
+pub struct EmailArgs {
+    /// Send alerts by email through this SMTP server
+    #[arg(long, env)]
+    smtp_server: Option<String>,
+
+    #[arg(long, env, default_value = "587")]
+    smtp_port: u16,
+
+    #[arg(long, value_enum, default_value = "starttls")]
+    smtp_security: SmtpSecurity,
+
+    #[arg(long, env)]
+    smtp_username: Option<String>,
+
+    #[arg(long, env, hide_env_values = true)]
+    smtp_password: Option<String>,
+
+    /// Sender address
+    #[arg(long, env, default_value = "unifi-tui@localhost")]
+    email_from: String,
+
+    /// Recipient addresses, comma separated
+    #[arg(long, env, value_delimiter = ',')]
+    email_to: Vec<String>,
+
+    #[arg(long, value_enum, default_value = "immediate")]
+    email_mode: EmailMode,
+
+    #[arg(long, default_value = "60")]
+    email_digest_minutes: u64,
+}
+
+impl EmailArgs {
+    pub fn notifier(self) -> Option<EmailNotifier> {
+        let server = self.smtp_server?;
+        if self.email_to.is_empty() {
+            tracing::warn!("--smtp-server is set but --email-to is empty, email alerts disabled");
+            return None;
+        }
+        Some(EmailNotifier::start(SmtpConfig {
+            server,
+            port: self.smtp_port,
+            security: self.smtp_security,
+            username: self.smtp_username,
+            password: self.smtp_password,
+            from: self.email_from,
+            to: self.email_to,
+            mode: self.email_mode,
+            digest_interval: Duration::from_secs(self.email_digest_minutes.max(1) * 60),
+        }))
+    }
+}
+
+struct SmtpConfig {
+    server: String,
+    port: u16,
+    security: SmtpSecurity,
+    username: Option<String>,
+    password: Option<String>,
+    from: String,
+    to: Vec<String>,
+    mode: EmailMode,
+    digest_interval: Duration,
+}
+
+/// Email channel for alerts. Mail is sent from a background task so a slow SMTP
+/// server never holds up a refresh; failures are queued for the error console.
+pub struct EmailNotifier {
+    tx: UnboundedSender<Vec<String>>,
+    errors: UnboundedReceiver<String>,
+}
+
+impl EmailNotifier {
+    fn start(config: SmtpConfig) -> Self {
+        let (tx, rx) = unbounded_channel();
+        let (error_tx, errors) = unbounded_channel();
+        tokio::spawn(run(config, rx, error_tx));
+        Self { tx, errors }
+    }
+
+    pub fn notify(&self, alerts: &[Alert]) {
+        if alerts.is_empty() {
+            return;
+        }
+        let lines = alerts
+            .iter()
+            .map(|alert| {
+                format!(
+                    "{}  {}",
+                    alert
+                        .raised_at
+                        .with_timezone(&Local)
+                        .format("%Y-%m-%d %H:%M:%S"),
+                    alert.message
+                )
+            })
+            .collect();
+        if self.tx.send(lines).is_err() {
+            tracing::warn!("Email notifier stopped");
+        }
+    }
+
+    pub fn drain_errors(&mut self) -> Vec<String> {
+        let mut errors = Vec::new();
+        while let Ok(error) = self.errors.try_recv() {
+            errors.push(error);
+        }
+        errors
+    }
+}
+
+async fn run(
+    config: SmtpConfig,
+    mut rx: UnboundedReceiver<Vec<String>>,
+    errors: UnboundedSender<String>,
+) {
+    let mut pending: Vec<String> = Vec::new();
+    let mut digest = tokio::time::interval(config.digest_interval);
+    digest.tick().await;
+
+    loop {
+        let flush = tokio::select! {
+            lines = rx.recv() => {
+                let Some(lines) = lines else {
+                    return;
+                };
+                pending.extend(lines);
+                matches!(config.mode, EmailMode::Immediate)
+            }
+            _ = digest.tick() => matches!(config.mode, EmailMode::Digest),
+        };
+        if !flush || pending.is_empty() {
+            continue;
+        }
+
+        let subject = match pending.len() {
+            1 => "UniFi alert".to_string(),
+            n => format!("{} UniFi alerts", n),
+        };
+        let body = pending.join("\r\n");
+        let sent = match timeout(SEND_TIMEOUT, send_mail(&config, &subject, &body)).await {
+            Ok(result) => result,
+            Err(_) => Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                format!(
+                    "no answer from the server within {}s",
+                    SEND_TIMEOUT.as_secs()
+                ),
+            )),
+        };
+        match sent {
+            Ok(()) => {
+                tracing::info!(count = pending.len(), "Sent alert email");
+                pending.clear();
+            }
+            Err(e) => {
+                // Keep the alerts for the next attempt rather than losing them.
+                tracing::warn!(error = %e, "Failed to send alert email");
+                let _ = errors.send(format!("Failed to send alert email: {}", e));
+            }
+        }
+    }
+}
+
+async fn send_mail(config: &SmtpConfig, subject: &str, body: &str) -> std::io::Result<()> {
+    let tcp = TcpStream::connect((config.server.as_str(), config.port)).await?;
+    match config.security {
+        SmtpSecurity::None => {
+            let mut smtp = Smtp::new(tcp);
+            smtp.greet().await?;
+            smtp.deliver(config, subject, body).await
+        }
+        SmtpSecurity::Tls => {
+            let mut smtp = Smtp::new(tls(config, tcp).await?);
+            smtp.greet().await?;
+            smtp.deliver(config, subject, body).await
+        }
+        SmtpSecurity::Starttls => {
+            let mut plain = Smtp::new(tcp);
+            plain.greet().await?;
+            plain.command("STARTTLS", 220).await?;
+            let mut smtp = Smtp::new(tls(config, plain.into_inner()).await?);
+            smtp.deliver(config, subject, body).await
+        }
+    }
+}
+
+async fn tls(
+    config: &SmtpConfig,
+    tcp: TcpStream,
+) -> std::io::Result<tokio_native_tls::TlsStream<TcpStream>> {
+    let connector = native_tls::TlsConnector::new().map_err(std::io::Error::other)?;
+    TlsConnector::from(connector)
+        .connect(&config.server, tcp)
+        .await
+        .map_err(std::io::Error::other)
+}
+
+struct Smtp<S> {
+    stream: BufReader<S>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> Smtp<S> {
+    fn new(stream: S) -> Self {
+        Self {
+            stream: BufReader::new(stream),
+        }
+    }
+
+    fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+
+    async fn greet(&mut self) -> std::io::Result<()> {
+        self.expect(220).await
+    }
+
+    async fn deliver(
+        &mut self,
+        config: &SmtpConfig,
+        subject: &str,
+        body: &str,
+    ) -> std::io::Result<()> {
+        self.command("EHLO unifi-tui", 250).await?;
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            let credentials = base64::engine::general_purpose::STANDARD
+                .encode(format!("\0{}\0{}", username, password));
+            self.command(&format!("AUTH PLAIN {}", credentials), 235)
+                .await?;
+        }
+
+        self.command(&format!("MAIL FROM:<{}>", config.from), 250)
+            .await?;
+        for recipient in &config.to {
+            self.command(&format!("RCPT TO:<{}>", recipient), 250)
+                .await?;
+        }
+        self.command("DATA", 354).await?;
+
+        let mut message = format!(
+            "From: {}\r\nTo: {}\r\nSubject: {}\r\nDate: {}\r\n\
+             Content-Type: text/plain; charset=utf-8\r\n\r\n",
+            config.from,
+            config.to.join(", "),
+            subject,
+            Utc::now().to_rfc2822()
+        );
+        for line in body.lines() {
+            // Dot-stuffing, so a line starting with "." does not end the message early.
+            if line.starts_with('.') {
+                message.push('.');
+            }
+            message.push_str(line);
+            message.push_str("\r\n");
+        }
+        message.push('.');
+        self.command(&message, 250).await?;
+        self.command("QUIT", 221).await
+    }
+
+    async fn command(&mut self, line: &str, expected: u16) -> std::io::Result<()> {
+        let stream = self.stream.get_mut();
+        stream.write_all(line.as_bytes()).await?;
+        stream.write_all(b"\r\n").await?;
+        stream.flush().await?;
+        self.expect(expected).await
+    }
+
+    /// Reads a possibly multi-line reply and checks its status code.
+    async fn expect(&mut self, expected: u16) -> std::io::Result<()> {
+        loop {
+            let mut line = String::new();
+            if self.stream.read_line(&mut line).await? == 0 {
+                return Err(std::io::ErrorKind::UnexpectedEof.into());
+            }
+            let code = line.get(..3).and_then(|c| c.parse::<u16>().ok());
+            if code != Some(expected) {
+                return Err(std::io::Error::other(format!(
+                    "SMTP server replied: {}",
+                    line.trim_end()
+                )));
+            }
+            if line.as_bytes().get(3) != Some(&b'-') {
+                return Ok(());
+            }
+        }
+    }
+}
@@ -7,7 +7,19 @@ pub enum AppError {
     Io(#[from] io::Error),
 
     #[error("UniFi error: {0}")]
-    UniFi(#[from] unifi_rs::UnifiError),
+    UniFi(#[source] unifi_rs::UnifiError),
+
+    #[error("Authentication failed: {0}")]
+    Auth(String),
+
+    #[error("Rate limited by the controller: {0}")]
+    RateLimited(String),
+
+    #[error("Request timed out: {0}")]
+    Timeout(String),
+
+    #[error("Unexpected API response: {0}")]
+    Schema(String),
 
     #[error("Application error: {0}")]
     Application(String),
@@ -16,6 +28,69 @@ pub enum AppError {
     Other(#[from] anyhow::Error),
 }
 
+/// How the UI should react to an error beyond reporting it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErrorTreatment {
+    Reauthenticate,
+    Backoff,
+    Retry,
+    Report,
+}
+
+impl AppError {
+    pub fn treatment(&self) -> ErrorTreatment {
+        match self {
+            AppError::Auth(_) => ErrorTreatment::Reauthenticate,
+            AppError::RateLimited(_) => ErrorTreatment::Backoff,
+            AppError::Timeout(_) => ErrorTreatment::Retry,
+            _ => ErrorTreatment::Report,
+        }
+    }
+
+    pub fn hint(&self) -> Option<&'static str> {
+        match self {
+            AppError::Auth(_) => Some("Check that the API key is valid and has not been revoked"),
+            AppError::RateLimited(_) => Some("Refreshing less often until the controller recovers"),
+            AppError::Timeout(_) => Some("Retrying on the next refresh"),
+            AppError::Schema(_) => Some("The controller may be newer than unifi-rs supports"),
+            _ => None,
+        }
+    }
+
+    /// Short form for table cells where the full message doesn't fit.
+    pub fn label(&self) -> &'static str {
+        match self {
+            AppError::Auth(_) => "auth error",
+            AppError::RateLimited(_) => "rate limited",
+            AppError::Timeout(_) => "timeout",
+            AppError::Schema(_) => "bad response",
+            AppError::Io(_) | AppError::UniFi(_) => "network error",
+            _ => "error",
+        }
+    }
+}
+
+// The API's own error responses carry their status code; transport failures come as
+// reqwest errors, which know whether they timed out, failed to decode or got a status.
+impl From<unifi_rs::UnifiError> for AppError {
+    fn from(error: unifi_rs::UnifiError) -> Self {
+        let status = match &error {
+            unifi_rs::UnifiError::Api { status_code, .. } => Some(*status_code),
+            unifi_rs::UnifiError::Http(e) => e.status().map(|s| s.as_u16()),
+            _ => None,
+        };
+        let message = error.to_string();
+
+        match (status, &error) {
+            (Some(401 | 403), _) => AppError::Auth(message),
+            (Some(429), _) => AppError::RateLimited(message),
+            (_, unifi_rs::UnifiError::Http(e)) if e.is_timeout() => AppError::Timeout(message),
+            (_, unifi_rs::UnifiError::Http(e)) if e.is_decode() => AppError::Schema(message),
+            _ => AppError::UniFi(error),
+        }
+    }
+}
+
 impl From<AppError> for io::Error {
     fn from(error: AppError) -> Self {
         match error {
@@ -26,3 +101,33 @@ impl From<AppError> for io::Error {
 }
 
 pub type Result<T> = std::result::Result<T, AppError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_error(status_code: u16, message: &str) -> AppError {
+        unifi_rs::UnifiError::Api {
+            status_code,
+            message: message.to_string(),
+        }
+        .into()
+    }
+
+    #[test]
+    fn classifies_api_errors_by_status_code() {
+        assert!(matches!(api_error(401, "Unauthorized"), AppError::Auth(_)));
+        assert!(matches!(api_error(403, "Forbidden"), AppError::Auth(_)));
+        assert!(matches!(
+            api_error(429, "Slow down"),
+            AppError::RateLimited(_)
+        ));
+    }
+
+    #[test]
+    fn ignores_status_codes_in_the_message() {
+        let error = api_error(404, "Site 4290a401-0403-4429-8401-403429401403 not found");
+        assert_eq!(error.treatment(), ErrorTreatment::Report);
+        assert_eq!(error.label(), "network error");
+    }
+}
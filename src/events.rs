@@ -0,0 +1,238 @@
+use crate::alerts::Alert;
+use crate::state::AppState;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use unifi_rs::models::client::ClientOverview;
+use uuid::Uuid;
+
+/// A change noticed between two refreshes, in the shape every sink publishes.
+pub enum NetworkEvent {
+    DeviceState {
+        device_id: Uuid,
+        name: String,
+        from: String,
+        to: String,
+    },
+    DeviceAdded {
+        device_id: Uuid,
+        name: String,
+        state: String,
+    },
+    ClientConnected {
+        client_id: Uuid,
+        name: String,
+    },
+    ClientDisconnected {
+        client_id: Uuid,
+        name: String,
+    },
+    Alert {
+        kind: String,
+        device_id: Uuid,
+        message: String,
+    },
+}
+
+impl NetworkEvent {
+    pub fn from_alert(alert: &Alert) -> Self {
+        NetworkEvent::Alert {
+            kind: format!("{:?}", alert.kind),
+            device_id: alert.device_id,
+            message: alert.message.clone(),
+        }
+    }
+
+    /// The device the event is about, if any.
+    pub fn device_id(&self) -> Option<Uuid> {
+        match self {
+            NetworkEvent::DeviceState { device_id, .. }
+            | NetworkEvent::DeviceAdded { device_id, .. }
+            | NetworkEvent::Alert { device_id, .. } => Some(*device_id),
+            NetworkEvent::ClientConnected { .. } | NetworkEvent::ClientDisconnected { .. } => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            NetworkEvent::DeviceState { .. } => "device_state",
+            NetworkEvent::DeviceAdded { .. } => "device_added",
+            NetworkEvent::ClientConnected { .. } => "client_connected",
+            NetworkEvent::ClientDisconnected { .. } => "client_disconnected",
+            NetworkEvent::Alert { .. } => "alert",
+        }
+    }
+
+    /// One-line human readable form, for sinks that carry plain text.
+    pub fn describe(&self) -> String {
+        match self {
+            NetworkEvent::DeviceState { name, from, to, .. } => {
+                format!("{} changed from {} to {}", name, from, to)
+            }
+            NetworkEvent::DeviceAdded { name, state, .. } => {
+                format!("{} was added ({})", name, state)
+            }
+            NetworkEvent::ClientConnected { name, .. } => format!("{} connected", name),
+            NetworkEvent::ClientDisconnected { name, .. } => format!("{} disconnected", name),
+            NetworkEvent::Alert { message, .. } => message.clone(),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        match self {
+            NetworkEvent::DeviceState {
+                device_id,
+                name,
+                from,
+                to,
+            } => json!({
+                "event": self.name(),
+                "device_id": device_id.to_string(),
+                "name": name,
+                "from": from,
+                "to": to,
+            }),
+            NetworkEvent::DeviceAdded {
+                device_id,
+                name,
+                state,
+            } => json!({
+                "event": self.name(),
+                "device_id": device_id.to_string(),
+                "name": name,
+                "state": state,
+            }),
+            NetworkEvent::ClientConnected { client_id, name }
+            | NetworkEvent::ClientDisconnected { client_id, name } => json!({
+                "event": self.name(),
+                "client_id": client_id.to_string(),
+                "name": name,
+            }),
+            NetworkEvent::Alert {
+                kind,
+                device_id,
+                message,
+            } => json!({
+                "event": self.name(),
+                "kind": kind,
+                "device_id": device_id.to_string(),
+                "message": message,
+            }),
+        }
+    }
+}
+
+/// Remembers device states and connected clients from the previous refresh.
+/// The first call, and the first call after the site context changes, only
+/// establish a baseline and report nothing.
+#[derive(Default)]
+pub struct ChangeTracker {
+    site_id: Option<Uuid>,
+    devices: Option<HashMap<Uuid, String>>,
+    clients: HashMap<Uuid, String>,
+    /// Site of every device and client above.
+    sites: HashMap<Uuid, Uuid>,
+}
+
+impl ChangeTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn diff(&mut self, state: &AppState) -> Vec<NetworkEvent> {
+        let site_id = state.selected_site.as_ref().map(|s| s.site_id);
+        if site_id != self.site_id {
+            self.site_id = site_id;
+            self.devices = None;
+            self.sites.clear();
+        }
+
+        let mut current_devices: HashMap<Uuid, String> = state
+            .devices
+            .iter()
+            .map(|d| (d.id, format!("{:?}", d.state)))
+            .collect();
+        let mut current_sites = state.device_sites.clone();
+        let mut current_clients = HashMap::new();
+        for client in &state.clients {
+            let (id, uplink, name) = match client {
+                ClientOverview::Wired(c) => (
+                    c.base.id,
+                    c.uplink_device_id,
+                    state
+                        .aliases
+                        .display_name(&c.mac_address, c.base.name.as_deref()),
+                ),
+                ClientOverview::Wireless(c) => (
+                    c.base.id,
+                    c.uplink_device_id,
+                    state
+                        .aliases
+                        .display_name(&c.mac_address, c.base.name.as_deref()),
+                ),
+                _ => continue,
+            };
+            if let Some(site) = state.device_sites.get(&uplink) {
+                current_sites.insert(id, *site);
+            }
+            current_clients.insert(id, name);
+        }
+
+        // A site that failed to load still has its devices and clients; keep the last
+        // known ones so neither the failure nor the recovery is reported as changes.
+        for (id, site) in &self.sites {
+            if !state.site_errors.contains_key(site) {
+                continue;
+            }
+            if let Some(before) = self.devices.as_ref().and_then(|d| d.get(id)) {
+                current_devices.entry(*id).or_insert_with(|| before.clone());
+            } else if let Some(name) = self.clients.get(id) {
+                current_clients.entry(*id).or_insert_with(|| name.clone());
+            } else {
+                continue;
+            }
+            current_sites.entry(*id).or_insert(*site);
+        }
+
+        let mut events = Vec::new();
+        if let Some(previous) = &self.devices {
+            for device in &state.devices {
+                let current = &current_devices[&device.id];
+                match previous.get(&device.id) {
+                    Some(before) if before != current => events.push(NetworkEvent::DeviceState {
+                        device_id: device.id,
+                        name: device.name.clone(),
+                        from: before.clone(),
+                        to: current.clone(),
+                    }),
+                    None => events.push(NetworkEvent::DeviceAdded {
+                        device_id: device.id,
+                        name: device.name.clone(),
+                        state: current.clone(),
+                    }),
+                    _ => {}
+                }
+            }
+            for (client_id, name) in &current_clients {
+                if !self.clients.contains_key(client_id) {
+                    events.push(NetworkEvent::ClientConnected {
+                        client_id: *client_id,
+                        name: name.clone(),
+                    });
+                }
+            }
+            for (client_id, name) in &self.clients {
+                if !current_clients.contains_key(client_id) {
+                    events.push(NetworkEvent::ClientDisconnected {
+                        client_id: *client_id,
+                        name: name.clone(),
+                    });
+                }
+            }
+        }
+
+        self.devices = Some(current_devices);
+        self.clients = current_clients;
+        self.sites = current_sites;
+        events
+    }
+}
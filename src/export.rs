@@ -0,0 +1,104 @@
+use crate::state::AppState;
+use serde_json::{json, Value};
+use unifi_rs::models::client::ClientOverview;
+
+pub fn sites(state: &AppState) -> Value {
+    state
+        .sites
+        .iter()
+        .map(|site| json!({ "id": site.id.to_string(), "name": site.name }))
+        .collect()
+}
+
+pub fn devices(state: &AppState) -> Value {
+    state
+        .devices
+        .iter()
+        .map(|device| {
+            json!({
+                "id": device.id.to_string(),
+                "name": device.name,
+                "model": device.model,
+                "mac": device.mac_address,
+                "ip": device.ip_address,
+                "state": format!("{:?}", device.state),
+                "site_id": state.device_sites.get(&device.id).map(|id| id.to_string()),
+            })
+        })
+        .collect()
+}
+
+pub fn clients(state: &AppState) -> Value {
+    state
+        .clients
+        .iter()
+        .filter_map(|client| {
+            let (kind, id, name, ip_address, mac_address, uplink_device_id) = match client {
+                ClientOverview::Wired(c) => (
+                    "Wired",
+                    c.base.id,
+                    c.base.name.as_deref(),
+                    &c.base.ip_address,
+                    &c.mac_address,
+                    c.uplink_device_id,
+                ),
+                ClientOverview::Wireless(c) => (
+                    "Wireless",
+                    c.base.id,
+                    c.base.name.as_deref(),
+                    &c.base.ip_address,
+                    &c.mac_address,
+                    c.uplink_device_id,
+                ),
+                _ => return None,
+            };
+            Some(json!({
+                "id": id.to_string(),
+                "name": state.aliases.display_name(mac_address, name),
+                "type": kind,
+                "ip": ip_address,
+                "mac": mac_address,
+                "uplink_device_id": uplink_device_id.to_string(),
+            }))
+        })
+        .collect()
+}
+
+/// The most recent stats sample, or `null` before the first refresh.
+pub fn stats(state: &AppState) -> Value {
+    let Some(stats) = state.stats_history.back() else {
+        return Value::Null;
+    };
+    let devices: Vec<_> = stats
+        .device_stats
+        .iter()
+        .map(|m| {
+            json!({
+                "device_id": m.device_id.to_string(),
+                "name": m.device_name,
+                "cpu_utilization": m.cpu_utilization,
+                "memory_utilization": m.memory_utilization,
+                "uptime": m.uptime,
+                "tx_rate_bps": m.tx_rate,
+                "rx_rate_bps": m.rx_rate,
+            })
+        })
+        .collect();
+
+    json!({
+        "timestamp": stats.timestamp.to_rfc3339(),
+        "client_count": stats.client_count,
+        "wireless_clients": stats.wireless_clients,
+        "wired_clients": stats.wired_clients,
+        "devices": devices,
+    })
+}
+
+/// Everything currently loaded, as one document.
+pub fn all(state: &AppState) -> Value {
+    json!({
+        "sites": sites(state),
+        "devices": devices(state),
+        "clients": clients(state),
+    })
+}
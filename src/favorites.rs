@@ -0,0 +1,65 @@
+use crate::error::Result;
+use crate::files;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// Sites pinned by the user, in the order they were pinned. Stored one ID per line
+/// next to the aliases so the order survives restarts.
+pub struct FavoriteSites {
+    path: Option<PathBuf>,
+    ids: Vec<Uuid>,
+}
+
+impl FavoriteSites {
+    pub fn open() -> Self {
+        let path = files::data_file("favorite_sites");
+
+        let ids = path
+            .as_ref()
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .map(|content| {
+                content
+                    .lines()
+                    .filter_map(|line| line.trim().parse().ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self { path, ids }
+    }
+
+    pub fn position(&self, site_id: Uuid) -> Option<usize> {
+        self.ids.iter().position(|id| *id == site_id)
+    }
+
+    /// The `index`th pinned site, counting from zero.
+    pub fn get(&self, index: usize) -> Option<Uuid> {
+        self.ids.get(index).copied()
+    }
+
+    /// Pins or unpins `site_id`, returning whether it is now a favorite.
+    pub fn toggle(&mut self, site_id: Uuid) -> Result<bool> {
+        let pinned = match self.position(site_id) {
+            Some(index) => {
+                self.ids.remove(index);
+                false
+            }
+            None => {
+                self.ids.push(site_id);
+                true
+            }
+        };
+        self.save()?;
+        Ok(pinned)
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let contents: String = self.ids.iter().map(|id| format!("{}\n", id)).collect();
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use directories::ProjectDirs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// Keeps the process from writing to disk (`--no-files`): aliases, favorites, history
+/// and the audit log then only live in memory, and nothing is saved to the data or
+/// cache directory.
+pub fn disable() {
+    DISABLED.store(true, Ordering::Relaxed);
+}
+
+/// Where persisted state lives, or `None` when files are disabled.
+pub fn project_dirs() -> Option<ProjectDirs> {
+    if DISABLED.load(Ordering::Relaxed) {
+        return None;
+    }
+    ProjectDirs::from("com", "unifi-tui", "unifi-tui")
+}
+
+/// Path of a file in the data directory, which is created if needed. `None` when
+/// files are disabled or the directory can't be created.
+pub fn data_file(name: &str) -> Option<PathBuf> {
+    let dirs = project_dirs()?;
+    let data_dir = dirs.data_dir();
+    std::fs::create_dir_all(data_dir).ok()?;
+    Some(data_dir.join(name))
+}
+
+/// Like [`project_dirs`], for saving something the user asked for.
+pub fn require_project_dirs() -> std::io::Result<ProjectDirs> {
+    if DISABLED.load(Ordering::Relaxed) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "writing files is disabled by --no-files",
+        ));
+    }
+    ProjectDirs::from("com", "unifi-tui", "unifi-tui").ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::NotFound, "No data directory available")
+    })
+}
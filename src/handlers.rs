@@ -1,43 +1,44 @@
-use crate::app::{App, DialogType};
+use crate::app::{App, DialogType, Mode};
+use crate::bus::Action;
 use crate::error::Result;
+use crate::ui::widgets::chart::{ChartCursor, ChartWindow};
 use crossterm::event::{KeyCode, KeyEvent};
 
 pub async fn handle_global_input(app: &mut App, key: KeyEvent) -> Result<bool> {
-    match key.code {
-        KeyCode::Char('q') => {
-            app.should_quit = true;
-            Ok(true)
-        }
-        KeyCode::Char('?') => {
-            app.toggle_help();
-            Ok(true)
-        }
-        KeyCode::Char('/') => {
-            app.enter_search_mode();
-            Ok(true)
-        }
-        KeyCode::Esc if !app.search_mode && !app.search_query.is_empty() => {
-            app.clear_search();
-            Ok(true)
-        }
-        KeyCode::Tab => {
-            app.next_tab();
+    match Action::from_key(app, key) {
+        Some(action) => {
+            app.dispatch(action);
             Ok(true)
         }
-        KeyCode::BackTab => {
-            app.previous_tab();
-            Ok(true)
-        }
-        KeyCode::F(5) => {
-            app.state.last_update -= app.state.refresh_interval;
-            Ok(true)
-        }
-        _ => Ok(false),
+        None => Ok(false),
     }
 }
 
 pub async fn handle_dialog_input(app: &mut App, key: KeyEvent) -> Result<()> {
-    if let Some(dialog) = app.dialog.take() {
+    if let Some(mut dialog) = app.dialog.take() {
+        if let DialogType::TypedConfirmation { expected } = &dialog.dialog_type {
+            match key.code {
+                KeyCode::Enter if dialog.input == *expected => {
+                    if let Some(callback) = dialog.callback {
+                        callback(app)?;
+                    }
+                }
+                KeyCode::Esc => {}
+                KeyCode::Char(c) => {
+                    dialog.input.push(c);
+                    app.dialog = Some(dialog);
+                }
+                KeyCode::Backspace => {
+                    dialog.input.pop();
+                    app.dialog = Some(dialog);
+                }
+                _ => {
+                    app.dialog = Some(dialog);
+                }
+            }
+            return Ok(());
+        }
+
         match key.code {
             KeyCode::Char('y') | KeyCode::Enter
                 if dialog.dialog_type == DialogType::Confirmation =>
@@ -55,14 +56,56 @@ pub async fn handle_dialog_input(app: &mut App, key: KeyEvent) -> Result<()> {
     Ok(())
 }
 
+/// Keys while jumping to a row. Returns false for keys that end the jump without
+/// being used, so they still reach the current view.
+pub fn handle_jump_input(app: &mut App, key: KeyEvent) -> bool {
+    let Some(query) = app.jump_query.as_mut() else {
+        return false;
+    };
+    match key.code {
+        KeyCode::Char(c) => query.push(c),
+        KeyCode::Backspace => {
+            query.pop();
+        }
+        KeyCode::Esc | KeyCode::Enter => {
+            app.jump_query = None;
+            return true;
+        }
+        _ => {
+            app.jump_query = None;
+            return false;
+        }
+    }
+    app.jump_to_prefix();
+    true
+}
+
 pub async fn handle_search_input(app: &mut App, key: KeyEvent) -> Result<()> {
     match key.code {
         KeyCode::Esc => {
             app.exit_search_mode();
         }
         KeyCode::Enter => {
+            if let Err(e) = app.search_history.record(&app.search_query) {
+                app.state
+                    .log_error(format!("Failed to save search history: {}", e));
+            }
             app.exit_search_mode();
         }
+        KeyCode::Up => {
+            if let Some(query) = app.search_history.older() {
+                app.search_query = query.to_string();
+                app.state.search(&app.search_query);
+            }
+        }
+        KeyCode::Down if app.search_history.is_browsing() => {
+            app.search_query = app
+                .search_history
+                .newer()
+                .map(str::to_string)
+                .unwrap_or_default();
+            app.state.search(&app.search_query);
+        }
         KeyCode::Char(c) => {
             app.search_query.push(c);
             app.state.search(&app.search_query);
@@ -78,29 +121,133 @@ pub async fn handle_search_input(app: &mut App, key: KeyEvent) -> Result<()> {
     Ok(())
 }
 
+/// Keys while a chart fills the screen. Only chart controls are passed on, so the
+/// view underneath cannot change until full-screen mode is left with `f` or Esc.
+pub async fn handle_fullscreen_chart_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    let cursor_active = match app.mode {
+        Mode::DeviceDetail => app
+            .device_stats_view
+            .as_ref()
+            .is_some_and(|view| view.cursor.is_some()),
+        _ => app.stats_cursor.is_some(),
+    };
+    match key.code {
+        KeyCode::Char('f') => app.chart_fullscreen = false,
+        KeyCode::Esc if !cursor_active => app.chart_fullscreen = false,
+        KeyCode::Left | KeyCode::Right if !cursor_active => {}
+        KeyCode::Left
+        | KeyCode::Right
+        | KeyCode::Up
+        | KeyCode::Down
+        | KeyCode::Esc
+        | KeyCode::Char('c')
+        | KeyCode::Char('b')
+        | KeyCode::Char('+')
+        | KeyCode::Char('=')
+        | KeyCode::Char('-')
+        | KeyCode::Char('[')
+        | KeyCode::Char(']')
+        | KeyCode::Char('0') => match app.mode {
+            Mode::DeviceDetail => handle_device_detail_input(app, key).await?,
+            _ => crate::ui::stats::handle_stats_input(app, key)?,
+        },
+        _ => {}
+    }
+    Ok(())
+}
+
 pub async fn handle_device_detail_input(app: &mut App, key: KeyEvent) -> Result<()> {
+    if let Some(view) = app.device_stats_view.as_mut() {
+        let total = view.history_len(&app.state);
+        match key.code {
+            KeyCode::Char('+') | KeyCode::Char('=') => view.window.zoom_in(total),
+            KeyCode::Char('-') => view.window.zoom_out(total),
+            KeyCode::Char('[') => view.window.pan_older(total),
+            KeyCode::Char(']') => view.window.pan_newer(),
+            KeyCode::Char('0') => view.window = ChartWindow::default(),
+            KeyCode::Up | KeyCode::Down => view.toggle_focus(&app.state),
+            _ => {}
+        }
+
+        // While the chart cursor is active the arrows move it instead of switching tabs.
+        let len = view.window.range(total).len();
+        if let Some(cursor) = view.cursor.as_mut() {
+            cursor.clamp(len);
+            let handled = match key.code {
+                KeyCode::Left => {
+                    cursor.older(len);
+                    true
+                }
+                KeyCode::Right => {
+                    cursor.newer();
+                    true
+                }
+                KeyCode::Esc | KeyCode::Char('c') => {
+                    view.cursor = None;
+                    true
+                }
+                _ => false,
+            };
+            if handled {
+                return Ok(());
+            }
+        }
+    }
+
     match key.code {
         KeyCode::Esc => {
             app.back_to_overview();
         }
+        KeyCode::Char('c') => {
+            if let Some(view) = app.device_stats_view.as_mut() {
+                // The charts live on the Performance tab.
+                view.current_tab = 1;
+                view.cursor = Some(ChartCursor::default());
+            }
+        }
+        KeyCode::Char('f') => {
+            if let Some(view) = app.device_stats_view.as_mut() {
+                view.current_tab = 1;
+                app.chart_fullscreen = true;
+            }
+        }
+        KeyCode::Char('x') => {
+            if let Some(device_id) = app.selected_device_id {
+                app.request_ssh(device_id);
+            }
+        }
+        KeyCode::Char('w') => {
+            if let Some(device_id) = app.selected_device_id {
+                app.open_device_in_browser(device_id);
+            }
+        }
+        KeyCode::Char('r') => {
+            if let Some(device_id) = app.selected_device_id {
+                app.refresh_device(device_id);
+            }
+        }
         KeyCode::Tab => {
             if let Some(view) = app.device_stats_view.as_mut() {
-                view.current_tab = (view.current_tab + 1) % 4;
+                let count = view.tab_count(&app.state);
+                view.current_tab = (view.current_tab + 1) % count;
             }
         }
         KeyCode::BackTab => {
             if let Some(view) = app.device_stats_view.as_mut() {
-                view.current_tab = (view.current_tab + 3) % 4;
+                let count = view.tab_count(&app.state);
+                view.current_tab = (view.current_tab + count - 1) % count;
             }
         }
         KeyCode::Right => {
             if let Some(view) = app.device_stats_view.as_mut() {
-                view.current_tab = (view.current_tab + 1) % 4;
+                let count = view.tab_count(&app.state);
+                view.current_tab = (view.current_tab + 1) % count;
             }
         }
         KeyCode::Left => {
             if let Some(view) = app.device_stats_view.as_mut() {
-                view.current_tab = (view.current_tab + 3) % 4;
+                let count = view.tab_count(&app.state);
+                view.current_tab = (view.current_tab + count - 1) % count;
             }
         }
         _ => {}
@@ -109,8 +256,19 @@ pub async fn handle_device_detail_input(app: &mut App, key: KeyEvent) -> Result<
 }
 
 pub async fn handle_client_detail_input(app: &mut App, key: KeyEvent) -> Result<()> {
-    if key.code == KeyCode::Esc {
-        app.back_to_overview();
+    match key.code {
+        KeyCode::Esc => app.back_to_overview(),
+        KeyCode::Char('n') => {
+            if let Some(client_id) = app.selected_client_id {
+                app.open_alias_prompt(client_id);
+            }
+        }
+        KeyCode::Char('w') => {
+            if let Some(client_id) = app.selected_client_id {
+                app.open_client_in_browser(client_id);
+            }
+        }
+        _ => {}
     }
     Ok(())
 }
@@ -0,0 +1,396 @@
+use crate::actions::{run_device_action, DeviceAction};
+use crate::alerts::AlertMonitor;
+use crate::audit::AuditLog;
+use crate::error::ErrorTreatment;
+use crate::events::{ChangeTracker, NetworkEvent};
+use crate::report::{self, ReportFormat};
+use crate::state::AppState;
+use crate::statusline;
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use clap::{Subcommand, ValueEnum};
+use serde_json::json;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+use unifi_rs::models::client::ClientOverview;
+use uuid::Uuid;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Sites visible to the API key
+    Sites {
+        #[command(subcommand)]
+        command: ListCommand,
+    },
+    /// Devices across all sites
+    Devices {
+        #[command(subcommand)]
+        command: ListCommand,
+    },
+    /// Connected clients across all sites
+    Clients {
+        #[command(subcommand)]
+        command: ListCommand,
+    },
+    /// Act on a single device
+    Device {
+        #[command(subcommand)]
+        command: DeviceCommand,
+    },
+    /// Stream state changes to stdout as line-delimited JSON
+    Watch,
+    /// Print a one-line summary for status bars, reusing recent data when possible
+    Status {
+        /// Reuse a summary cached within this many seconds
+        #[arg(long, default_value_t = 60)]
+        max_age: u64,
+    },
+    /// Print a network health summary
+    Report {
+        /// Report format
+        #[arg(long, value_enum, default_value = "markdown")]
+        format: ReportFormat,
+        /// Write the report to this file instead of stdout
+        #[arg(long)]
+        output_file: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ListCommand {
+    /// Print the list and exit
+    List {
+        /// Output format
+        #[arg(long, value_enum, default_value = "table")]
+        output: OutputFormat,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Subcommand)]
+pub enum DeviceCommand {
+    /// Restart the device with the given MAC address
+    Restart { mac: String },
+}
+
+/// Runs a subcommand against freshly fetched state and exits, for scripting.
+pub async fn run(command: Command, mut state: AppState) -> Result<()> {
+    match command {
+        Command::Watch => return watch(state).await,
+        Command::Status { max_age } => return print_status(state, max_age).await,
+        _ => {}
+    }
+
+    state.load().await?;
+    for entry in &state.error_log {
+        eprintln!("warning: {}", entry.message);
+    }
+
+    match command {
+        Command::Sites {
+            command: ListCommand::List { output },
+        } => print_sites(&state, output)?,
+        Command::Devices {
+            command: ListCommand::List { output },
+        } => print_devices(&state, output)?,
+        Command::Clients {
+            command: ListCommand::List { output },
+        } => print_clients(&state, output)?,
+        Command::Device {
+            command: DeviceCommand::Restart { mac },
+        } => restart_device(&state, &mac).await?,
+        Command::Report {
+            format,
+            output_file,
+        } => print_report(&state, format, output_file)?,
+        Command::Watch | Command::Status { .. } => {
+            unreachable!("watch and status are handled before the initial load")
+        }
+    }
+    Ok(())
+}
+
+fn print_sites(state: &AppState, output: OutputFormat) -> Result<()> {
+    let rows = state
+        .sites
+        .iter()
+        .map(|site| {
+            let devices = state
+                .device_sites
+                .values()
+                .filter(|site_id| **site_id == site.id)
+                .count();
+            vec![
+                site.id.to_string(),
+                site.name.clone().unwrap_or_else(|| "Unnamed".to_string()),
+                devices.to_string(),
+            ]
+        })
+        .collect();
+    print_rows(output, &["ID", "NAME", "DEVICES"], rows)
+}
+
+fn print_devices(state: &AppState, output: OutputFormat) -> Result<()> {
+    let rows = state
+        .devices
+        .iter()
+        .map(|device| {
+            vec![
+                device.name.clone(),
+                device.model.clone(),
+                device.mac_address.clone(),
+                device.ip_address.clone(),
+                format!("{:?}", device.state),
+                site_name(state, device.id),
+            ]
+        })
+        .collect();
+    print_rows(
+        output,
+        &["NAME", "MODEL", "MAC", "IP", "STATE", "SITE"],
+        rows,
+    )
+}
+
+fn print_clients(state: &AppState, output: OutputFormat) -> Result<()> {
+    let rows = state
+        .clients
+        .iter()
+        .filter_map(|client| {
+            let (kind, name, ip_address, mac_address, uplink_device_id) = match client {
+                ClientOverview::Wired(c) => (
+                    "Wired",
+                    c.base.name.as_deref(),
+                    c.base.ip_address.clone(),
+                    &c.mac_address,
+                    c.uplink_device_id,
+                ),
+                ClientOverview::Wireless(c) => (
+                    "Wireless",
+                    c.base.name.as_deref(),
+                    c.base.ip_address.clone(),
+                    &c.mac_address,
+                    c.uplink_device_id,
+                ),
+                _ => return None,
+            };
+            let uplink = state
+                .devices
+                .iter()
+                .find(|d| d.id == uplink_device_id)
+                .map_or("Unknown".to_string(), |d| d.name.clone());
+            Some(vec![
+                state.aliases.display_name(mac_address, name),
+                kind.to_string(),
+                ip_address.unwrap_or_default(),
+                mac_address.clone(),
+                uplink,
+                site_name(state, uplink_device_id),
+            ])
+        })
+        .collect();
+    print_rows(
+        output,
+        &["NAME", "TYPE", "IP", "MAC", "CONNECTED TO", "SITE"],
+        rows,
+    )
+}
+
+async fn restart_device(state: &AppState, mac: &str) -> Result<()> {
+    let device = state
+        .devices
+        .iter()
+        .find(|d| d.mac_address.eq_ignore_ascii_case(mac))
+        .ok_or_else(|| anyhow!("No device with MAC address {}", mac))?;
+    let site_id = state
+        .device_sites
+        .get(&device.id)
+        .copied()
+        .ok_or_else(|| anyhow!("No site known for device {}", device.name))?;
+
+    run_device_action(
+        state.client.clone(),
+        AuditLog::open(),
+        site_id,
+        device.id,
+        device.name.clone(),
+        DeviceAction::Restart,
+    )
+    .await?;
+    println!("Restart requested for {}", device.name);
+    Ok(())
+}
+
+async fn print_status(mut state: AppState, max_age: u64) -> Result<()> {
+    if let Some(line) = statusline::read_cache(Duration::from_secs(max_age)) {
+        println!("{}", line);
+        return Ok(());
+    }
+
+    state.load().await?;
+    let line = statusline::render(&state);
+    statusline::write_cache(&line);
+    println!("{}", line);
+    Ok(())
+}
+
+fn print_report(
+    state: &AppState,
+    format: ReportFormat,
+    output_file: Option<PathBuf>,
+) -> Result<()> {
+    let mut monitor = AlertMonitor::new();
+    monitor.evaluate(state, None);
+    let alerts: Vec<_> = monitor.active().collect();
+    let contents = report::render(state, &alerts, format);
+    match output_file {
+        Some(path) => std::fs::write(&path, contents)?,
+        None => print!("{}", contents),
+    }
+    Ok(())
+}
+
+/// Runs the refresh loop without the TUI and prints one JSON object per change.
+/// The first successful fetch only establishes a baseline.
+async fn watch(mut state: AppState) -> Result<()> {
+    let mut alerts = AlertMonitor::new();
+    let mut changes = ChangeTracker::new();
+
+    loop {
+        let mut delay = Duration::from_millis(500);
+        match state.refresh_data().await {
+            Ok(true) => {
+                let raised = alerts.evaluate(&state, None);
+                let events = changes
+                    .diff(&state)
+                    .into_iter()
+                    .chain(raised.iter().map(NetworkEvent::from_alert));
+                for event in events {
+                    emit(event.to_json())?;
+                }
+            }
+            Ok(false) => {}
+            Err(e) => {
+                // A failed fetch leaves `last_update` untouched, so wait a full
+                // interval rather than retrying (and reporting) twice a second.
+                delay = if e.treatment() == ErrorTreatment::Backoff {
+                    state.back_off()
+                } else {
+                    state.refresh_interval
+                };
+                emit(json!({
+                    "event": "error",
+                    "message": e.to_string(),
+                }))?;
+            }
+        }
+
+        tokio::time::sleep(delay).await;
+    }
+}
+
+fn emit(mut event: serde_json::Value) -> Result<()> {
+    event["timestamp"] = json!(Utc::now().to_rfc3339());
+    let mut stdout = std::io::stdout().lock();
+    writeln!(stdout, "{}", event)?;
+    stdout.flush()?;
+    Ok(())
+}
+
+fn site_name(state: &AppState, device_id: Uuid) -> String {
+    state
+        .device_sites
+        .get(&device_id)
+        .and_then(|site_id| state.sites.iter().find(|s| s.id == *site_id))
+        .and_then(|site| site.name.clone())
+        .unwrap_or_else(|| "Unknown".to_string())
+}
+
+fn print_rows(output: OutputFormat, headers: &[&str], rows: Vec<Vec<String>>) -> Result<()> {
+    match output {
+        OutputFormat::Table => print_table(headers, rows),
+        OutputFormat::Json => print_json(headers, rows)?,
+        OutputFormat::Csv => print_csv(headers, rows),
+    }
+    Ok(())
+}
+
+/// One object per row, keyed by the snake_cased column header.
+fn print_json(headers: &[&str], rows: Vec<Vec<String>>) -> Result<()> {
+    let keys: Vec<String> = headers
+        .iter()
+        .map(|h| h.to_lowercase().replace(' ', "_"))
+        .collect();
+    let objects: Vec<serde_json::Value> = rows
+        .into_iter()
+        .map(|row| {
+            keys.iter()
+                .cloned()
+                .zip(row.into_iter().map(serde_json::Value::String))
+                .collect::<serde_json::Map<_, _>>()
+                .into()
+        })
+        .collect();
+    println!("{}", serde_json::to_string_pretty(&objects)?);
+    Ok(())
+}
+
+fn print_csv(headers: &[&str], rows: Vec<Vec<String>>) {
+    let escape = |field: &str| {
+        if field.contains([',', '"', '\n']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    };
+
+    println!(
+        "{}",
+        headers
+            .iter()
+            .map(|h| escape(h))
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+    for row in rows {
+        println!(
+            "{}",
+            row.iter().map(|f| escape(f)).collect::<Vec<_>>().join(",")
+        );
+    }
+}
+
+fn print_table(headers: &[&str], rows: Vec<Vec<String>>) {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: Vec<String>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    println!(
+        "{}",
+        format_row(headers.iter().map(|h| h.to_string()).collect())
+    );
+    for row in rows {
+        println!("{}", format_row(row));
+    }
+}
@@ -0,0 +1,123 @@
+use crate::events::NetworkEvent;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tokio::time::timeout;
+
+const HOOK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Shell commands from the config file that run when matching events happen. Each
+/// command gets the event as JSON on stdin and the main fields as `UNIFI_TUI_*`
+/// environment variables.
+#[derive(Debug, Default, Clone)]
+pub struct Hooks {
+    pub device_offline: Option<String>,
+    pub client_connected: Option<String>,
+    pub alert: Option<String>,
+}
+
+impl Hooks {
+    fn command_for(&self, event: &NetworkEvent) -> Option<&str> {
+        match event {
+            NetworkEvent::DeviceState { to, .. } if to == "Offline" => {
+                self.device_offline.as_deref()
+            }
+            NetworkEvent::ClientConnected { .. } => self.client_connected.as_deref(),
+            NetworkEvent::Alert { .. } => self.alert.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Starts the hook for each event that has one, without waiting for it.
+    pub fn run(&self, events: &[NetworkEvent]) {
+        for event in events {
+            let Some(command) = self.command_for(event) else {
+                continue;
+            };
+            let mut env = vec![
+                ("UNIFI_TUI_EVENT", event.name().to_string()),
+                ("UNIFI_TUI_MESSAGE", event.describe()),
+            ];
+            match event {
+                NetworkEvent::DeviceState { name, .. } | NetworkEvent::DeviceAdded { name, .. } => {
+                    env.push(("UNIFI_TUI_NAME", name.clone()));
+                }
+                NetworkEvent::ClientConnected { client_id, name }
+                | NetworkEvent::ClientDisconnected { client_id, name } => {
+                    env.push(("UNIFI_TUI_NAME", name.clone()));
+                    env.push(("UNIFI_TUI_CLIENT_ID", client_id.to_string()));
+                }
+                NetworkEvent::Alert { kind, .. } => {
+                    env.push(("UNIFI_TUI_ALERT_KIND", kind.clone()));
+                }
+            }
+            if let Some(device_id) = event.device_id() {
+                env.push(("UNIFI_TUI_DEVICE_ID", device_id.to_string()));
+            }
+            tokio::spawn(run_hook(
+                command.to_string(),
+                env,
+                event.to_json().to_string(),
+            ));
+        }
+    }
+}
+
+async fn run_hook(command: String, env: Vec<(&'static str, String)>, input: String) {
+    let child = Command::new(shell())
+        .arg(shell_flag())
+        .arg(&command)
+        .envs(env)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            tracing::warn!(command = %command, error = %e, "Failed to start hook");
+            return;
+        }
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // A hook that ignores its input closes the pipe early, which is fine.
+        let _ = stdin.write_all(input.as_bytes()).await;
+    }
+
+    match timeout(HOOK_TIMEOUT, child.wait_with_output()).await {
+        Ok(Ok(output)) if output.status.success() => {
+            tracing::debug!(command = %command, "Hook finished");
+        }
+        Ok(Ok(output)) => tracing::warn!(
+            command = %command,
+            status = %output.status,
+            stderr = %String::from_utf8_lossy(&output.stderr).trim(),
+            "Hook failed"
+        ),
+        Ok(Err(e)) => tracing::warn!(command = %command, error = %e, "Hook failed"),
+        Err(_) => tracing::warn!(
+            command = %command,
+            "Hook did not finish within {}s and was killed",
+            HOOK_TIMEOUT.as_secs()
+        ),
+    }
+}
+
+fn shell() -> &'static str {
+    if cfg!(windows) {
+        "cmd"
+    } else {
+        "sh"
+    }
+}
+
+fn shell_flag() -> &'static str {
+    if cfg!(windows) {
+        "/C"
+    } else {
+        "-c"
+    }
+}
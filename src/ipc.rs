@@ -0,0 +1,154 @@
+use directories::ProjectDirs;
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tokio::sync::oneshot;
+
+/// A line received on the control socket. The UI loop answers it through
+/// `respond`, which is written back to the connection as a single line.
+pub struct IpcRequest {
+    pub command: String,
+    reply: oneshot::Sender<String>,
+}
+
+impl IpcRequest {
+    pub fn respond(self, response: impl Into<String>) {
+        let _ = self.reply.send(response.into());
+    }
+}
+
+/// Accepts line-based commands from external scripts while the TUI runs.
+/// Connections are served on background tasks; the UI loop drains the queued
+/// requests on every tick, the same way it drains task outcomes.
+pub struct ControlSocket {
+    path: PathBuf,
+    rx: UnboundedReceiver<IpcRequest>,
+}
+
+impl ControlSocket {
+    pub fn default_path() -> PathBuf {
+        if cfg!(windows) {
+            return PathBuf::from(r"\\.\pipe\unifi-tui");
+        }
+        ProjectDirs::from("com", "unifi-tui", "unifi-tui")
+            .and_then(|dirs| dirs.runtime_dir().map(|dir| dir.to_path_buf()))
+            .unwrap_or_else(std::env::temp_dir)
+            .join("unifi-tui.sock")
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    #[cfg(unix)]
+    pub fn bind(path: PathBuf) -> std::io::Result<Self> {
+        use tokio::net::UnixListener;
+
+        if path.exists() {
+            if std::os::unix::net::UnixStream::connect(&path).is_ok() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::AddrInUse,
+                    format!("{} is in use by another instance", path.display()),
+                ));
+            }
+            std::fs::remove_file(&path)?;
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let listener = UnixListener::bind(&path)?;
+        let (tx, rx) = unbounded_channel();
+        tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, _)) => {
+                        tokio::spawn(serve(stream, tx.clone()));
+                    }
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Control socket accept failed");
+                        break;
+                    }
+                }
+            }
+        });
+
+        tracing::info!(path = %path.display(), "Control socket listening");
+        Ok(Self { path, rx })
+    }
+
+    #[cfg(windows)]
+    pub fn bind(path: PathBuf) -> std::io::Result<Self> {
+        use tokio::net::windows::named_pipe::ServerOptions;
+
+        let mut server = ServerOptions::new()
+            .first_pipe_instance(true)
+            .create(&path)?;
+        let (tx, rx) = unbounded_channel();
+        let pipe_name = path.clone();
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = server.connect().await {
+                    tracing::warn!(error = %e, "Control pipe connect failed");
+                    break;
+                }
+                let connected = server;
+                server = match ServerOptions::new().create(&pipe_name) {
+                    Ok(next) => next,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Failed to create control pipe instance");
+                        break;
+                    }
+                };
+                tokio::spawn(serve(connected, tx.clone()));
+            }
+        });
+
+        tracing::info!(path = %path.display(), "Control pipe listening");
+        Ok(Self { path, rx })
+    }
+
+    pub fn drain(&mut self) -> Vec<IpcRequest> {
+        let mut requests = Vec::new();
+        while let Ok(request) = self.rx.try_recv() {
+            requests.push(request);
+        }
+        requests
+    }
+}
+
+impl Drop for ControlSocket {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+async fn serve<S>(stream: S, tx: UnboundedSender<IpcRequest>)
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let (reader, mut writer) = tokio::io::split(stream);
+    let mut lines = BufReader::new(reader).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        let command = line.trim().to_string();
+        if command.is_empty() {
+            continue;
+        }
+
+        let (reply, response) = oneshot::channel();
+        if tx.send(IpcRequest { command, reply }).is_err() {
+            break;
+        }
+        let Ok(response) = response.await else {
+            break;
+        };
+        if writer
+            .write_all(format!("{}\n", response).as_bytes())
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+}
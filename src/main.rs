@@ -1,11 +1,45 @@
+mod actions;
+mod alerts;
+mod aliases;
+mod api;
 mod app;
+mod audit;
+mod availability;
+mod bus;
+mod config;
+mod email;
 mod error;
+mod events;
+mod export;
+mod favorites;
+mod files;
 mod handlers;
+mod headless;
+mod hooks;
+mod ipc;
+mod mqtt;
+mod oui;
+mod pane_ratios;
+mod preflight;
+mod probe;
+mod refresher;
+mod report;
+mod scheduler;
+mod script;
+mod search_history;
+mod snapshot;
+mod source;
 mod state;
+mod statusline;
+mod syslog;
+mod tasks;
+mod toast;
+mod traceroute;
 mod ui;
+mod weblink;
 
 use anyhow::Result;
-use clap::{Parser, ValueEnum};
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use crossterm::event::MouseEvent;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
@@ -14,8 +48,10 @@ use crossterm::{
 };
 use directories::ProjectDirs;
 use ratatui::prelude::*;
+use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Once;
+use std::time::Instant;
 use std::{io, time::Duration};
 use tracing::level_filters::LevelFilter;
 use tracing::{error, info};
@@ -24,14 +60,21 @@ use tracing_subscriber::fmt::format::FmtSpan;
 use tracing_subscriber::EnvFilter;
 use unifi_rs::UnifiClientBuilder;
 
-use crate::app::{App, Mode};
+use crate::api::ApiServer;
+use crate::app::{App, Connection, IdleLock, Kiosk};
+use crate::email::EmailArgs;
 use crate::handlers::{
-    handle_client_detail_input, handle_device_detail_input, handle_dialog_input,
-    handle_global_input, handle_search_input,
+    handle_dialog_input, handle_fullscreen_chart_input, handle_global_input, handle_jump_input,
+    handle_search_input,
 };
+use crate::ipc::ControlSocket;
+use crate::mqtt::{MqttArgs, MqttPublisher};
+use crate::probe::LatencyMonitor;
 use crate::state::AppState;
+use crate::syslog::SyslogArgs;
+use crate::toast::ToastLevel;
+use crate::ui::icons::IconStyle;
 use crate::ui::render;
-use crate::ui::topology::topology::{handle_topology_input, handle_topology_mouse};
 
 #[derive(Debug, Clone, ValueEnum)]
 enum LogLevel {
@@ -54,28 +97,121 @@ impl From<LogLevel> for LevelFilter {
     }
 }
 
+#[derive(Subcommand)]
+enum Command {
+    #[command(flatten)]
+    Headless(headless::Command),
+    /// Print a shell completion script
+    Completions { shell: clap_complete::Shell },
+    /// Print the man page
+    Mangen,
+}
+
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// UniFi Controller URL
-    #[arg(long, env)]
-    url: String,
+    #[arg(long, env, global = true)]
+    url: Option<String>,
 
     /// API Key
-    #[arg(long, env)]
-    api_key: String,
+    #[arg(long, env, global = true)]
+    api_key: Option<String>,
 
     /// Skip SSL verification
-    #[arg(long, default_value = "false")]
+    #[arg(long, default_value = "false", global = true)]
     insecure: bool,
 
     /// Enable logging
     #[arg(long)]
     logging: bool,
 
+    /// Never write to disk: no logs, history, favorites, aliases, audit log, status cache,
+    /// snapshots or reports, and no control socket unless --control-socket is given
+    #[arg(long, global = true, conflicts_with = "logging")]
+    no_files: bool,
+
     /// Log level (only valid if logging is enabled)
     #[arg(long, value_enum, default_value = "info")]
     log_level: LogLevel,
+
+    /// How long maintenance mode suppresses alerts, in minutes
+    #[arg(long, default_value = "60")]
+    maintenance_minutes: u64,
+
+    /// How long disconnected clients stay in the Clients tab, in minutes (0 hides them)
+    #[arg(long, default_value = "30")]
+    client_retention_minutes: u64,
+
+    /// Probe each device from this host and show the latency
+    #[arg(long)]
+    probe: bool,
+
+    /// User for SSH sessions opened from the device views
+    #[arg(long, env, default_value = "root")]
+    ssh_user: String,
+
+    /// TCP port used by the latency probe
+    #[arg(long, default_value = "22")]
+    probe_port: u16,
+
+    /// Icons in the device and client tables
+    #[arg(long, value_enum, default_value = "ascii")]
+    icons: IconStyle,
+
+    /// Draw views as plain-text lines for terminal screen readers
+    #[arg(long)]
+    screen_reader: bool,
+
+    /// Lock the screen after this many minutes without input
+    #[arg(long)]
+    lock_after_minutes: Option<u64>,
+
+    /// Passphrase required to unlock the screen; without one any key unlocks it
+    #[arg(long, env = "UNIFI_TUI_LOCK_PASSPHRASE", hide_env_values = true)]
+    lock_passphrase: Option<String>,
+
+    /// Wallboard mode: hide the tab bar and cycle between Dashboard, Stats and Topology.
+    /// Only q (quit) is handled
+    #[arg(long)]
+    kiosk: bool,
+
+    /// How long kiosk mode shows each view, in seconds
+    #[arg(long, default_value = "30")]
+    kiosk_interval: u64,
+
+    /// Poll every 30s or more, fetch device details only for gateways and the open device
+    /// and redraw less often, for laptops on battery or slow VPN links
+    #[arg(long)]
+    low_power: bool,
+
+    /// Start on the dashboard that compares all sites
+    #[arg(long)]
+    sites_dashboard: bool,
+
+    /// Path of the control socket (named pipe on Windows) scripts can send commands to
+    #[arg(long)]
+    control_socket: Option<PathBuf>,
+
+    /// Do not open a control socket
+    #[arg(long)]
+    no_control_socket: bool,
+
+    /// Serve the fetched state as read-only JSON on this address, e.g. 127.0.0.1:8089
+    #[arg(long)]
+    serve: Option<SocketAddr>,
+
+    #[command(flatten)]
+    mqtt: MqttArgs,
+
+    #[command(flatten)]
+    syslog: SyslogArgs,
+
+    #[command(flatten)]
+    email: EmailArgs,
 }
 
 static INIT: Once = Once::new();
@@ -124,24 +260,113 @@ pub fn initialize_logging(
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    if cli.no_files {
+        files::disable();
+    }
+
+    match cli.command {
+        Some(Command::Completions { shell }) => {
+            clap_complete::generate(shell, &mut Cli::command(), "unifi-tui", &mut io::stdout());
+            return Ok(());
+        }
+        Some(Command::Mangen) => {
+            clap_mangen::Man::new(Cli::command()).render(&mut io::stdout())?;
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    // Optional at the clap level so completions and mangen work without them.
+    let (Some(url), Some(api_key)) = (cli.url, cli.api_key) else {
+        Cli::command()
+            .error(
+                clap::error::ErrorKind::MissingRequiredArgument,
+                "--url and --api-key are required",
+            )
+            .exit();
+    };
 
     if let Some(log_path) = initialize_logging(cli.logging, cli.log_level.into())? {
         info!("Starting application. Log file: {:?}", log_path);
     }
 
+    let client = UnifiClientBuilder::new(url.clone())
+        .api_key(api_key)
+        .verify_ssl(!cli.insecure)
+        .build()?;
+
+    let mut state = AppState::new(client).await?;
+    if let Some(Command::Headless(command)) = cli.command {
+        return headless::run(command, state).await;
+    }
+
+    if let Err(diagnosis) = preflight::check(&state.client, &url, cli.insecure).await {
+        eprintln!(
+            "Could not connect to the UniFi controller.\n\n  {}",
+            diagnosis
+        );
+        std::process::exit(1);
+    }
+
+    let api = match cli.serve {
+        Some(addr) => Some(ApiServer::bind(addr).await?),
+        None => None,
+    };
+
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
-
-    let client = UnifiClientBuilder::new(cli.url)
-        .api_key(cli.api_key)
-        .verify_ssl(!cli.insecure)
-        .build()?;
-
-    let state = AppState::new(client).await?;
-    let app = App::new(state).await?;
+    if cli.probe {
+        state.latency = Some(LatencyMonitor::new(cli.probe_port));
+    }
+    let mut app = App::new(state).await?;
+    app.refresh_controller_info();
+    app.maintenance_duration = Duration::from_secs(cli.maintenance_minutes * 60);
+    app.state.client_retention = Duration::from_secs(cli.client_retention_minutes * 60);
+    app.ssh_user = cli.ssh_user;
+    app.icons = cli.icons;
+    app.screen_reader = cli.screen_reader;
+    app.low_power = cli.low_power;
+    if app.low_power {
+        app.set_refresh_interval(app.state.refresh_interval);
+    }
+    app.idle_lock = cli.lock_after_minutes.map(|minutes| IdleLock {
+        timeout: Duration::from_secs(minutes * 60),
+        passphrase: cli.lock_passphrase,
+    });
+    app.reload_config();
+    app.reload_scripts();
+    if cli.kiosk {
+        app.kiosk = Some(Kiosk::new(Duration::from_secs(cli.kiosk_interval.max(1))));
+    }
+    if cli.sites_dashboard {
+        app.show_site_dashboard();
+    }
+    app.api = api;
+    app.mqtt = cli.mqtt.config().map(MqttPublisher::start);
+    app.syslog = cli.syslog.forwarder();
+    app.email = cli.email.notifier();
+    // The default socket is created in the runtime directory, so --no-files only allows
+    // one at a path given explicitly.
+    let control_socket = match cli.control_socket {
+        Some(path) => Some(path),
+        None if cli.no_files => None,
+        None => Some(ControlSocket::default_path()),
+    };
+    if let Some(path) = control_socket.filter(|_| !cli.no_control_socket) {
+        match ControlSocket::bind(path) {
+            Ok(socket) => app.control_socket = Some(socket),
+            Err(e) => app
+                .state
+                .log_error(format!("Control socket unavailable: {}", e)),
+        }
+    }
+    app.connection = Some(Connection {
+        url,
+        insecure: cli.insecure,
+    });
 
     let res = run_app(&mut terminal, app).await;
 
@@ -160,51 +385,115 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-async fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> Result<()> {
+async fn run_app<B: Backend + io::Write>(terminal: &mut Terminal<B>, mut app: App) -> Result<()> {
+    let mut last_draw: Option<Instant> = None;
     loop {
-        terminal.draw(|f| render(&mut app, f))?;
+        // Nothing is drawn while idle except the status clock (refresh countdown,
+        // toast expiry), which low-power mode lets fall further behind.
+        let (tick, clock) = if app.low_power {
+            (Duration::from_secs(1), Duration::from_secs(5))
+        } else {
+            (Duration::from_millis(250), Duration::from_secs(1))
+        };
+        if app.needs_redraw || last_draw.is_none_or(|at| at.elapsed() >= clock) {
+            app.needs_redraw = false;
+            last_draw = Some(Instant::now());
+            app.sync_selection();
+            let frame = terminal.draw(|f| render(&mut app, f))?;
+            if std::mem::take(&mut app.snapshot_requested) {
+                match snapshot::save(frame.buffer) {
+                    Ok(path) => app.state.notify(
+                        ToastLevel::Success,
+                        format!("Screen saved to {}", path.display()),
+                    ),
+                    Err(e) => app.state.set_error(format!("Failed to save screen: {}", e)),
+                }
+                app.needs_redraw = true;
+            }
+        }
 
-        if event::poll(Duration::from_millis(100))? {
+        if event::poll(tick)? {
+            app.needs_redraw = true;
             match event::read()? {
                 Event::Key(key) => {
-                    if handle_global_input(&mut app, key).await? {
+                    app.last_input = Instant::now();
+                    if app.lock_screen.is_some() {
+                        ui::lock::handle_lock_input(&mut app, key);
+                        continue;
+                    }
+
+                    if app.kiosk.is_some() {
+                        if key.code == KeyCode::Char('q') {
+                            app.should_quit = true;
+                        }
+                        continue;
+                    }
+
+                    if app.reauth_prompt.is_some() {
+                        ui::reauth::handle_reauth_input(&mut app, key).await?;
+                        continue;
+                    }
+
+                    if app.alias_prompt.is_some() {
+                        ui::clients::handle_alias_prompt_input(&mut app, key).await?;
+                        continue;
+                    }
+
+                    if app.site_switcher.is_some() {
+                        ui::site_switcher::handle_site_switcher_input(&mut app, key)?;
+                        continue;
+                    }
+
+                    if app.schedule_prompt.is_some() {
+                        ui::jobs::handle_schedule_prompt_input(&mut app, key).await?;
                         continue;
                     }
 
                     if app.dialog.is_some() {
                         handle_dialog_input(&mut app, key).await?;
+                        continue;
+                    }
+
+                    if app.chart_fullscreen {
+                        handle_fullscreen_chart_input(&mut app, key).await?;
+                        continue;
+                    }
+
+                    if handle_jump_input(&mut app, key) {
+                        continue;
+                    }
+
+                    if handle_global_input(&mut app, key).await? {
+                        continue;
+                    }
+
+                    if app.show_jobs {
+                        ui::jobs::handle_jobs_input(&mut app, key).await?;
+                    } else if app.show_alerts {
+                        ui::alerts::handle_alerts_input(&mut app, key).await?;
+                    } else if app.show_activity {
+                        ui::activity::handle_activity_input(&mut app, key).await?;
+                    } else if app.traceroute.is_some() {
+                        ui::traceroute::handle_traceroute_input(&mut app, key).await?;
+                    } else if app.show_controller {
+                        ui::controller::handle_controller_input(&mut app, key).await?;
+                    } else if app.show_errors {
+                        ui::errors::handle_error_console_input(&mut app, key).await?;
                     } else if app.search_mode {
                         handle_search_input(&mut app, key).await?;
                     } else if app.show_help {
                         if key.code == KeyCode::Esc {
                             app.show_help = false;
                         }
-                    } else {
-                        match app.mode {
-                            Mode::Overview => match app.current_tab {
-                                0 => ui::sites::handle_sites_input(&mut app, key)?,
-                                1 => ui::devices::handle_device_input(&mut app, key).await?,
-                                2 => ui::clients::handle_client_input(&mut app, key).await?,
-                                3 => handle_topology_input(&mut app, key).await?,
-                                4 => {}
-                                _ => {}
-                            },
-                            Mode::DeviceDetail => {
-                                handle_device_detail_input(&mut app, key).await?;
-                            }
-                            Mode::ClientDetail => {
-                                handle_client_detail_input(&mut app, key).await?;
-                            }
-                            Mode::Help => {
-                                if key.code == KeyCode::Esc {
-                                    app.mode = Mode::Overview;
-                                }
-                            }
-                        }
+                    } else if let Some(page) = ui::page::current(&app) {
+                        page.handle_key(&mut app, key).await?;
                     }
                 }
                 Event::Mouse(event) => {
-                    if app.current_tab == 3 && app.mode == Mode::Overview {
+                    app.last_input = Instant::now();
+                    let page = ui::page::current(&app)
+                        .filter(|_| app.lock_screen.is_none() && app.kiosk.is_none());
+                    if let Some(page) = page {
                         let size = terminal.size()?;
                         let area = Rect::new(0, 0, size.width, size.height);
 
@@ -212,13 +501,14 @@ async fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> Result
                             .direction(Direction::Vertical)
                             .constraints([
                                 Constraint::Length(3), // Title
-                                Constraint::Min(0),    // Topology area
+                                Constraint::Length(1), // Breadcrumb
+                                Constraint::Min(0),    // Content
                                 Constraint::Length(3), // Status bar
                             ])
                             .split(area);
 
-                        if is_mouse_in_area(event, areas[1]) {
-                            handle_topology_mouse(&mut app, event, areas[1]).await?;
+                        if is_mouse_in_area(event, areas[2]) {
+                            page.handle_mouse(&mut app, event, areas[2]).await?;
                         }
                     }
                 }
@@ -226,9 +516,26 @@ async fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> Result
             }
         }
 
-        if app.dialog.is_none() {
-            if let Err(e) = app.refresh().await {
-                app.state.set_error(format!("Error refreshing data: {}", e));
+        if let Some(target) = app.pending_ssh.take() {
+            run_ssh(terminal, &mut app, &target).await?;
+            app.needs_redraw = true;
+        }
+
+        app.reload_config();
+        app.reload_scripts();
+        app.check_idle_lock();
+        app.advance_kiosk();
+        app.run_due_jobs();
+        app.process_task_outcomes();
+        app.process_ipc_requests();
+        app.state.run_probes();
+        if let Some(traceroute) = app.traceroute.as_mut() {
+            traceroute.poll();
+        }
+
+        if app.dialog.is_none() && app.reauth_prompt.is_none() {
+            if let Err(e) = app.refresh() {
+                app.handle_refresh_error(e);
             }
         }
 
@@ -239,6 +546,43 @@ async fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> Result
 
     Ok(())
 }
+async fn run_ssh<B: Backend + io::Write>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+    target: &str,
+) -> Result<()> {
+    info!(target = %target, "Suspending TUI for SSH session");
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    let status = tokio::process::Command::new("ssh")
+        .arg(target)
+        .status()
+        .await;
+
+    enable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableMouseCapture
+    )?;
+    terminal.clear()?;
+
+    match status {
+        Ok(status) if status.success() => {}
+        Ok(status) => app
+            .state
+            .set_error(format!("SSH to {} exited with {}", target, status)),
+        Err(e) => app.state.set_error(format!("Failed to launch ssh: {}", e)),
+    }
+    Ok(())
+}
+
 fn is_mouse_in_area(event: MouseEvent, area: Rect) -> bool {
     let (col, row) = (event.column, event.row);
     col >= area.x && col < area.x + area.width && row >= area.y && row < area.y + area.height
@@ -0,0 +1,325 @@
+use crate::events::NetworkEvent;
+use crate::state::AppState;
+use clap::Args;
+use serde_json::json;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use unifi_rs::device::DeviceState;
+
+const KEEP_ALIVE: Duration = Duration::from_secs(60);
+const RECONNECT_DELAY: Duration = Duration::from_secs(10);
+
+// (key, name, unit) of the sensors announced for Home Assistant discovery.
+const SENSORS: &[(&str, &str, Option<&str>)] = &[
+    ("devices_online", "Devices online", None),
+    ("devices_total", "Devices", None),
+    ("clients", "Clients", None),
+    ("wired_clients", "Wired clients", None),
+    ("wireless_clients", "Wireless clients", None),
+    ("wan_download", "WAN download", Some("bit/s")),
+    ("wan_upload", "WAN upload", Some("bit/s")),
+];
+
+#[derive(Args)]
+#[command(next_help_heading = "MQTT")]
+pub struct MqttArgs {
+    /// Publish events and metrics to this MQTT broker (host:port)
+    #[arg(long, env)]
+    mqtt_broker: Option<String>,
+
+    #[arg(long, env)]
+    mqtt_username: Option<String>,
+
+    #[arg(long, env, hide_env_values = true)]
+    mqtt_password: Option<String>,
+
+    /// Client ID, also used to identify the device in Home Assistant
+    #[arg(long, default_value = "unifi-tui")]
+    mqtt_client_id: String,
+
+    /// Prefix for the events, metrics and status topics
+    #[arg(long, default_value = "unifi-tui")]
+    mqtt_topic_prefix: String,
+
+    /// Home Assistant discovery prefix
+    #[arg(long, default_value = "homeassistant")]
+    mqtt_discovery_prefix: String,
+
+    /// Do not announce sensors for Home Assistant discovery
+    #[arg(long)]
+    no_mqtt_discovery: bool,
+
+    /// Seconds between metrics publishes
+    #[arg(long, default_value = "30")]
+    mqtt_interval: u64,
+}
+
+impl MqttArgs {
+    pub fn config(self) -> Option<MqttConfig> {
+        Some(MqttConfig {
+            broker: self.mqtt_broker?,
+            client_id: self.mqtt_client_id,
+            username: self.mqtt_username,
+            password: self.mqtt_password,
+            topic_prefix: self.mqtt_topic_prefix,
+            discovery_prefix: (!self.no_mqtt_discovery).then_some(self.mqtt_discovery_prefix),
+            metrics_interval: Duration::from_secs(self.mqtt_interval),
+        })
+    }
+}
+
+pub struct MqttConfig {
+    pub broker: String,
+    pub client_id: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub topic_prefix: String,
+    pub discovery_prefix: Option<String>,
+    pub metrics_interval: Duration,
+}
+
+struct Message {
+    topic: String,
+    payload: String,
+    retain: bool,
+}
+
+/// Publishes events under `{prefix}/events/{event}` and a retained metrics document
+/// under `{prefix}/metrics`. The connection lives on a background task that
+/// reconnects on its own; messages produced while it is down are dropped (QoS 0).
+pub struct MqttPublisher {
+    tx: UnboundedSender<Message>,
+    prefix: String,
+    metrics_interval: Duration,
+    last_metrics: Option<Instant>,
+}
+
+impl MqttPublisher {
+    pub fn start(config: MqttConfig) -> Self {
+        let (tx, rx) = unbounded_channel();
+        let prefix = config.topic_prefix.clone();
+        let metrics_interval = config.metrics_interval;
+        tokio::spawn(run(config, rx));
+        Self {
+            tx,
+            prefix,
+            metrics_interval,
+            last_metrics: None,
+        }
+    }
+
+    pub fn publish_events(&self, events: &[NetworkEvent]) {
+        for event in events {
+            self.send(
+                format!("{}/events/{}", self.prefix, event.name()),
+                event.to_json().to_string(),
+                false,
+            );
+        }
+    }
+
+    /// Publishes the metrics document if `metrics_interval` has passed since the last one.
+    pub fn publish_metrics(&mut self, state: &AppState) {
+        if self
+            .last_metrics
+            .is_some_and(|last| last.elapsed() < self.metrics_interval)
+        {
+            return;
+        }
+        self.last_metrics = Some(Instant::now());
+
+        let (wan_download, wan_upload) = state
+            .devices
+            .iter()
+            .filter(|d| state.is_gateway(d.id))
+            .filter_map(|d| state.device_stats.get(&d.id)?.uplink.as_ref())
+            .fold((0, 0), |(rx, tx), u| {
+                (rx + u.rx_rate_bps, tx + u.tx_rate_bps)
+            });
+        let metrics = json!({
+            "devices_online": state
+                .devices
+                .iter()
+                .filter(|d| matches!(d.state, DeviceState::Online))
+                .count(),
+            "devices_total": state.devices.len(),
+            "clients": state.clients.len(),
+            "wired_clients": state.stats_history.back().map_or(0, |s| s.wired_clients),
+            "wireless_clients": state.stats_history.back().map_or(0, |s| s.wireless_clients),
+            "wan_download": wan_download,
+            "wan_upload": wan_upload,
+        });
+        self.send(
+            format!("{}/metrics", self.prefix),
+            metrics.to_string(),
+            true,
+        );
+    }
+
+    fn send(&self, topic: String, payload: String, retain: bool) {
+        let message = Message {
+            topic,
+            payload,
+            retain,
+        };
+        if self.tx.send(message).is_err() {
+            tracing::warn!("MQTT publisher stopped");
+        }
+    }
+}
+
+async fn run(config: MqttConfig, mut rx: UnboundedReceiver<Message>) {
+    loop {
+        // Anything queued while disconnected is stale by now.
+        while rx.try_recv().is_ok() {}
+
+        match session(&config, &mut rx).await {
+            Ok(()) => return,
+            Err(e) => {
+                tracing::warn!(broker = %config.broker, error = %e, "MQTT connection lost");
+            }
+        }
+        tokio::time::sleep(RECONNECT_DELAY).await;
+    }
+}
+
+/// Runs one broker connection until it fails. Returns `Ok` only once the
+/// publisher has been dropped.
+async fn session(config: &MqttConfig, rx: &mut UnboundedReceiver<Message>) -> std::io::Result<()> {
+    let mut stream = TcpStream::connect(&config.broker).await?;
+    let status_topic = format!("{}/status", config.topic_prefix);
+    stream
+        .write_all(&connect_packet(config, &status_topic))
+        .await?;
+
+    let mut connack = [0u8; 4];
+    stream.read_exact(&mut connack).await?;
+    if connack[0] != 0x20 || connack[3] != 0 {
+        return Err(std::io::Error::other(format!(
+            "broker refused the connection (return code {})",
+            connack[3]
+        )));
+    }
+    tracing::info!(broker = %config.broker, "Connected to MQTT broker");
+
+    stream
+        .write_all(&publish_packet(&status_topic, "online", true))
+        .await?;
+    if let Some(discovery) = &config.discovery_prefix {
+        for (topic, payload) in discovery_messages(config, discovery) {
+            stream
+                .write_all(&publish_packet(&topic, &payload, true))
+                .await?;
+        }
+    }
+
+    let (mut reader, mut writer) = stream.split();
+    let mut ping = tokio::time::interval(KEEP_ALIVE / 2);
+    let mut buf = [0u8; 64];
+    loop {
+        tokio::select! {
+            message = rx.recv() => {
+                let Some(message) = message else {
+                    return Ok(());
+                };
+                writer
+                    .write_all(&publish_packet(&message.topic, &message.payload, message.retain))
+                    .await?;
+            }
+            _ = ping.tick() => writer.write_all(&[0xC0, 0x00]).await?,
+            // Only PINGRESP comes back for QoS 0; a closed socket reads zero bytes.
+            read = reader.read(&mut buf) => {
+                if read? == 0 {
+                    return Err(std::io::ErrorKind::UnexpectedEof.into());
+                }
+            }
+        }
+    }
+}
+
+fn discovery_messages(config: &MqttConfig, discovery_prefix: &str) -> Vec<(String, String)> {
+    SENSORS
+        .iter()
+        .map(|(key, name, unit)| {
+            let topic = format!(
+                "{}/sensor/{}/{}/config",
+                discovery_prefix, config.client_id, key
+            );
+            let mut payload = json!({
+                "name": name,
+                "unique_id": format!("{}_{}", config.client_id, key),
+                "state_topic": format!("{}/metrics", config.topic_prefix),
+                "value_template": format!("{{{{ value_json.{} }}}}", key),
+                "availability_topic": format!("{}/status", config.topic_prefix),
+                "device": {
+                    "identifiers": [config.client_id],
+                    "name": "UniFi Network",
+                    "manufacturer": "Ubiquiti",
+                },
+            });
+            if let Some(unit) = unit {
+                payload["unit_of_measurement"] = json!(unit);
+            }
+            (topic, payload.to_string())
+        })
+        .collect()
+}
+
+fn connect_packet(config: &MqttConfig, status_topic: &str) -> Vec<u8> {
+    // Clean session, plus a retained "offline" will on the status topic.
+    let mut flags = 0x02 | 0x04 | 0x20;
+    if config.username.is_some() {
+        flags |= 0x80;
+    }
+    if config.password.is_some() {
+        flags |= 0x40;
+    }
+
+    let mut body = Vec::new();
+    push_str(&mut body, "MQTT");
+    body.push(4);
+    body.push(flags);
+    body.extend_from_slice(&(KEEP_ALIVE.as_secs() as u16).to_be_bytes());
+    push_str(&mut body, &config.client_id);
+    push_str(&mut body, status_topic);
+    push_str(&mut body, "offline");
+    if let Some(username) = &config.username {
+        push_str(&mut body, username);
+    }
+    if let Some(password) = &config.password {
+        push_str(&mut body, password);
+    }
+    packet(0x10, body)
+}
+
+fn publish_packet(topic: &str, payload: &str, retain: bool) -> Vec<u8> {
+    let mut body = Vec::new();
+    push_str(&mut body, topic);
+    body.extend_from_slice(payload.as_bytes());
+    packet(if retain { 0x31 } else { 0x30 }, body)
+}
+
+fn packet(header: u8, body: Vec<u8>) -> Vec<u8> {
+    let mut out = vec![header];
+    let mut remaining = body.len();
+    loop {
+        let mut byte = (remaining % 128) as u8;
+        remaining /= 128;
+        if remaining > 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if remaining == 0 {
+            break;
+        }
+    }
+    out.extend(body);
+    out
+}
+
+fn push_str(buf: &mut Vec<u8>, value: &str) {
+    buf.extend_from_slice(&(value.len() as u16).to_be_bytes());
+    buf.extend_from_slice(value.as_bytes());
+}
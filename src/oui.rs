@@ -0,0 +1,89 @@
+// The Integration API does not report the controller's fingerprint (OS, category or
+// vendor) for clients, so the vendor is looked up from the MAC address instead.
+
+/// Organisationally unique identifiers (the first three octets of a MAC) of vendors that
+/// are common on home and small office networks. The IEEE registry has tens of thousands
+/// of entries; this only covers the usual suspects.
+const VENDORS: &[(&str, &str)] = &[
+    ("00:0E:58", "Sonos"),
+    ("00:11:32", "Synology"),
+    ("00:17:88", "Philips Hue"),
+    ("00:1B:63", "Apple"),
+    ("00:27:22", "Ubiquiti"),
+    ("04:18:D6", "Ubiquiti"),
+    ("08:3A:F2", "Espressif"),
+    ("10:52:1C", "Espressif"),
+    ("18:B4:30", "Google Nest"),
+    ("18:E8:29", "Ubiquiti"),
+    ("18:FE:34", "Espressif"),
+    ("24:0A:C4", "Espressif"),
+    ("24:5A:4C", "Ubiquiti"),
+    ("24:6F:28", "Espressif"),
+    ("24:A4:3C", "Ubiquiti"),
+    ("28:CD:C1", "Raspberry Pi"),
+    ("2C:CF:67", "Raspberry Pi"),
+    ("30:AE:A4", "Espressif"),
+    ("3C:22:FB", "Apple"),
+    ("3C:5A:B4", "Google"),
+    ("3C:71:BF", "Espressif"),
+    ("44:D9:E7", "Ubiquiti"),
+    ("48:A6:B8", "Sonos"),
+    ("54:60:09", "Google"),
+    ("5C:AA:FD", "Sonos"),
+    ("5C:CF:7F", "Espressif"),
+    ("60:01:94", "Espressif"),
+    ("64:16:66", "Google Nest"),
+    ("68:37:E9", "Amazon"),
+    ("68:D7:9A", "Ubiquiti"),
+    ("74:83:C2", "Ubiquiti"),
+    ("78:28:CA", "Sonos"),
+    ("78:8A:20", "Ubiquiti"),
+    ("80:2A:A8", "Ubiquiti"),
+    ("84:D6:D0", "Amazon"),
+    ("84:F3:EB", "Espressif"),
+    ("88:66:A5", "Apple"),
+    ("8C:85:90", "Apple"),
+    ("8C:AA:B5", "Espressif"),
+    ("94:9F:3E", "Sonos"),
+    ("A4:83:E7", "Apple"),
+    ("A4:CF:12", "Espressif"),
+    ("AC:8B:A9", "Ubiquiti"),
+    ("AC:BC:32", "Apple"),
+    ("B4:FB:E4", "Ubiquiti"),
+    ("B8:27:EB", "Raspberry Pi"),
+    ("BC:DD:C2", "Espressif"),
+    ("D8:3A:DD", "Raspberry Pi"),
+    ("DC:9F:DB", "Ubiquiti"),
+    ("DC:A6:32", "Raspberry Pi"),
+    ("E0:63:DA", "Ubiquiti"),
+    ("E4:5F:01", "Raspberry Pi"),
+    ("EC:B5:FA", "Philips Hue"),
+    ("EC:FA:BC", "Espressif"),
+    ("F0:18:98", "Apple"),
+    ("F0:27:2D", "Amazon"),
+    ("F0:9F:C2", "Ubiquiti"),
+    ("F4:F5:D8", "Google"),
+    ("FC:65:DE", "Amazon"),
+    ("FC:EC:DA", "Ubiquiti"),
+];
+
+/// Phones and laptops use locally administered, randomized addresses on Wi-Fi by default,
+/// which carry no vendor.
+pub fn is_randomized(mac: &str) -> bool {
+    mac.get(..2)
+        .and_then(|octet| u8::from_str_radix(octet, 16).ok())
+        .is_some_and(|octet| octet & 0x02 != 0)
+}
+
+pub fn vendor(mac: &str) -> &'static str {
+    if is_randomized(mac) {
+        return "Private MAC";
+    }
+    let prefix = mac.replace('-', ":").to_uppercase();
+    let Some(prefix) = prefix.get(..8) else {
+        return "Unknown";
+    };
+    VENDORS
+        .binary_search_by(|(oui, _)| (*oui).cmp(prefix))
+        .map_or("Unknown", |i| VENDORS[i].1)
+}
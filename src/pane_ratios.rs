@@ -0,0 +1,73 @@
+use crate::error::Result;
+use crate::files;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+const STEP: u16 = 5;
+const MIN_SHARE: u16 = 10;
+const MAX_SHARE: u16 = 90;
+/// Where the first resize of a view starts from.
+const DEFAULT_SHARE: u16 = 40;
+
+/// How much of its height each view gives to its fixed-size panes (summaries,
+/// tables, info boxes) rather than its charts, in percent. Views without an entry
+/// keep their built-in sizes. Stored as `view percent` lines next to the favorites.
+pub struct PaneRatios {
+    path: Option<PathBuf>,
+    shares: BTreeMap<String, u16>,
+}
+
+impl PaneRatios {
+    pub fn open() -> Self {
+        let path = files::data_file("pane_ratios");
+
+        let shares = path
+            .as_ref()
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .map(|content| {
+                content
+                    .lines()
+                    .filter_map(|line| {
+                        let (view, share) = line.split_once(' ')?;
+                        let share = share.trim().parse().ok()?;
+                        Some((view.to_string(), share))
+                    })
+                    .filter(|(_, share)| (MIN_SHARE..=MAX_SHARE).contains(share))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self { path, shares }
+    }
+
+    pub fn get(&self, view: &str) -> Option<u16> {
+        self.shares.get(view).copied()
+    }
+
+    /// Grows or shrinks the fixed panes of `view` by one step and returns the new share.
+    pub fn resize(&mut self, view: &str, grow: bool) -> Result<u16> {
+        let share = self.get(view).unwrap_or(DEFAULT_SHARE);
+        let share = if grow {
+            (share + STEP).min(MAX_SHARE)
+        } else {
+            share.saturating_sub(STEP).max(MIN_SHARE)
+        };
+        self.shares.insert(view.to_string(), share);
+        self.save()?;
+        Ok(share)
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let contents: String = self
+            .shares
+            .iter()
+            .map(|(view, share)| format!("{} {}\n", view, share))
+            .collect();
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+}
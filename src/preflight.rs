@@ -0,0 +1,83 @@
+use crate::error::AppError;
+use std::time::Duration;
+use tokio::time::timeout;
+use unifi_rs::UnifiClient;
+
+const PREFLIGHT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Makes one cheap request before the TUI takes over the terminal, so a wrong URL,
+/// certificate or API key is reported as plain text with a way to fix it.
+pub async fn check(client: &UnifiClient, url: &str, insecure: bool) -> Result<(), String> {
+    let error = match timeout(PREFLIGHT_TIMEOUT, client.list_sites(Some(0), Some(1))).await {
+        Ok(Ok(_)) => return Ok(()),
+        Ok(Err(e)) => AppError::from(e),
+        Err(_) => {
+            return Err(format!(
+                "The controller at {} did not answer within {}s. Check that the URL points at \
+                 the console and that this host can reach it.",
+                url,
+                PREFLIGHT_TIMEOUT.as_secs()
+            ))
+        }
+    };
+    tracing::error!(error = %error, "Preflight failed");
+
+    Err(format!(
+        "{}\n\n  Details: {}",
+        diagnose(&error, url, insecure),
+        error
+    ))
+}
+
+fn diagnose(error: &AppError, url: &str, insecure: bool) -> String {
+    let message = error.to_string().to_lowercase();
+    let mentions = |words: &[&str]| words.iter().any(|word| message.contains(word));
+
+    if let AppError::Auth(_) = error {
+        return "The controller rejected the API key. Create a new one under Settings > \
+                Control Plane > Integrations and pass it with --api-key."
+            .to_string();
+    }
+    if mentions(&["dns", "lookup", "resolve", "name or service not known"]) {
+        return format!(
+            "Could not resolve the host in {}. Check the spelling of --url and that DNS works \
+             from this machine.",
+            url
+        );
+    }
+    if mentions(&["certificate", "tls", "ssl", "handshake"]) {
+        return if insecure {
+            format!(
+                "The TLS handshake with {} failed even with --insecure.",
+                url
+            )
+        } else {
+            format!(
+                "The certificate presented by {} could not be verified. Consoles use a \
+                 self-signed certificate by default; rerun with --insecure to accept it.",
+                url
+            )
+        };
+    }
+    if mentions(&[
+        "connection refused",
+        "error trying to connect",
+        "unreachable",
+    ]) {
+        return format!(
+            "Could not connect to {}. Check the host and port and that the console is online.",
+            url
+        );
+    }
+    if mentions(&["404", "not found"]) {
+        return format!(
+            "{} answered, but not with the UniFi Network API. Use the console's base URL, \
+             e.g. https://192.168.1.1, without a path.",
+            url
+        );
+    }
+    match error.hint() {
+        Some(hint) => format!("The first request to {} failed. {}.", url, hint),
+        None => format!("The first request to {} failed.", url),
+    }
+}
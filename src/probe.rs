@@ -0,0 +1,100 @@
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, VecDeque};
+use std::io;
+use std::time::{Duration, Instant};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use uuid::Uuid;
+
+const HISTORY_LEN: usize = 60;
+const PROBE_INTERVAL: Duration = Duration::from_secs(5);
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone)]
+pub struct LatencySample {
+    pub timestamp: DateTime<Utc>,
+    /// `None` when the device did not answer within the probe timeout.
+    pub latency: Option<Duration>,
+}
+
+/// Measures reachability from the TUI host to each device with a TCP connect
+/// probe. ICMP needs raw sockets (root or CAP_NET_RAW), whereas a TCP handshake
+/// works unprivileged and a refused connection still proves the host is up.
+pub struct LatencyMonitor {
+    port: u16,
+    last_run: Option<Instant>,
+    history: HashMap<Uuid, VecDeque<LatencySample>>,
+    tx: UnboundedSender<(Uuid, LatencySample)>,
+    rx: UnboundedReceiver<(Uuid, LatencySample)>,
+}
+
+impl LatencyMonitor {
+    pub fn new(port: u16) -> Self {
+        let (tx, rx) = unbounded_channel();
+        Self {
+            port,
+            last_run: None,
+            history: HashMap::new(),
+            tx,
+            rx,
+        }
+    }
+
+    /// Starts a probe round for `targets` if the last one is old enough.
+    pub fn tick(&mut self, targets: impl IntoIterator<Item = (Uuid, String)>) {
+        if self.last_run.is_some_and(|t| t.elapsed() < PROBE_INTERVAL) {
+            return;
+        }
+        self.last_run = Some(Instant::now());
+
+        for (device_id, ip_address) in targets {
+            let tx = self.tx.clone();
+            let port = self.port;
+            tokio::spawn(async move {
+                let latency = probe(&ip_address, port).await;
+                let sample = LatencySample {
+                    timestamp: Utc::now(),
+                    latency,
+                };
+                let _ = tx.send((device_id, sample));
+            });
+        }
+    }
+
+    pub fn drain(&mut self) {
+        while let Ok((device_id, sample)) = self.rx.try_recv() {
+            let history = self
+                .history
+                .entry(device_id)
+                .or_insert_with(|| VecDeque::with_capacity(HISTORY_LEN));
+            if history.len() >= HISTORY_LEN {
+                history.pop_front();
+            }
+            history.push_back(sample);
+        }
+    }
+
+    pub fn latest(&self, device_id: &Uuid) -> Option<&LatencySample> {
+        self.history.get(device_id).and_then(|h| h.back())
+    }
+
+    pub fn history(&self, device_id: &Uuid) -> Option<&VecDeque<LatencySample>> {
+        self.history.get(device_id)
+    }
+}
+
+async fn probe(ip_address: &str, port: u16) -> Option<Duration> {
+    let started = Instant::now();
+    match tokio::time::timeout(PROBE_TIMEOUT, TcpStream::connect((ip_address, port))).await {
+        Ok(Ok(_)) => Some(started.elapsed()),
+        Ok(Err(e)) if e.kind() == io::ErrorKind::ConnectionRefused => Some(started.elapsed()),
+        _ => None,
+    }
+}
+
+pub fn format_latency(latency: Option<Duration>) -> String {
+    match latency {
+        Some(latency) => format!("{:.1} ms", latency.as_secs_f64() * 1000.0),
+        None => "timeout".to_string(),
+    }
+}
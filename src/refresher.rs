@@ -0,0 +1,184 @@
+use crate::error::Result;
+use crate::source::NetworkSource;
+use crate::state::{fetch_snapshot, DetailFetch, Snapshot};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use uuid::Uuid;
+
+struct RefreshRequest {
+    source: Arc<dyn NetworkSource>,
+    site_id: Option<Uuid>,
+    details: DetailFetch,
+}
+
+/// A site's data fetched ahead of switching to it, see [`Refresher::prefetch`].
+struct Prefetched {
+    site_id: Uuid,
+    fetched_at: Instant,
+    snapshot: Snapshot,
+}
+
+/// Fetches controller data on a dedicated task. The UI loop asks for a refresh when
+/// one is due and applies the snapshot once it arrives, so slow API calls never hold
+/// up key handling or rendering. Only one fetch is in flight at a time.
+///
+/// Polling is the only option: the Integration API has no event or WebSocket feed to
+/// apply client and device changes from incrementally.
+pub struct Refresher {
+    requests: UnboundedSender<RefreshRequest>,
+    snapshots: UnboundedReceiver<(Option<Uuid>, Result<Snapshot>)>,
+    in_flight: bool,
+    prefetch_tx: UnboundedSender<(Uuid, Instant, Result<Snapshot>)>,
+    prefetch_rx: UnboundedReceiver<(Uuid, Instant, Result<Snapshot>)>,
+    prefetched: Option<Prefetched>,
+}
+
+impl Refresher {
+    pub fn new() -> Self {
+        let (requests, mut pending) = unbounded_channel::<RefreshRequest>();
+        let (tx, snapshots) = unbounded_channel();
+        tokio::spawn(async move {
+            while let Some(request) = pending.recv().await {
+                let snapshot =
+                    fetch_snapshot(request.source, request.site_id, request.details).await;
+                if tx.send((request.site_id, snapshot)).is_err() {
+                    break;
+                }
+            }
+            tracing::debug!("Refresh task stopped");
+        });
+        let (prefetch_tx, prefetch_rx) = unbounded_channel();
+        Self {
+            requests,
+            snapshots,
+            in_flight: false,
+            prefetch_tx,
+            prefetch_rx,
+            prefetched: None,
+        }
+    }
+
+    pub fn is_busy(&self) -> bool {
+        self.in_flight
+    }
+
+    /// Starts fetching the data of `site_id`, or of every site without one.
+    pub fn request(
+        &mut self,
+        source: Arc<dyn NetworkSource>,
+        site_id: Option<Uuid>,
+        details: DetailFetch,
+    ) {
+        if self.in_flight {
+            return;
+        }
+        let request = RefreshRequest {
+            source,
+            site_id,
+            details,
+        };
+        match self.requests.send(request) {
+            Ok(()) => self.in_flight = true,
+            Err(_) => tracing::error!("Refresh task is not running"),
+        }
+    }
+
+    /// The finished fetch, if any, with the site context it was requested for.
+    pub fn try_recv(&mut self) -> Option<(Option<Uuid>, Result<Snapshot>)> {
+        let fetched = self.snapshots.try_recv().ok()?;
+        self.in_flight = false;
+        Some(fetched)
+    }
+
+    /// Fetches the device and client overviews of `site_id` alongside the regular
+    /// refresh, so switching to that site can show them without waiting for a fetch.
+    /// Only the latest prefetched site is kept.
+    pub fn prefetch(&mut self, source: Arc<dyn NetworkSource>, site_id: Uuid) {
+        let tx = self.prefetch_tx.clone();
+        tokio::spawn(async move {
+            let snapshot = fetch_snapshot(source, Some(site_id), DetailFetch::Open(None)).await;
+            let _ = tx.send((site_id, Instant::now(), snapshot));
+        });
+    }
+
+    /// The prefetched data of `site_id`, if it is no older than `max_age`.
+    pub fn take_prefetched(&mut self, site_id: Uuid, max_age: Duration) -> Option<Snapshot> {
+        while let Ok((id, fetched_at, snapshot)) = self.prefetch_rx.try_recv() {
+            match snapshot {
+                Ok(snapshot) => {
+                    self.prefetched = Some(Prefetched {
+                        site_id: id,
+                        fetched_at,
+                        snapshot,
+                    })
+                }
+                Err(e) => tracing::debug!(site_id = %id, error = %e, "Site prefetch failed"),
+            }
+        }
+        if self.prefetched.as_ref()?.site_id != site_id {
+            return None;
+        }
+        let prefetched = self.prefetched.take()?;
+        (prefetched.fetched_at.elapsed() <= max_age).then_some(prefetched.snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::MockSource;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn fetches_off_the_calling_task() {
+        let mut refresher = Refresher::new();
+        refresher.request(Arc::new(MockSource::default()), None, DetailFetch::All);
+        assert!(refresher.is_busy());
+
+        let mut fetched = None;
+        for _ in 0..100 {
+            fetched = refresher.try_recv();
+            if fetched.is_some() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let (site_id, snapshot) = fetched.expect("snapshot");
+        assert_eq!(site_id, None);
+        assert!(snapshot.is_ok());
+        assert!(!refresher.is_busy());
+    }
+
+    #[tokio::test]
+    async fn keeps_prefetched_site_until_taken() {
+        let mut refresher = Refresher::new();
+        let site_id = Uuid::new_v4();
+        let max_age = Duration::from_secs(60);
+        refresher.prefetch(Arc::new(MockSource::default()), site_id);
+
+        let mut prefetched = None;
+        for _ in 0..100 {
+            prefetched = refresher.take_prefetched(site_id, max_age);
+            if prefetched.is_some() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(prefetched.is_some());
+        assert!(refresher.take_prefetched(site_id, max_age).is_none());
+        assert!(!refresher.is_busy());
+    }
+
+    #[tokio::test]
+    async fn ages_prefetched_site_from_when_it_was_fetched() {
+        let mut refresher = Refresher::new();
+        let site_id = Uuid::new_v4();
+        refresher.prefetch(Arc::new(MockSource::default()), site_id);
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        // Never taken while it waited, but still too old by the time it is.
+        let max_age = Duration::from_millis(100);
+        assert!(refresher.take_prefetched(site_id, max_age).is_none());
+    }
+}
@@ -0,0 +1,253 @@
+use crate::alerts::Alert;
+use crate::files;
+use crate::state::AppState;
+use crate::ui::widgets::format_network_speed;
+use chrono::Local;
+use clap::ValueEnum;
+use std::path::PathBuf;
+use unifi_rs::device::DeviceState;
+use uuid::Uuid;
+
+const TOP_TALKERS: usize = 10;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReportFormat::Markdown => "md",
+            ReportFormat::Html => "html",
+        }
+    }
+}
+
+struct Section {
+    title: &'static str,
+    headers: &'static [&'static str],
+    rows: Vec<Vec<String>>,
+    empty: &'static str,
+}
+
+/// Renders the weekly-style health summary: per-site counts, offline devices,
+/// firmware status, top talkers by uplink throughput and current alerts.
+pub fn render(state: &AppState, alerts: &[&Alert], format: ReportFormat) -> String {
+    let sections = sections(state, alerts);
+    let generated = Local::now().format("%Y-%m-%d %H:%M").to_string();
+    match format {
+        ReportFormat::Markdown => render_markdown(&generated, &sections),
+        ReportFormat::Html => render_html(&generated, &sections),
+    }
+}
+
+/// Writes a rendered report to a timestamped file under the data directory.
+pub fn save(contents: &str, format: ReportFormat) -> std::io::Result<PathBuf> {
+    let dirs = files::require_project_dirs()?;
+    let dir = dirs.data_dir().join("reports");
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!(
+        "report-{}.{}",
+        Local::now().format("%Y%m%d-%H%M%S"),
+        format.extension()
+    ));
+    std::fs::write(&path, contents)?;
+    Ok(path)
+}
+
+fn sections(state: &AppState, alerts: &[&Alert]) -> Vec<Section> {
+    let device_name = |id: &Uuid| {
+        state
+            .devices
+            .iter()
+            .find(|d| d.id == *id)
+            .map_or("Unknown".to_string(), |d| d.name.clone())
+    };
+    let site_name = |device_id: &Uuid| {
+        state
+            .device_sites
+            .get(device_id)
+            .and_then(|site_id| state.sites.iter().find(|s| s.id == *site_id))
+            .and_then(|site| site.name.clone())
+            .unwrap_or_else(|| "Unknown".to_string())
+    };
+
+    let summaries = state.site_summaries();
+    let sites = state
+        .sites
+        .iter()
+        .map(|site| {
+            let summary = summaries.get(&site.id).copied().unwrap_or_default();
+            vec![
+                site.name.clone().unwrap_or_else(|| "Unnamed".to_string()),
+                summary.devices.to_string(),
+                summary.online.to_string(),
+                summary.clients.to_string(),
+            ]
+        })
+        .collect();
+
+    let offline = state
+        .devices
+        .iter()
+        .filter(|d| !matches!(d.state, DeviceState::Online))
+        .map(|d| {
+            vec![
+                d.name.clone(),
+                d.model.clone(),
+                format!("{:?}", d.state),
+                site_name(&d.id),
+            ]
+        })
+        .collect();
+
+    let firmware = state
+        .devices
+        .iter()
+        .map(|d| {
+            let details = state.device_details.get(&d.id);
+            vec![
+                d.name.clone(),
+                d.model.clone(),
+                details.map_or("Unknown".to_string(), |d| d.firmware_version.clone()),
+                match details {
+                    Some(d) if d.firmware_updatable => "Update available".to_string(),
+                    Some(_) => "Up to date".to_string(),
+                    None => "Unknown".to_string(),
+                },
+            ]
+        })
+        .collect();
+
+    let mut talkers: Vec<_> = state
+        .devices
+        .iter()
+        .filter_map(|d| {
+            let uplink = state.device_stats.get(&d.id)?.uplink.as_ref()?;
+            Some((d, uplink.tx_rate_bps, uplink.rx_rate_bps))
+        })
+        .collect();
+    talkers.sort_by_key(|(_, tx, rx)| std::cmp::Reverse(tx + rx));
+    let talkers = talkers
+        .into_iter()
+        .take(TOP_TALKERS)
+        .map(|(d, tx, rx)| {
+            vec![
+                d.name.clone(),
+                site_name(&d.id),
+                format_network_speed(tx),
+                format_network_speed(rx),
+            ]
+        })
+        .collect();
+
+    let alerts = alerts
+        .iter()
+        .map(|alert| {
+            vec![
+                alert
+                    .raised_at
+                    .with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string(),
+                format!("{:?}", alert.kind),
+                device_name(&alert.device_id),
+                alert.message.clone(),
+            ]
+        })
+        .collect();
+
+    vec![
+        Section {
+            title: "Sites",
+            headers: &["Site", "Devices", "Online", "Clients"],
+            rows: sites,
+            empty: "No sites",
+        },
+        Section {
+            title: "Offline Devices",
+            headers: &["Device", "Model", "State", "Site"],
+            rows: offline,
+            empty: "All devices online",
+        },
+        Section {
+            title: "Firmware",
+            headers: &["Device", "Model", "Version", "Status"],
+            rows: firmware,
+            empty: "No devices",
+        },
+        Section {
+            title: "Top Talkers",
+            headers: &["Device", "Site", "TX", "RX"],
+            rows: talkers,
+            empty: "No throughput data",
+        },
+        Section {
+            title: "Alerts",
+            headers: &["Raised", "Kind", "Device", "Message"],
+            rows: alerts,
+            empty: "No active alerts",
+        },
+    ]
+}
+
+fn render_markdown(generated: &str, sections: &[Section]) -> String {
+    let mut out = format!("# Network Health Report\n\nGenerated {}\n", generated);
+    for section in sections {
+        out.push_str(&format!("\n## {}\n\n", section.title));
+        if section.rows.is_empty() {
+            out.push_str(&format!("_{}_\n", section.empty));
+            continue;
+        }
+        out.push_str(&format!("| {} |\n", section.headers.join(" | ")));
+        out.push_str(&format!("|{}\n", "---|".repeat(section.headers.len())));
+        for row in &section.rows {
+            let cells: Vec<String> = row.iter().map(|c| c.replace('|', "\\|")).collect();
+            out.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+    }
+    out
+}
+
+fn render_html(generated: &str, sections: &[Section]) -> String {
+    let mut out = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Network Health Report</title>\n<style>\n\
+         body { font-family: sans-serif; margin: 2em; }\n\
+         table { border-collapse: collapse; margin-bottom: 1em; }\n\
+         th, td { border: 1px solid #ccc; padding: 4px 8px; text-align: left; }\n\
+         </style>\n</head>\n<body>\n<h1>Network Health Report</h1>\n",
+    );
+    out.push_str(&format!("<p>Generated {}</p>\n", escape_html(generated)));
+    for section in sections {
+        out.push_str(&format!("<h2>{}</h2>\n", section.title));
+        if section.rows.is_empty() {
+            out.push_str(&format!("<p><em>{}</em></p>\n", section.empty));
+            continue;
+        }
+        out.push_str("<table>\n<tr>");
+        for header in section.headers {
+            out.push_str(&format!("<th>{}</th>", header));
+        }
+        out.push_str("</tr>\n");
+        for row in &section.rows {
+            out.push_str("<tr>");
+            for cell in row {
+                out.push_str(&format!("<td>{}</td>", escape_html(cell)));
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</table>\n");
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,89 @@
+use crate::actions::DeviceAction;
+use chrono::{DateTime, Duration, Local, NaiveTime};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct ScheduledJob {
+    pub id: Uuid,
+    pub site_id: Uuid,
+    pub device_id: Uuid,
+    pub device_name: String,
+    pub action: DeviceAction,
+    pub run_at: DateTime<Local>,
+}
+
+#[derive(Default)]
+pub struct Scheduler {
+    jobs: Vec<ScheduledJob>,
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn jobs(&self) -> &[ScheduledJob] {
+        &self.jobs
+    }
+
+    pub fn schedule(&mut self, job: ScheduledJob) {
+        tracing::info!(
+            job_id = ?job.id,
+            device_id = ?job.device_id,
+            action = ?job.action,
+            run_at = %job.run_at,
+            "Scheduled device action"
+        );
+        self.jobs.push(job);
+        self.jobs.sort_by_key(|j| j.run_at);
+    }
+
+    pub fn cancel(&mut self, job_id: Uuid) -> Option<ScheduledJob> {
+        let idx = self.jobs.iter().position(|j| j.id == job_id)?;
+        tracing::info!(job_id = ?job_id, "Cancelled scheduled action");
+        Some(self.jobs.remove(idx))
+    }
+
+    /// Removes and returns every job whose run time has passed.
+    pub fn take_due(&mut self, now: DateTime<Local>) -> Vec<ScheduledJob> {
+        let (due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.jobs)
+            .into_iter()
+            .partition(|j| j.run_at <= now);
+        self.jobs = pending;
+        due
+    }
+}
+
+/// Parses an `HH:MM` time into its next occurrence, rolling over to tomorrow
+/// when the time has already passed today.
+pub fn parse_run_at(input: &str, now: DateTime<Local>) -> Option<DateTime<Local>> {
+    let time = NaiveTime::parse_from_str(input.trim(), "%H:%M").ok()?;
+    let today = now
+        .date_naive()
+        .and_time(time)
+        .and_local_timezone(Local)
+        .earliest()?;
+
+    if today > now {
+        Some(today)
+    } else {
+        (now.date_naive() + Duration::days(1))
+            .and_time(time)
+            .and_local_timezone(Local)
+            .earliest()
+    }
+}
+
+pub fn format_countdown(run_at: DateTime<Local>, now: DateTime<Local>) -> String {
+    let remaining = run_at.signed_duration_since(now);
+    let hours = remaining.num_hours();
+    let minutes = remaining.num_minutes() % 60;
+
+    if hours > 0 {
+        format!("in {}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("in {}m", minutes)
+    } else {
+        "now".to_string()
+    }
+}
@@ -0,0 +1,632 @@
+//! Expressions for the computed columns and filters in the `scripts` file. Columns
+//! are evaluated for every visible row on every frame, so the language is kept to
+//! single expressions: no loops, assignments or I/O, which means evaluation always
+//! finishes quickly and a script can't stall the UI or touch the system.
+
+use crate::state::{frequency_label, AppState};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use unifi_rs::device::DeviceOverview;
+use unifi_rs::models::client::ClientOverview;
+
+/// Fields of one device or client row, by the names scripts use.
+pub type Record = HashMap<&'static str, Value>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Num(f64),
+    Str(String),
+}
+
+impl Value {
+    fn truthy(&self) -> bool {
+        match self {
+            Value::Null => false,
+            Value::Bool(b) => *b,
+            Value::Num(n) => *n != 0.0,
+            Value::Str(s) => !s.is_empty(),
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            Value::Null => "null",
+            Value::Bool(_) => "bool",
+            Value::Num(_) => "number",
+            Value::Str(_) => "string",
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "-"),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Num(n) if n.fract() == 0.0 && n.abs() < 1e15 => write!(f, "{}", *n as i64),
+            Value::Num(n) => write!(f, "{:.1}", n),
+            Value::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<Option<f64>> for Value {
+    fn from(value: Option<f64>) -> Self {
+        value.map_or(Value::Null, Value::Num)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinOp {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Contains,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl BinOp {
+    fn precedence(self) -> u8 {
+        match self {
+            BinOp::Or => 1,
+            BinOp::And => 2,
+            BinOp::Eq
+            | BinOp::Ne
+            | BinOp::Lt
+            | BinOp::Le
+            | BinOp::Gt
+            | BinOp::Ge
+            | BinOp::Contains => 3,
+            BinOp::Add | BinOp::Sub => 4,
+            BinOp::Mul | BinOp::Div | BinOp::Rem => 5,
+        }
+    }
+}
+
+/// A parsed script expression, e.g. `uptime / 86400` or
+/// `type == "wireless" and days_since(connected_at) > 7`.
+#[derive(Debug)]
+pub enum Expr {
+    Literal(Value),
+    Field(String),
+    Not(Box<Expr>),
+    Neg(Box<Expr>),
+    Binary(BinOp, Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+}
+
+impl Expr {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.expression(0)?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(expr),
+            Some(token) => Err(format!("unexpected {}", token)),
+        }
+    }
+
+    pub fn eval(&self, record: &Record) -> Result<Value, String> {
+        match self {
+            Expr::Literal(value) => Ok(value.clone()),
+            Expr::Field(name) => record
+                .get(name.as_str())
+                .cloned()
+                .ok_or_else(|| format!("unknown field {}", name)),
+            Expr::Not(inner) => Ok(Value::Bool(!inner.eval(record)?.truthy())),
+            Expr::Neg(inner) => match inner.eval(record)? {
+                Value::Num(n) => Ok(Value::Num(-n)),
+                Value::Null => Ok(Value::Null),
+                other => Err(format!("cannot negate a {}", other.type_name())),
+            },
+            Expr::Binary(BinOp::And, lhs, rhs) => Ok(Value::Bool(
+                lhs.eval(record)?.truthy() && rhs.eval(record)?.truthy(),
+            )),
+            Expr::Binary(BinOp::Or, lhs, rhs) => Ok(Value::Bool(
+                lhs.eval(record)?.truthy() || rhs.eval(record)?.truthy(),
+            )),
+            Expr::Binary(op, lhs, rhs) => binary(*op, lhs.eval(record)?, rhs.eval(record)?),
+            Expr::Call(name, args) => {
+                let args = args
+                    .iter()
+                    .map(|arg| arg.eval(record))
+                    .collect::<Result<Vec<_>, _>>()?;
+                call(name, args)
+            }
+        }
+    }
+
+    /// Whether a row passes the expression used as a filter. Rows the expression
+    /// can't be evaluated for are left out.
+    pub fn matches(&self, record: &Record) -> bool {
+        self.eval(record).is_ok_and(|value| value.truthy())
+    }
+}
+
+fn binary(op: BinOp, lhs: Value, rhs: Value) -> Result<Value, String> {
+    use Value::*;
+    let value = match (op, lhs, rhs) {
+        (BinOp::Eq, a, b) => Bool(a == b),
+        (BinOp::Ne, a, b) => Bool(a != b),
+        (BinOp::Contains, Str(a), Str(b)) => Bool(a.to_lowercase().contains(&b.to_lowercase())),
+        // Missing values (no stats yet) propagate instead of failing the whole row.
+        (_, Null, _) | (_, _, Null) => Null,
+        (BinOp::Lt, Num(a), Num(b)) => Bool(a < b),
+        (BinOp::Le, Num(a), Num(b)) => Bool(a <= b),
+        (BinOp::Gt, Num(a), Num(b)) => Bool(a > b),
+        (BinOp::Ge, Num(a), Num(b)) => Bool(a >= b),
+        (BinOp::Lt, Str(a), Str(b)) => Bool(a < b),
+        (BinOp::Le, Str(a), Str(b)) => Bool(a <= b),
+        (BinOp::Gt, Str(a), Str(b)) => Bool(a > b),
+        (BinOp::Ge, Str(a), Str(b)) => Bool(a >= b),
+        (BinOp::Add, Num(a), Num(b)) => Num(a + b),
+        (BinOp::Add, Str(a), b) => Str(format!("{}{}", a, b)),
+        (BinOp::Add, a, Str(b)) => Str(format!("{}{}", a, b)),
+        (BinOp::Sub, Num(a), Num(b)) => Num(a - b),
+        (BinOp::Mul, Num(a), Num(b)) => Num(a * b),
+        (BinOp::Div, Num(_), Num(0.0)) => Null,
+        (BinOp::Div, Num(a), Num(b)) => Num(a / b),
+        (BinOp::Rem, Num(_), Num(0.0)) => Null,
+        (BinOp::Rem, Num(a), Num(b)) => Num(a % b),
+        (op, a, b) => {
+            return Err(format!(
+                "cannot apply {:?} to a {} and a {}",
+                op,
+                a.type_name(),
+                b.type_name()
+            ))
+        }
+    };
+    Ok(value)
+}
+
+fn call(name: &str, args: Vec<Value>) -> Result<Value, String> {
+    let value = match (name, args.as_slice()) {
+        (_, [Value::Null]) => Value::Null,
+        ("days_since", [Value::Str(timestamp)]) => {
+            let at = DateTime::parse_from_rfc3339(timestamp)
+                .map_err(|_| format!("days_since: `{}` is not a timestamp", timestamp))?;
+            let elapsed = Utc::now().signed_duration_since(at);
+            Value::Num(elapsed.num_seconds() as f64 / 86_400.0)
+        }
+        ("round", [Value::Num(n)]) => Value::Num(n.round()),
+        ("lower", [Value::Str(s)]) => Value::Str(s.to_lowercase()),
+        ("len", [Value::Str(s)]) => Value::Num(s.chars().count() as f64),
+        _ => {
+            return Err(format!(
+                "{}() does not take ({})",
+                name,
+                args.iter()
+                    .map(Value::type_name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        }
+    };
+    Ok(value)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Num(f64),
+    Str(String),
+    Ident(String),
+    Op(BinOp),
+    Not,
+    LParen,
+    RParen,
+    Comma,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Num(n) => write!(f, "number {}", n),
+            Token::Str(s) => write!(f, "string \"{}\"", s),
+            Token::Ident(name) => write!(f, "`{}`", name),
+            Token::Op(op) => write!(f, "operator {:?}", op),
+            Token::Not => write!(f, "`not`"),
+            Token::LParen => write!(f, "`(`"),
+            Token::RParen => write!(f, "`)`"),
+            Token::Comma => write!(f, "`,`"),
+        }
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = source.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        if c.is_ascii_digit() || c == '.' {
+            let mut number = String::new();
+            while let Some(&d) = chars.peek().filter(|d| d.is_ascii_digit() || **d == '.') {
+                number.push(d);
+                chars.next();
+            }
+            let n = number
+                .parse()
+                .map_err(|_| format!("invalid number {}", number))?;
+            tokens.push(Token::Num(n));
+            continue;
+        }
+        if c.is_alphabetic() || c == '_' {
+            let mut ident = String::new();
+            while let Some(&d) = chars.peek().filter(|d| d.is_alphanumeric() || **d == '_') {
+                ident.push(d);
+                chars.next();
+            }
+            tokens.push(match ident.as_str() {
+                "and" => Token::Op(BinOp::And),
+                "or" => Token::Op(BinOp::Or),
+                "not" => Token::Not,
+                "contains" => Token::Op(BinOp::Contains),
+                _ => Token::Ident(ident),
+            });
+            continue;
+        }
+        chars.next();
+        let token = match c {
+            '"' | '\'' => {
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some(end) if end == c => break,
+                        Some(ch) => text.push(ch),
+                        None => return Err("unterminated string".to_string()),
+                    }
+                }
+                Token::Str(text)
+            }
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            ',' => Token::Comma,
+            '+' => Token::Op(BinOp::Add),
+            '-' => Token::Op(BinOp::Sub),
+            '*' => Token::Op(BinOp::Mul),
+            '/' => Token::Op(BinOp::Div),
+            '%' => Token::Op(BinOp::Rem),
+            '=' | '!' | '<' | '>' | '&' | '|' => {
+                let next = chars.next_if(|&n| n == '=' || (n == c && matches!(c, '&' | '|')));
+                match (c, next) {
+                    ('=', Some('=')) => Token::Op(BinOp::Eq),
+                    ('!', Some('=')) => Token::Op(BinOp::Ne),
+                    ('!', None) => Token::Not,
+                    ('<', Some('=')) => Token::Op(BinOp::Le),
+                    ('<', None) => Token::Op(BinOp::Lt),
+                    ('>', Some('=')) => Token::Op(BinOp::Ge),
+                    ('>', None) => Token::Op(BinOp::Gt),
+                    ('&', Some('&')) => Token::Op(BinOp::And),
+                    ('|', Some('|')) => Token::Op(BinOp::Or),
+                    _ => return Err(format!("unexpected `{}`", c)),
+                }
+            }
+            _ => return Err(format!("unexpected `{}`", c)),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), String> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            Some(token) => Err(format!("expected {}, found {}", expected, token)),
+            None => Err(format!("expected {}", expected)),
+        }
+    }
+
+    /// Precedence climbing over the binary operators.
+    fn expression(&mut self, min_precedence: u8) -> Result<Expr, String> {
+        let mut lhs = self.unary()?;
+        while let Some(Token::Op(op)) = self.tokens.get(self.pos).cloned() {
+            if op.precedence() <= min_precedence {
+                break;
+            }
+            self.pos += 1;
+            let rhs = self.expression(op.precedence())?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Not) => {
+                self.pos += 1;
+                Ok(Expr::Not(Box::new(self.unary()?)))
+            }
+            Some(Token::Op(BinOp::Sub)) => {
+                self.pos += 1;
+                Ok(Expr::Neg(Box::new(self.unary()?)))
+            }
+            _ => self.primary(),
+        }
+    }
+
+    fn primary(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Num(n)) => Ok(Expr::Literal(Value::Num(n))),
+            Some(Token::Str(s)) => Ok(Expr::Literal(Value::Str(s))),
+            Some(Token::LParen) => {
+                let expr = self.expression(0)?;
+                self.expect(Token::RParen)?;
+                Ok(expr)
+            }
+            Some(Token::Ident(name)) => match name.as_str() {
+                "true" => Ok(Expr::Literal(Value::Bool(true))),
+                "false" => Ok(Expr::Literal(Value::Bool(false))),
+                "null" => Ok(Expr::Literal(Value::Null)),
+                _ if self.tokens.get(self.pos) == Some(&Token::LParen) => {
+                    self.pos += 1;
+                    let mut args = Vec::new();
+                    if self.tokens.get(self.pos) == Some(&Token::RParen) {
+                        self.pos += 1;
+                    } else {
+                        loop {
+                            args.push(self.expression(0)?);
+                            match self.next() {
+                                Some(Token::Comma) => continue,
+                                Some(Token::RParen) => break,
+                                _ => return Err(format!("unclosed call to {}", name)),
+                            }
+                        }
+                    }
+                    Ok(Expr::Call(name, args))
+                }
+                _ => Ok(Expr::Field(name)),
+            },
+            Some(token) => Err(format!("unexpected {}", token)),
+            None => Err("unexpected end of expression".to_string()),
+        }
+    }
+}
+
+pub fn device_record(state: &AppState, device: &DeviceOverview) -> Record {
+    let stats = state.device_stats.get(&device.id);
+    let details = state.device_details.get(&device.id);
+    let uplink = stats.and_then(|s| s.uplink.as_ref());
+    let clients = state
+        .device_client_counts
+        .get(&device.id)
+        .copied()
+        .unwrap_or_default();
+
+    HashMap::from([
+        ("name", Value::Str(device.name.clone())),
+        ("model", Value::Str(device.model.clone())),
+        ("mac", Value::Str(device.mac_address.clone())),
+        ("ip", Value::Str(device.ip_address.clone())),
+        ("state", Value::Str(format!("{:?}", device.state))),
+        (
+            "firmware",
+            details.map_or(Value::Null, |d| Value::Str(d.firmware_version.clone())),
+        ),
+        ("cpu", stats.and_then(|s| s.cpu_utilization_pct).into()),
+        (
+            "memory",
+            stats.and_then(|s| s.memory_utilization_pct).into(),
+        ),
+        ("uptime", stats.map(|s| s.uptime_sec as f64).into()),
+        ("tx", uplink.map(|u| u.tx_rate_bps as f64).into()),
+        ("rx", uplink.map(|u| u.rx_rate_bps as f64).into()),
+        ("clients", Value::Num(clients as f64)),
+    ])
+}
+
+pub fn client_record(state: &AppState, client: &ClientOverview) -> Record {
+    let (base, mac, uplink, kind) = match client {
+        ClientOverview::Wired(c) => (&c.base, &c.mac_address, c.uplink_device_id, "wired"),
+        ClientOverview::Wireless(c) => (&c.base, &c.mac_address, c.uplink_device_id, "wireless"),
+        _ => return HashMap::from([("type", Value::Str("other".to_string()))]),
+    };
+    let device = state.devices.iter().find(|d| d.id == uplink);
+
+    HashMap::from([
+        (
+            "name",
+            Value::Str(state.aliases.display_name(mac, base.name.as_deref())),
+        ),
+        ("mac", Value::Str(mac.clone())),
+        (
+            "ip",
+            base.ip_address.clone().map_or(Value::Null, Value::Str),
+        ),
+        ("type", Value::Str(kind.to_string())),
+        ("connected_at", Value::Str(base.connected_at.to_rfc3339())),
+        (
+            "uplink",
+            device.map_or(Value::Null, |d| Value::Str(d.name.clone())),
+        ),
+        (
+            "band",
+            state.client_band(client).map_or(Value::Null, |(band, _)| {
+                Value::Str(frequency_label(band).to_string())
+            }),
+        ),
+    ])
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScriptTarget {
+    Device,
+    Client,
+}
+
+/// A named expression from the scripts file, shown as a column or used as a filter.
+#[derive(Debug, Clone)]
+pub struct Script {
+    pub name: String,
+    pub expr: Arc<Expr>,
+}
+
+impl Script {
+    /// The column text for a row; evaluation errors show as `ERR`.
+    pub fn cell(&self, record: &Record) -> String {
+        match self.expr.eval(record) {
+            Ok(value) => value.to_string(),
+            Err(_) => "ERR".to_string(),
+        }
+    }
+}
+
+/// Computed columns and filters read from the `scripts` file in the config
+/// directory, one per line:
+///
+/// ```text
+/// device column Days up = round(uptime / 86400)
+/// client filter Stale = days_since(connected_at) > 7
+/// ```
+#[derive(Debug, Default)]
+pub struct Scripts {
+    pub device_columns: Vec<Script>,
+    pub client_columns: Vec<Script>,
+    pub device_filters: Vec<Script>,
+    pub client_filters: Vec<Script>,
+}
+
+impl Scripts {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut scripts = Self::default();
+        for (number, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: &str| format!("line {}: {}", number + 1, message);
+
+            let mut words = line.splitn(3, char::is_whitespace);
+            let (target, kind, rest) = (words.next(), words.next(), words.next());
+            let Some((name, source)) = rest.and_then(|rest| rest.split_once('=')) else {
+                return Err(error(
+                    "expected `<device|client> <column|filter> NAME = EXPR`",
+                ));
+            };
+            let script = Script {
+                name: name.trim().to_string(),
+                expr: Arc::new(Expr::parse(source.trim()).map_err(|e| error(&e))?),
+            };
+            if script.name.is_empty() {
+                return Err(error("missing name"));
+            }
+
+            let list = match (target, kind) {
+                (Some("device"), Some("column")) => &mut scripts.device_columns,
+                (Some("client"), Some("column")) => &mut scripts.client_columns,
+                (Some("device"), Some("filter")) => &mut scripts.device_filters,
+                (Some("client"), Some("filter")) => &mut scripts.client_filters,
+                _ => return Err(error("expected `<device|client> <column|filter>`")),
+            };
+            list.push(script);
+        }
+        Ok(scripts)
+    }
+
+    pub fn columns(&self, target: ScriptTarget) -> &[Script] {
+        match target {
+            ScriptTarget::Device => &self.device_columns,
+            ScriptTarget::Client => &self.client_columns,
+        }
+    }
+
+    pub fn filters(&self, target: ScriptTarget) -> &[Script] {
+        match target {
+            ScriptTarget::Device => &self.device_filters,
+            ScriptTarget::Client => &self.client_filters,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(source: &str, record: &Record) -> Result<Value, String> {
+        Expr::parse(source)?.eval(record)
+    }
+
+    #[test]
+    fn arithmetic_follows_precedence() {
+        let record = Record::new();
+        assert_eq!(eval("1 + 2 * 3", &record), Ok(Value::Num(7.0)));
+        assert_eq!(eval("(1 + 2) * 3", &record), Ok(Value::Num(9.0)));
+        assert_eq!(eval("10 - 4 - 3", &record), Ok(Value::Num(3.0)));
+        assert_eq!(eval("-2 * 3", &record), Ok(Value::Num(-6.0)));
+    }
+
+    #[test]
+    fn filters_combine_fields() {
+        let record = HashMap::from([
+            ("model", Value::Str("U6-Pro".to_string())),
+            ("clients", Value::Num(25.0)),
+            ("cpu", Value::Null),
+        ]);
+        let busy = Expr::parse(r#"clients > 20 and model contains "u6""#).unwrap();
+        assert!(busy.matches(&record));
+        let idle = Expr::parse("not (clients > 20) || model == 'USW'").unwrap();
+        assert!(!idle.matches(&record));
+        // Missing stats don't match rather than failing.
+        assert!(!Expr::parse("cpu > 50").unwrap().matches(&record));
+        assert!(!Expr::parse("uptime > 50").unwrap().matches(&record));
+    }
+
+    #[test]
+    fn values_format_for_columns() {
+        assert_eq!(Value::Num(3.0).to_string(), "3");
+        assert_eq!(Value::Num(2.34).to_string(), "2.3");
+        assert_eq!(Value::Null.to_string(), "-");
+    }
+
+    #[test]
+    fn parse_errors_are_reported() {
+        assert!(Expr::parse("1 +").is_err());
+        assert!(Expr::parse("(1").is_err());
+        assert!(Expr::parse("a = 1").is_err());
+        assert!(Expr::parse("\"open").is_err());
+        assert!(eval("round('x')", &Record::new()).is_err());
+    }
+
+    #[test]
+    fn scripts_file_is_parsed_by_target() {
+        let scripts = Scripts::parse(
+            "# comment\n\
+             device column Days up = round(uptime / 86400)\n\
+             client filter Stale = days_since(connected_at) > 7\n",
+        )
+        .unwrap();
+        assert_eq!(scripts.device_columns[0].name, "Days up");
+        assert_eq!(scripts.client_filters[0].name, "Stale");
+        assert!(scripts.device_filters.is_empty());
+
+        let error = Scripts::parse("site column X = 1").unwrap_err();
+        assert!(error.starts_with("line 1"));
+    }
+}
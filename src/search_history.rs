@@ -0,0 +1,93 @@
+use crate::error::Result;
+use crate::files;
+use std::path::PathBuf;
+
+const MAX_QUERIES: usize = 50;
+
+/// Recent search queries, oldest first, stored one per line next to the aliases.
+/// `cursor` tracks how far back Up has gone while the search box is open.
+pub struct SearchHistory {
+    path: Option<PathBuf>,
+    queries: Vec<String>,
+    cursor: Option<usize>,
+}
+
+impl SearchHistory {
+    pub fn open() -> Self {
+        let path = files::data_file("search_history");
+
+        let queries = path
+            .as_ref()
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .map(|content| {
+                content
+                    .lines()
+                    .filter(|line| !line.trim().is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            path,
+            queries,
+            cursor: None,
+        }
+    }
+
+    /// Adds `query` as the most recent entry, moving it up if it was already there.
+    pub fn record(&mut self, query: &str) -> Result<()> {
+        self.cursor = None;
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(());
+        }
+
+        self.queries.retain(|q| q != query);
+        self.queries.push(query.to_string());
+        if self.queries.len() > MAX_QUERIES {
+            self.queries.drain(..self.queries.len() - MAX_QUERIES);
+        }
+        self.save()
+    }
+
+    /// Steps back to the previous query, stopping at the oldest.
+    pub fn older(&mut self) -> Option<&str> {
+        let index = match self.cursor {
+            Some(0) => 0,
+            Some(i) => i - 1,
+            None => self.queries.len().checked_sub(1)?,
+        };
+        self.cursor = Some(index);
+        self.queries.get(index).map(String::as_str)
+    }
+
+    /// Steps forward to the next query; `None` once past the newest.
+    pub fn newer(&mut self) -> Option<&str> {
+        let index = self.cursor? + 1;
+        if index >= self.queries.len() {
+            self.cursor = None;
+            return None;
+        }
+        self.cursor = Some(index);
+        self.queries.get(index).map(String::as_str)
+    }
+
+    pub fn is_browsing(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    pub fn reset(&mut self) {
+        self.cursor = None;
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let contents: String = self.queries.iter().map(|q| format!("{}\n", q)).collect();
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use crate::files;
+use chrono::Local;
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+use std::path::PathBuf;
+use unicode_width::UnicodeWidthStr;
+
+/// Writes the frame buffer as a plain `.txt` file and an `.ans` file that keeps
+/// colors and text attributes as ANSI escape sequences. Returns the `.ans` path.
+pub fn save(buffer: &Buffer) -> std::io::Result<PathBuf> {
+    let dirs = files::require_project_dirs()?;
+    let dir = dirs.data_dir().join("snapshots");
+    std::fs::create_dir_all(&dir)?;
+    let stem = dir.join(format!("screen-{}", Local::now().format("%Y%m%d-%H%M%S")));
+
+    std::fs::write(stem.with_extension("txt"), to_text(buffer))?;
+    let ansi_path = stem.with_extension("ans");
+    std::fs::write(&ansi_path, to_ansi(buffer))?;
+    Ok(ansi_path)
+}
+
+fn to_text(buffer: &Buffer) -> String {
+    let mut out = String::new();
+    for y in buffer.area.top()..buffer.area.bottom() {
+        let mut line = String::new();
+        let mut skip = 0;
+        for x in buffer.area.left()..buffer.area.right() {
+            let symbol = buffer[(x, y)].symbol();
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            skip = symbol.width().saturating_sub(1);
+            line.push_str(symbol);
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+fn to_ansi(buffer: &Buffer) -> String {
+    let mut out = String::new();
+    for y in buffer.area.top()..buffer.area.bottom() {
+        let mut current = None;
+        let mut skip = 0;
+        for x in buffer.area.left()..buffer.area.right() {
+            let cell = &buffer[(x, y)];
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            skip = cell.symbol().width().saturating_sub(1);
+
+            let style = (cell.fg, cell.bg, cell.modifier);
+            if current != Some(style) {
+                out.push_str(&sgr(cell.fg, cell.bg, cell.modifier));
+                current = Some(style);
+            }
+            out.push_str(cell.symbol());
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+fn sgr(fg: Color, bg: Color, modifier: Modifier) -> String {
+    let mut codes = vec!["0".to_string()];
+    for (flag, code) in [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::CROSSED_OUT, "9"),
+    ] {
+        if modifier.contains(flag) {
+            codes.push(code.to_string());
+        }
+    }
+    codes.extend(color_code(fg, false));
+    codes.extend(color_code(bg, true));
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+fn color_code(color: Color, background: bool) -> Option<String> {
+    let offset = if background { 10 } else { 0 };
+    let base = match color {
+        Color::Reset => return None,
+        Color::Black => 30,
+        Color::Red => 31,
+        Color::Green => 32,
+        Color::Yellow => 33,
+        Color::Blue => 34,
+        Color::Magenta => 35,
+        Color::Cyan => 36,
+        Color::Gray => 37,
+        Color::DarkGray => 90,
+        Color::LightRed => 91,
+        Color::LightGreen => 92,
+        Color::LightYellow => 93,
+        Color::LightBlue => 94,
+        Color::LightMagenta => 95,
+        Color::LightCyan => 96,
+        Color::White => 97,
+        Color::Rgb(r, g, b) => {
+            return Some(format!("{};2;{};{};{}", 38 + offset, r, g, b));
+        }
+        Color::Indexed(i) => return Some(format!("{};5;{}", 38 + offset, i)),
+    };
+    Some((base + offset).to_string())
+}
@@ -0,0 +1,251 @@
+use crate::error::{AppError, Result};
+use std::future::Future;
+use std::pin::Pin;
+use tracing::instrument;
+use unifi_rs::common::Page;
+use unifi_rs::device::{DeviceDetails, DeviceOverview};
+use unifi_rs::models::client::ClientOverview;
+use unifi_rs::site::SiteOverview;
+use unifi_rs::statistics::DeviceStatistics;
+use unifi_rs::UnifiClient;
+use uuid::Uuid;
+
+const PAGE_SIZE: i32 = 25;
+
+pub type SourceFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+/// The controller reads `AppState` refreshes from. Implemented for `UnifiClient`, and
+/// by `MockSource` so the refresh logic can run without a controller.
+pub trait NetworkSource: Send + Sync {
+    fn sites(&self) -> SourceFuture<'_, Vec<SiteOverview>>;
+    fn devices(&self, site_id: Uuid) -> SourceFuture<'_, Vec<DeviceOverview>>;
+    fn clients(&self, site_id: Uuid) -> SourceFuture<'_, Vec<ClientOverview>>;
+    fn device_details(&self, site_id: Uuid, device_id: Uuid) -> SourceFuture<'_, DeviceDetails>;
+    fn device_statistics(
+        &self,
+        site_id: Uuid,
+        device_id: Uuid,
+    ) -> SourceFuture<'_, DeviceStatistics>;
+    fn application_version(&self) -> SourceFuture<'_, String>;
+}
+
+impl NetworkSource for UnifiClient {
+    fn sites(&self) -> SourceFuture<'_, Vec<SiteOverview>> {
+        Box::pin(fetch_all_paged_data(|offset, limit| {
+            let client = self.clone();
+            Box::pin(async move {
+                client
+                    .list_sites(Some(offset), Some(limit))
+                    .await
+                    .map_err(AppError::from)
+            })
+        }))
+    }
+
+    fn devices(&self, site_id: Uuid) -> SourceFuture<'_, Vec<DeviceOverview>> {
+        Box::pin(fetch_all_paged_data(move |offset, limit| {
+            let client = self.clone();
+            Box::pin(async move {
+                client
+                    .list_devices(site_id, Some(offset), Some(limit))
+                    .await
+                    .map_err(AppError::from)
+            })
+        }))
+    }
+
+    fn clients(&self, site_id: Uuid) -> SourceFuture<'_, Vec<ClientOverview>> {
+        Box::pin(fetch_all_paged_data(move |offset, limit| {
+            let client = self.clone();
+            Box::pin(async move {
+                client
+                    .list_clients(site_id, Some(offset), Some(limit))
+                    .await
+                    .map_err(AppError::from)
+            })
+        }))
+    }
+
+    fn device_details(&self, site_id: Uuid, device_id: Uuid) -> SourceFuture<'_, DeviceDetails> {
+        Box::pin(async move {
+            self.get_device_details(site_id, device_id)
+                .await
+                .map_err(AppError::from)
+        })
+    }
+
+    fn device_statistics(
+        &self,
+        site_id: Uuid,
+        device_id: Uuid,
+    ) -> SourceFuture<'_, DeviceStatistics> {
+        Box::pin(async move {
+            self.get_device_statistics(site_id, device_id)
+                .await
+                .map_err(AppError::from)
+        })
+    }
+
+    fn application_version(&self) -> SourceFuture<'_, String> {
+        Box::pin(async move {
+            let info = self.get_info().await?;
+            Ok(info.application_version)
+        })
+    }
+}
+
+#[instrument(skip(fetch_page))]
+async fn fetch_all_paged_data<T>(
+    fetch_page: impl Fn(i32, i32) -> Pin<Box<dyn Future<Output = Result<Page<T>>> + Send>> + Send,
+) -> Result<Vec<T>> {
+    let mut all_items = Vec::new();
+    let mut offset = 0;
+
+    loop {
+        tracing::debug!(offset, page_size = PAGE_SIZE, "Fetching page");
+        let page = fetch_page(offset, PAGE_SIZE).await?;
+        all_items.extend(page.data);
+
+        if offset + page.count >= page.total_count {
+            break;
+        }
+        offset += PAGE_SIZE;
+    }
+
+    tracing::debug!(items_count = all_items.len(), "Completed paged data fetch");
+    Ok(all_items)
+}
+
+/// Serves fixed data. Sites listed in `failing_sites` time out, and `rate_limited`
+/// makes the site list answer with a 429.
+#[cfg(test)]
+#[derive(Default)]
+pub struct MockSource {
+    pub sites: Vec<SiteOverview>,
+    pub devices: std::collections::HashMap<Uuid, Vec<DeviceOverview>>,
+    pub clients: std::collections::HashMap<Uuid, Vec<ClientOverview>>,
+    pub details: std::collections::HashMap<Uuid, DeviceDetails>,
+    pub failing_sites: std::collections::HashSet<Uuid>,
+    pub rate_limited: bool,
+}
+
+#[cfg(test)]
+impl MockSource {
+    fn site_result<T: Clone>(
+        &self,
+        site_id: Uuid,
+        data: &std::collections::HashMap<Uuid, Vec<T>>,
+    ) -> Result<Vec<T>> {
+        if self.failing_sites.contains(&site_id) {
+            return Err(AppError::Timeout(format!("site {}", site_id)));
+        }
+        Ok(data.get(&site_id).cloned().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+impl NetworkSource for MockSource {
+    fn sites(&self) -> SourceFuture<'_, Vec<SiteOverview>> {
+        Box::pin(async move {
+            if self.rate_limited {
+                return Err(AppError::RateLimited("429 Too Many Requests".to_string()));
+            }
+            Ok(self.sites.clone())
+        })
+    }
+
+    fn devices(&self, site_id: Uuid) -> SourceFuture<'_, Vec<DeviceOverview>> {
+        Box::pin(async move { self.site_result(site_id, &self.devices) })
+    }
+
+    fn clients(&self, site_id: Uuid) -> SourceFuture<'_, Vec<ClientOverview>> {
+        Box::pin(async move { self.site_result(site_id, &self.clients) })
+    }
+
+    fn device_details(&self, _site_id: Uuid, device_id: Uuid) -> SourceFuture<'_, DeviceDetails> {
+        Box::pin(async move {
+            self.details
+                .get(&device_id)
+                .cloned()
+                .ok_or_else(|| AppError::Application(format!("no details for {}", device_id)))
+        })
+    }
+
+    fn device_statistics(
+        &self,
+        _site_id: Uuid,
+        device_id: Uuid,
+    ) -> SourceFuture<'_, DeviceStatistics> {
+        Box::pin(async move {
+            Err(AppError::Application(format!(
+                "no statistics for {}",
+                device_id
+            )))
+        })
+    }
+
+    fn application_version(&self) -> SourceFuture<'_, String> {
+        Box::pin(async { Ok("mock".to_string()) })
+    }
+}
+
+/// Controller records for building a [`MockSource`].
+#[cfg(test)]
+pub mod fixtures {
+    use super::*;
+
+    pub fn site(id: Uuid, name: &str) -> SiteOverview {
+        serde_json::from_value(serde_json::json!({
+            "id": id.to_string(),
+            "name": name,
+        }))
+        .expect("site fixture")
+    }
+
+    pub fn device(name: &str, model: &str) -> DeviceOverview {
+        serde_json::from_value(serde_json::json!({
+            "id": Uuid::new_v4().to_string(),
+            "name": name,
+            "model": model,
+            "macAddress": "aa:bb:cc:00:00:01",
+            "ipAddress": "192.168.1.1",
+            "state": "ONLINE",
+            "features": [],
+            "interfaces": [],
+        }))
+        .expect("device fixture")
+    }
+
+    /// Connected half an hour ago, so its connection time renders the same every run.
+    pub fn client(name: &str, kind: &str, uplink_device_id: Uuid) -> ClientOverview {
+        let connected_at = chrono::Utc::now() - chrono::Duration::minutes(30);
+        serde_json::from_value(serde_json::json!({
+            "type": kind,
+            "id": Uuid::new_v4().to_string(),
+            "name": name,
+            "connectedAt": connected_at.to_rfc3339(),
+            "macAddress": "aa:bb:cc:dd:ee:ff",
+            "uplinkDeviceId": uplink_device_id.to_string(),
+        }))
+        .expect("client fixture")
+    }
+
+    pub fn details(device: &DeviceOverview, uplink: Option<Uuid>) -> DeviceDetails {
+        serde_json::from_value(serde_json::json!({
+            "id": device.id.to_string(),
+            "name": device.name,
+            "model": device.model,
+            "supported": true,
+            "macAddress": device.mac_address,
+            "ipAddress": device.ip_address,
+            "state": "ONLINE",
+            "firmwareVersion": "4.0.6",
+            "firmwareUpdatable": false,
+            "adoptedAt": null,
+            "provisionedAt": null,
+            "configurationId": "default",
+            "uplink": uplink.map(|id| serde_json::json!({ "deviceId": id.to_string() })),
+        }))
+        .expect("details fixture")
+    }
+}
@@ -1,18 +1,39 @@
+use crate::aliases::ClientAliases;
+use crate::availability::Availability;
 use crate::error::{AppError, Result};
-use chrono::{DateTime, Utc};
-use std::collections::{HashMap, VecDeque};
-use std::future::Future;
-use std::pin::Pin;
+use crate::events::NetworkEvent;
+use crate::favorites::FavoriteSites;
+use crate::probe::LatencyMonitor;
+use crate::script::{client_record, device_record, Script};
+use crate::source::NetworkSource;
+use crate::toast::{ToastLevel, Toasts};
+use chrono::{DateTime, Local, Utc};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use tokio::time::timeout;
 use tracing::instrument;
-use unifi_rs::common::Page;
-use unifi_rs::device::{DeviceDetails, DeviceOverview};
+use unifi_rs::common::FrequencyBand;
+use unifi_rs::device::{DeviceDetails, DeviceOverview, DeviceState};
 use unifi_rs::models::client::ClientOverview;
 use unifi_rs::site::SiteOverview;
 use unifi_rs::statistics::DeviceStatistics;
 use unifi_rs::UnifiClient;
 use uuid::Uuid;
 
+const MAX_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+const SITE_FETCH_CONCURRENCY: usize = 4;
+const SITE_FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Clone)]
+pub struct ErrorEntry {
+    pub timestamp: DateTime<Local>,
+    pub message: String,
+}
+
 #[derive(Clone)]
 pub struct SiteContext {
     pub site_id: Uuid,
@@ -47,24 +68,84 @@ pub struct DeviceMetrics {
     pub uptime: i64,
     pub tx_rate: Option<i64>,
     pub rx_rate: Option<i64>,
+    /// Whether wireless clients were attached, making the uplink traffic RF load
+    /// rather than backbone load.
+    pub wireless: bool,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct SiteSummary {
+    pub devices: usize,
+    pub online: usize,
+    pub clients: usize,
+}
+
+impl SiteSummary {
+    pub fn offline(&self) -> usize {
+        self.devices - self.online
+    }
+}
+
+/// A client that dropped off the controller's list, kept for a while so brief
+/// disconnects are still visible in the Clients tab.
+#[derive(Clone)]
+pub struct RecentClient {
+    pub client: ClientOverview,
+    pub site_id: Option<Uuid>,
+    pub last_seen: DateTime<Utc>,
+}
+
+/// A change seen for one device, shown on its Events tab.
+pub struct DeviceEvent {
+    pub at: DateTime<Local>,
+    pub kind: &'static str,
+    pub message: String,
+}
+
+const MAX_DEVICE_EVENTS: usize = 50;
+
+pub struct ControllerInfo {
+    pub application_version: String,
+    pub fetched_at: DateTime<Local>,
 }
 
 pub struct AppState {
     pub client: UnifiClient,
+    pub source: Arc<dyn NetworkSource>,
     pub sites: Vec<SiteOverview>,
     pub selected_site: Option<SiteContext>,
     pub devices: Vec<DeviceOverview>,
     pub clients: Vec<ClientOverview>,
     pub filtered_devices: Vec<DeviceOverview>,
     pub filtered_clients: Vec<ClientOverview>,
+    pub filtered_sites: Vec<SiteOverview>,
     pub device_details: HashMap<Uuid, DeviceDetails>,
     pub device_stats: HashMap<Uuid, DeviceStatistics>,
+    pub device_sites: HashMap<Uuid, Uuid>,
+    /// Clients per uplink device, counted once per refresh for script columns.
+    pub device_client_counts: HashMap<Uuid, usize>,
     pub stats_history: VecDeque<NetworkStats>,
     pub last_update: Instant,
     pub refresh_interval: Duration,
-    pub error_message: Option<String>,
-    pub error_timestamp: Option<Instant>,
+    pub base_refresh_interval: Duration,
+    pub retry_after: Option<Instant>,
+    pub toasts: Toasts,
+    pub error_log: VecDeque<ErrorEntry>,
     pub network_history: HashMap<Uuid, VecDeque<NetworkThroughput>>,
+    pub aliases: ClientAliases,
+    pub favorites: FavoriteSites,
+    pub availability: Availability,
+    pub device_events: HashMap<Uuid, VecDeque<DeviceEvent>>,
+    pub site_errors: HashMap<Uuid, &'static str>,
+    pub recent_clients: Vec<RecentClient>,
+    pub only_2ghz_clients: bool,
+    pub device_filter: Option<Script>,
+    pub client_filter: Option<Script>,
+    pub client_retention: Duration,
+    pub controller_info: Option<ControllerInfo>,
+    /// Devices with a single-device refetch in flight, shown as refreshing.
+    pub refreshing_devices: HashSet<Uuid>,
+    pub latency: Option<LatencyMonitor>,
 }
 
 impl AppState {
@@ -72,6 +153,7 @@ impl AppState {
     pub async fn new(client: UnifiClient) -> Result<Self> {
         tracing::info!("Initializing new AppState");
         Ok(Self {
+            source: Arc::new(client.clone()),
             client,
             sites: Vec::new(),
             selected_site: None,
@@ -79,181 +161,290 @@ impl AppState {
             clients: Vec::new(),
             filtered_devices: Vec::new(),
             filtered_clients: Vec::new(),
+            filtered_sites: Vec::new(),
             device_details: HashMap::new(),
             device_stats: HashMap::new(),
+            device_sites: HashMap::new(),
+            device_client_counts: HashMap::new(),
             stats_history: VecDeque::with_capacity(100),
             last_update: Instant::now(),
             refresh_interval: Duration::from_secs(5),
-            error_message: None,
-            error_timestamp: None,
+            base_refresh_interval: Duration::from_secs(5),
+            retry_after: None,
+            toasts: Toasts::default(),
+            error_log: VecDeque::with_capacity(100),
             network_history: HashMap::new(),
+            aliases: ClientAliases::open(),
+            favorites: FavoriteSites::open(),
+            availability: Availability::open(),
+            device_events: HashMap::new(),
+            site_errors: HashMap::new(),
+            recent_clients: Vec::new(),
+            only_2ghz_clients: false,
+            device_filter: None,
+            client_filter: None,
+            client_retention: Duration::from_secs(30 * 60),
+            controller_info: None,
+            refreshing_devices: HashSet::new(),
+            latency: None,
         })
     }
 
-    pub async fn refresh_data(&mut self) -> Result<()> {
+    pub async fn refresh_data(&mut self) -> Result<bool> {
+        if !self.start_refresh() {
+            return Ok(false);
+        }
+        let snapshot = fetch_snapshot(self.source.clone(), self.site_id(), DetailFetch::All).await;
+        self.finish_refresh(snapshot)?;
+        Ok(true)
+    }
+
+    /// Returns true when a refresh is due: the interval has passed and no back-off
+    /// is holding it off.
+    pub fn start_refresh(&mut self) -> bool {
+        if self.retry_after.is_some_and(|at| Instant::now() < at) {
+            return false;
+        }
         if self.last_update.elapsed() < self.refresh_interval {
-            return Ok(());
+            return false;
         }
+        self.retry_after = None;
+        true
+    }
 
+    /// Applies the result of a refresh started with [`AppState::start_refresh`].
+    pub fn finish_refresh(&mut self, snapshot: Result<Snapshot>) -> Result<()> {
+        self.apply_snapshot(snapshot)?;
+        self.recover_refresh_interval();
+        Ok(())
+    }
+
+    /// The site context data is fetched for, `None` for all sites.
+    pub fn site_id(&self) -> Option<Uuid> {
+        self.selected_site.as_ref().map(|s| s.site_id)
+    }
+
+    /// Fetches everything immediately, ignoring the refresh interval.
+    pub async fn load(&mut self) -> Result<()> {
         tracing::debug!("Starting data refresh");
+        let snapshot = fetch_snapshot(self.source.clone(), self.site_id(), DetailFetch::All).await;
+        self.apply_snapshot(snapshot)
+    }
 
-        if let Err(e) = self.fetch_sites_and_data().await {
+    fn apply_snapshot(&mut self, snapshot: Result<Snapshot>) -> Result<()> {
+        let previous_clients = self.clients.clone();
+        let applied = snapshot.and_then(|snapshot| self.apply_sites_and_data(snapshot));
+        self.count_device_clients();
+        if let Err(e) = applied {
             tracing::error!(error = %e, "Failed to refresh data");
-            self.set_error(format!("Error refreshing data: {}", e));
             return Err(e);
         }
 
+        self.track_recent_clients(previous_clients);
+        // Allow a few missed refreshes before a gap stops counting towards availability
+        let max_gap = chrono::Duration::from_std(self.refresh_interval * 3)
+            .unwrap_or_else(|_| chrono::Duration::zero())
+            .max(chrono::Duration::minutes(2));
+        if let Err(e) = self.availability.record(&self.devices, max_gap) {
+            tracing::warn!(error = %e, "Failed to save device availability");
+        }
         self.update_stats();
         self.apply_filters();
         self.last_update = Instant::now();
         Ok(())
     }
 
-    #[instrument(skip(self), fields(site_id = ?self.selected_site.as_ref().map(|s| s.site_id)))]
-    async fn fetch_sites_and_data(&mut self) -> Result<()> {
-        let sites = self
-            .fetch_all_paged_data(
-                |offset, limit| {
-                    let client = self.client.clone();
-                    Box::pin(async move {
-                        client
-                            .list_sites(Some(offset), Some(limit))
-                            .await
-                            .map_err(AppError::UniFi)
-                    })
-                },
-                25,
-            )
-            .await?;
-
-        self.sites = sites;
-
-        match &self.selected_site {
-            Some(site) => {
-                tracing::debug!(site_id = ?site.site_id, "Fetching site data");
-                self.fetch_site_data(site.site_id).await?;
-            }
-            None => {
-                self.fetch_all_sites_data().await?;
-            }
+    pub fn run_probes(&mut self) {
+        if let Some(monitor) = self.latency.as_mut() {
+            monitor.drain();
+            monitor.tick(self.devices.iter().map(|d| (d.id, d.ip_address.clone())));
         }
-
-        Ok(())
     }
 
-    async fn fetch_site_data(&mut self, site_id: Uuid) -> Result<()> {
-        let (devices, clients) = tokio::join!(
-            self.fetch_all_paged_data(
-                |offset, limit| {
-                    let client = self.client.clone();
-                    Box::pin(async move {
-                        client
-                            .list_devices(site_id, Some(offset), Some(limit))
-                            .await
-                            .map_err(AppError::UniFi)
-                    })
-                },
-                25,
-            ),
-            self.fetch_all_paged_data(
-                |offset, limit| {
-                    let client = self.client.clone();
-                    Box::pin(async move {
-                        client
-                            .list_clients(site_id, Some(offset), Some(limit))
-                            .await
-                            .map_err(AppError::UniFi)
-                    })
-                },
-                25,
-            )
-        );
+    /// Stores a device refetched outside the refresh cycle by [`fetch_device`].
+    pub fn apply_device(&mut self, details: DeviceDetails, stats: DeviceStatistics) {
+        let device_id = details.id;
+        self.device_details.insert(device_id, details);
+        self.update_network_history(device_id, &stats);
+        self.device_stats.insert(device_id, stats);
+        tracing::debug!(device_id = ?device_id, "Refreshed single device");
+    }
 
-        let (devices, clients) = (devices?, clients?);
+    pub fn set_controller_info(&mut self, application_version: String) {
+        tracing::info!(version = %application_version, "Fetched controller info");
+        self.controller_info = Some(ControllerInfo {
+            application_version,
+            fetched_at: Local::now(),
+        });
+    }
 
-        let mut device_data_futures = Vec::new();
-        for device in &devices {
-            let client = self.client.clone();
-            let device_id = device.id;
-            device_data_futures.push(async move {
-                let details = client.get_device_details(site_id, device_id).await;
-                let stats = client.get_device_statistics(site_id, device_id).await;
-                (device_id, details, stats)
-            });
-        }
-        
-        for fut in device_data_futures {
-            let (device_id, details, stats) = fut.await;
-            if let Ok(details) = details {
-                self.device_details.insert(device_id, details);
-            }
-            if let Ok(stats) = stats {
-                self.device_stats.insert(device_id, stats.clone());
-                self.update_network_history(device_id, &stats);
-            }
-        }
+    /// Doubles the refresh interval (up to 30s) after the controller answered
+    /// with a 429 and holds off the next attempt for that long.
+    pub fn back_off(&mut self) -> Duration {
+        self.refresh_interval = (self.refresh_interval * 2).min(MAX_REFRESH_INTERVAL);
+        self.retry_after = Some(Instant::now() + self.refresh_interval);
+        tracing::warn!(interval = ?self.refresh_interval, "Rate limited, backing off");
+        self.refresh_interval
+    }
 
-        if self.selected_site.as_ref().map(|s| s.site_id) == Some(site_id) {
-            self.devices = devices;
-            self.clients = clients;
+    /// Changes the normal refresh interval; a back-off in progress keeps stepping
+    /// down towards the new one.
+    pub fn set_base_refresh_interval(&mut self, interval: Duration) {
+        self.refresh_interval = if self.is_backing_off() {
+            self.refresh_interval.max(interval)
         } else {
-            self.devices.extend(devices);
-            self.clients.extend(clients);
-        }
+            interval
+        };
+        self.base_refresh_interval = interval;
+    }
 
-        Ok(())
+    pub fn is_backing_off(&self) -> bool {
+        self.refresh_interval > self.base_refresh_interval
     }
 
-    #[instrument(skip(self, fetch_page))]
-    async fn fetch_all_paged_data<T>(
-        &self,
-        fetch_page: impl Fn(i32, i32) -> Pin<Box<dyn Future<Output = Result<Page<T>>> + Send>> + Send,
-        page_size: i32,
-    ) -> Result<Vec<T>> {
-        let mut all_items = Vec::new();
-        let mut offset = 0;
+    // Step back down gradually so a controller that only just recovered is not
+    // immediately hit at the full rate again.
+    fn recover_refresh_interval(&mut self) {
+        if self.is_backing_off() {
+            self.refresh_interval = (self.refresh_interval / 2).max(self.base_refresh_interval);
+        }
+    }
 
-        loop {
-            tracing::debug!(offset, page_size, "Fetching page");
-            let page = fetch_page(offset, page_size).await?;
-            all_items.extend(page.data);
+    fn apply_sites_and_data(&mut self, snapshot: Snapshot) -> Result<()> {
+        self.sites = snapshot.sites;
+        self.sort_sites();
+        let mut results = snapshot.results;
 
-            if offset + page.count >= page.total_count {
-                break;
-            }
-            offset += page_size;
+        if let Some(site_id) = snapshot.site_id {
+            tracing::debug!(site_id = ?site_id, "Applying site data");
+            let result = results
+                .remove(&site_id)
+                .unwrap_or_else(|| Err(AppError::Application("site was not fetched".into())));
+            self.record_site_result(site_id, result.as_ref().err());
+            self.apply_site_data(site_id, result?);
+            return Ok(());
         }
 
-        tracing::debug!(items_count = all_items.len(), "Completed paged data fetch");
-        Ok(all_items)
-    }
-
-    #[instrument(skip(self))]
-    async fn fetch_all_sites_data(&mut self) -> Result<()> {
+        let site_ids: Vec<Uuid> = self.sites.iter().map(|s| s.id).collect();
         self.devices.clear();
         self.clients.clear();
         self.device_details.clear();
         self.device_stats.clear();
+        self.device_sites.clear();
+        self.site_errors.retain(|id, _| site_ids.contains(id));
 
-        let site_ids: Vec<Uuid> = self.sites.iter().map(|s| s.id).collect();
-
+        let mut rate_limited = None;
         for site_id in site_ids {
-            match self.fetch_site_data(site_id).await {
-                Ok(_) => {
+            let Some(result) = results.remove(&site_id) else {
+                continue;
+            };
+            self.record_site_result(site_id, result.as_ref().err());
+            match result {
+                Ok(data) => {
                     tracing::debug!(site_id = ?site_id, "Successfully fetched site data");
+                    self.apply_site_data(site_id, data);
                 }
+                // Backing off is global, so the caller still has to see the 429.
+                Err(e @ AppError::RateLimited(_)) => rate_limited = Some(e),
                 Err(e) => {
                     tracing::error!(
                         site_id = ?site_id,
                         error = %e,
                         "Failed to fetch site data"
                     );
-                    self.set_error(format!("Error fetching data for site {}: {}", site_id, e));
+                    self.log_error(format!("Error fetching data for site {}: {}", site_id, e));
                 }
             }
         }
 
-        Ok(())
+        match rate_limited {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    fn apply_site_data(&mut self, site_id: Uuid, data: SiteData) {
+        for device in &data.devices {
+            self.device_sites.insert(device.id, site_id);
+        }
+        for (device_id, details) in data.details {
+            self.device_details.insert(device_id, details);
+        }
+        for (device_id, stats) in data.stats {
+            self.update_network_history(device_id, &stats);
+            self.device_stats.insert(device_id, stats);
+        }
+
+        if self.selected_site.as_ref().map(|s| s.site_id) == Some(site_id) {
+            self.devices = data.devices;
+            self.clients = data.clients;
+        } else {
+            self.devices.extend(data.devices);
+            self.clients.extend(data.clients);
+        }
+    }
+
+    fn count_device_clients(&mut self) {
+        self.device_client_counts.clear();
+        for client in &self.clients {
+            let uplink = match client {
+                ClientOverview::Wired(c) => c.uplink_device_id,
+                ClientOverview::Wireless(c) => c.uplink_device_id,
+                _ => continue,
+            };
+            *self.device_client_counts.entry(uplink).or_default() += 1;
+        }
+    }
+
+    fn record_site_result(&mut self, site_id: Uuid, error: Option<&AppError>) {
+        match error {
+            Some(e) => {
+                self.site_errors.insert(site_id, e.label());
+            }
+            None => {
+                self.site_errors.remove(&site_id);
+            }
+        }
+    }
+
+    fn track_recent_clients(&mut self, previous: Vec<ClientOverview>) {
+        let now = Utc::now();
+        let current: HashSet<Uuid> = self.clients.iter().filter_map(client_key).collect();
+        self.recent_clients
+            .retain(|recent| client_key(&recent.client).is_some_and(|id| !current.contains(&id)));
+
+        for client in previous {
+            let (id, uplink) = match &client {
+                ClientOverview::Wired(c) => (c.base.id, c.uplink_device_id),
+                ClientOverview::Wireless(c) => (c.base.id, c.uplink_device_id),
+                _ => continue,
+            };
+            if current.contains(&id)
+                || self
+                    .recent_clients
+                    .iter()
+                    .any(|recent| client_key(&recent.client) == Some(id))
+            {
+                continue;
+            }
+            // Clients of a site that failed to load or left the site context have not
+            // disconnected, their uplink device is just missing along with them.
+            if !self.devices.iter().any(|d| d.id == uplink) {
+                continue;
+            }
+            self.recent_clients.push(RecentClient {
+                site_id: self.device_sites.get(&uplink).copied(),
+                client,
+                last_seen: now,
+            });
+        }
+
+        let retention =
+            chrono::Duration::from_std(self.client_retention).unwrap_or(chrono::Duration::zero());
+        self.recent_clients
+            .retain(|recent| now.signed_duration_since(recent.last_seen) < retention);
+        self.recent_clients
+            .sort_by_key(|recent| std::cmp::Reverse(recent.last_seen));
     }
 
     #[instrument(skip(self, stats))]
@@ -286,9 +477,24 @@ impl AppState {
 
     #[instrument(skip(self))]
     pub fn set_error(&mut self, message: String) {
+        self.log_error(message.clone());
+        self.toasts.push(ToastLevel::Error, message);
+    }
+
+    /// Records an error in the console without raising a toast.
+    pub fn log_error(&mut self, message: String) {
         tracing::error!(error = %message);
-        self.error_message = Some(message);
-        self.error_timestamp = Some(Instant::now());
+        if self.error_log.len() >= 100 {
+            self.error_log.pop_front();
+        }
+        self.error_log.push_back(ErrorEntry {
+            timestamp: Local::now(),
+            message,
+        });
+    }
+
+    pub fn notify(&mut self, level: ToastLevel, message: impl Into<String>) {
+        self.toasts.push(level, message);
     }
 
     #[instrument(skip(self))]
@@ -333,6 +539,15 @@ impl AppState {
 
     #[instrument(skip(self))]
     fn collect_device_metrics(&self) -> Vec<DeviceMetrics> {
+        let access_points: HashSet<Uuid> = self
+            .clients
+            .iter()
+            .filter_map(|client| match client {
+                ClientOverview::Wireless(c) => Some(c.uplink_device_id),
+                _ => None,
+            })
+            .collect();
+
         let metrics: Vec<DeviceMetrics> = self
             .devices
             .iter()
@@ -346,6 +561,7 @@ impl AppState {
                     uptime: stats.uptime_sec,
                     tx_rate: stats.uplink.as_ref().map(|u| u.tx_rate_bps),
                     rx_rate: stats.uplink.as_ref().map(|u| u.rx_rate_bps),
+                    wireless: access_points.contains(&device.id),
                 })
             })
             .collect();
@@ -354,10 +570,128 @@ impl AppState {
         metrics
     }
 
+    /// Devices from `device_id` up to the root of the site, following each device's uplink.
+    pub fn uplink_chain(&self, device_id: Uuid) -> Vec<&DeviceOverview> {
+        let mut chain: Vec<&DeviceOverview> = Vec::new();
+        let mut next = Some(device_id);
+        while let Some(id) = next {
+            // A misreported uplink could point back down the chain.
+            if chain.iter().any(|d| d.id == id) {
+                break;
+            }
+            let Some(device) = self.devices.iter().find(|d| d.id == id) else {
+                break;
+            };
+            chain.push(device);
+            next = self
+                .device_details
+                .get(&id)
+                .and_then(|details| details.uplink.as_ref())
+                .map(|uplink| uplink.device_id);
+        }
+        chain
+    }
+
+    /// Files device-related events under their device, keeping the latest
+    /// `MAX_DEVICE_EVENTS` for each.
+    pub fn record_device_events(&mut self, events: &[NetworkEvent]) {
+        let now = Local::now();
+        for event in events {
+            let Some(device_id) = event.device_id() else {
+                continue;
+            };
+            let log = self.device_events.entry(device_id).or_default();
+            if log.len() >= MAX_DEVICE_EVENTS {
+                log.pop_front();
+            }
+            log.push_back(DeviceEvent {
+                at: now,
+                kind: event.name(),
+                message: event.describe(),
+            });
+        }
+    }
+
+    // Devices without an uplink sit at the root of the site, which is where the gateway lives.
+    pub fn is_gateway(&self, device_id: Uuid) -> bool {
+        self.device_details
+            .get(&device_id)
+            .is_some_and(|details| details.uplink.is_none())
+    }
+
+    /// Moves favorite sites to the top in the order they were pinned; the rest
+    /// keep the controller's order.
+    pub fn sort_sites(&mut self) {
+        let favorites = &self.favorites;
+        self.sites
+            .sort_by_key(|site| favorites.position(site.id).unwrap_or(usize::MAX));
+    }
+
+    /// Device and client counts per site, for the sites whose data is currently
+    /// loaded (every site when none is selected, otherwise only the selected one).
+    pub fn site_summaries(&self) -> HashMap<Uuid, SiteSummary> {
+        let mut summaries: HashMap<Uuid, SiteSummary> = self
+            .sites
+            .iter()
+            .filter(|site| {
+                self.selected_site
+                    .as_ref()
+                    .is_none_or(|selected| selected.site_id == site.id)
+            })
+            .map(|site| (site.id, SiteSummary::default()))
+            .collect();
+
+        for device in &self.devices {
+            let Some(summary) = self
+                .device_sites
+                .get(&device.id)
+                .and_then(|site_id| summaries.get_mut(site_id))
+            else {
+                continue;
+            };
+            summary.devices += 1;
+            if matches!(device.state, DeviceState::Online) {
+                summary.online += 1;
+            }
+        }
+
+        // Clients carry no site ID, so they are counted at the site of their uplink.
+        for client in &self.clients {
+            let uplink = match client {
+                ClientOverview::Wired(c) => c.uplink_device_id,
+                ClientOverview::Wireless(c) => c.uplink_device_id,
+                _ => continue,
+            };
+            if let Some(summary) = self
+                .device_sites
+                .get(&uplink)
+                .and_then(|site_id| summaries.get_mut(site_id))
+            {
+                summary.clients += 1;
+            }
+        }
+
+        summaries
+    }
+
+    pub fn pending_firmware_updates(&self) -> Vec<&DeviceOverview> {
+        self.devices
+            .iter()
+            .filter(|d| {
+                self.device_details
+                    .get(&d.id)
+                    .is_some_and(|details| details.firmware_updatable)
+            })
+            .collect()
+    }
+
     #[instrument(skip(self))]
     pub fn apply_filters(&mut self) {
         self.filtered_devices = self.devices.clone();
         self.filtered_clients = self.clients.clone();
+        self.filtered_sites = self.sites.clone();
+        self.filter_client_band();
+        self.filter_scripted();
 
         tracing::debug!(
             device_count = self.filtered_devices.len(),
@@ -366,6 +700,50 @@ impl AppState {
         );
     }
 
+    /// Band and channel of the radio a wireless client is on. The Integration API does not
+    /// report them per client, so they are only known when its AP has a single radio.
+    pub fn client_band(&self, client: &ClientOverview) -> Option<(&FrequencyBand, Option<String>)> {
+        let ClientOverview::Wireless(c) = client else {
+            return None;
+        };
+        let details = self.device_details.get(&c.uplink_device_id)?;
+        let [radio] = details.interfaces.as_ref()?.radios.as_slice() else {
+            return None;
+        };
+        Some((
+            radio.frequency_ghz.as_ref()?,
+            radio.channel.map(|c| c.to_string()),
+        ))
+    }
+
+    fn filter_client_band(&mut self) {
+        if !self.only_2ghz_clients {
+            return;
+        }
+        let clients = std::mem::take(&mut self.filtered_clients);
+        self.filtered_clients = clients
+            .into_iter()
+            .filter(|c| matches!(self.client_band(c), Some((FrequencyBand::Band2_4GHz, _))))
+            .collect();
+    }
+
+    fn filter_scripted(&mut self) {
+        if let Some(filter) = self.device_filter.clone() {
+            let devices = std::mem::take(&mut self.filtered_devices);
+            self.filtered_devices = devices
+                .into_iter()
+                .filter(|d| filter.expr.matches(&device_record(self, d)))
+                .collect();
+        }
+        if let Some(filter) = self.client_filter.clone() {
+            let clients = std::mem::take(&mut self.filtered_clients);
+            self.filtered_clients = clients
+                .into_iter()
+                .filter(|c| filter.expr.matches(&client_record(self, c)))
+                .collect();
+        }
+    }
+
     #[instrument(skip(self))]
     pub fn set_site_context(&mut self, site_id: Option<Uuid>) {
         let previous_site = self.selected_site.as_ref().map(|s| s.site_id);
@@ -396,6 +774,8 @@ impl AppState {
         self.clients.clear();
         self.device_details.clear();
         self.device_stats.clear();
+        self.device_sites.clear();
+        self.device_client_counts.clear();
         self.last_update = Instant::now() - self.refresh_interval;
     }
 
@@ -406,9 +786,24 @@ impl AppState {
         if query.is_empty() {
             self.filtered_devices = self.devices.clone();
             self.filtered_clients = self.clients.clone();
+            self.filtered_sites = self.sites.clone();
+            self.filter_client_band();
+            self.filter_scripted();
             return;
         }
 
+        self.filtered_sites = self
+            .sites
+            .iter()
+            .filter(|s| {
+                s.name
+                    .as_deref()
+                    .is_some_and(|name| name.to_lowercase().contains(&query))
+                    || s.id.to_string().contains(&query)
+            })
+            .cloned()
+            .collect();
+
         self.filtered_devices = self
             .devices
             .iter()
@@ -432,6 +827,7 @@ impl AppState {
             .filter(|c| match c {
                 ClientOverview::Wired(wc) => [
                     wc.base.name.as_deref().unwrap_or(""),
+                    self.aliases.get(&wc.mac_address).unwrap_or(""),
                     wc.base.ip_address.as_deref().unwrap_or(""),
                     &wc.mac_address,
                     &wc.uplink_device_id.to_string(),
@@ -440,6 +836,7 @@ impl AppState {
                 .any(|field| field.to_lowercase().contains(&query)),
                 ClientOverview::Wireless(wc) => [
                     wc.base.name.as_deref().unwrap_or(""),
+                    self.aliases.get(&wc.mac_address).unwrap_or(""),
                     wc.base.ip_address.as_deref().unwrap_or(""),
                     &wc.mac_address,
                     &wc.uplink_device_id.to_string(),
@@ -451,10 +848,448 @@ impl AppState {
             .cloned()
             .collect();
 
+        self.filter_client_band();
+        self.filter_scripted();
+
         tracing::trace!(
             query = %query,
-            matches = self.filtered_devices.len() + self.filtered_clients.len(),
+            matches = self.filtered_devices.len()
+                + self.filtered_clients.len()
+                + self.filtered_sites.len(),
             "Search executed"
         );
     }
 }
+
+pub fn frequency_label(band: &FrequencyBand) -> &'static str {
+    match band {
+        FrequencyBand::Band2_4GHz => "2.4 GHz",
+        FrequencyBand::Band5GHz => "5 GHz",
+        FrequencyBand::Band6GHz => "6 GHz",
+        FrequencyBand::Band60GHz => "60 GHz",
+    }
+}
+
+fn client_key(client: &ClientOverview) -> Option<Uuid> {
+    match client {
+        ClientOverview::Wired(c) => Some(c.base.id),
+        ClientOverview::Wireless(c) => Some(c.base.id),
+        _ => None,
+    }
+}
+
+/// Everything one refresh fetched, before it is applied. Fetching needs no access to
+/// the state, so it can run on a background task while the UI keeps drawing.
+pub struct Snapshot {
+    site_id: Option<Uuid>,
+    sites: Vec<SiteOverview>,
+    results: HashMap<Uuid, Result<SiteData>>,
+}
+
+/// Which devices a refresh fetches details and statistics for, one request each.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DetailFetch {
+    All,
+    /// Only the device whose detail view is open, if any, to save requests.
+    Open(Option<Uuid>),
+}
+
+impl DetailFetch {
+    // Gateways are always fetched: telling them apart needs their uplink, and the WAN
+    // throughput shown everywhere comes from their statistics.
+    fn wants(self, device: &DeviceOverview) -> bool {
+        match self {
+            DetailFetch::All => true,
+            DetailFetch::Open(open) => {
+                open == Some(device.id) || is_gateway_model(device) || has_gateway_feature(device)
+            }
+        }
+    }
+}
+
+// Model prefixes of the Dream Machine, Dream Router, Cloud Gateway, Express and
+// Security Gateway lines.
+const GATEWAY_MODEL_PREFIXES: &[&str] = &["UDM", "UDR", "UDW", "UCG", "UXG", "USG", "UX"];
+
+pub fn is_gateway_model(device: &DeviceOverview) -> bool {
+    let model = device.model.to_uppercase();
+    GATEWAY_MODEL_PREFIXES
+        .iter()
+        .any(|prefix| model.starts_with(prefix))
+}
+
+pub fn has_gateway_feature(device: &DeviceOverview) -> bool {
+    device
+        .features
+        .iter()
+        .any(|f| f == "gateway" || f == "routing")
+}
+
+/// Fetches the site list, then the data of `site_id` or, without one, of every site.
+#[instrument(skip(source))]
+pub async fn fetch_snapshot(
+    source: Arc<dyn NetworkSource>,
+    site_id: Option<Uuid>,
+    details: DetailFetch,
+) -> Result<Snapshot> {
+    let sites = source.sites().await?;
+    let results = match site_id {
+        Some(id) => {
+            tracing::debug!(site_id = ?id, "Fetching site data");
+            HashMap::from([(id, fetch_site(source, id, details).await)])
+        }
+        None => fetch_all_sites(source, sites.iter().map(|s| s.id).collect(), details).await,
+    };
+    Ok(Snapshot {
+        site_id,
+        sites,
+        results,
+    })
+}
+
+async fn fetch_all_sites(
+    source: Arc<dyn NetworkSource>,
+    site_ids: Vec<Uuid>,
+    details: DetailFetch,
+) -> HashMap<Uuid, Result<SiteData>> {
+    // Sites are fetched side by side so one slow or broken controller site can't hold up
+    // the rest; the semaphore keeps large deployments from opening dozens of requests.
+    let permits = Arc::new(Semaphore::new(SITE_FETCH_CONCURRENCY));
+    let mut tasks = JoinSet::new();
+    for site_id in site_ids {
+        let source = source.clone();
+        let permits = permits.clone();
+        tasks.spawn(async move {
+            let _permit = permits.acquire_owned().await;
+            let fetch = fetch_site(source, site_id, details);
+            let result = match timeout(SITE_FETCH_TIMEOUT, fetch).await {
+                Ok(result) => result,
+                Err(_) => Err(AppError::Timeout(format!(
+                    "site data took longer than {}s",
+                    SITE_FETCH_TIMEOUT.as_secs()
+                ))),
+            };
+            (site_id, result)
+        });
+    }
+
+    let mut results = HashMap::new();
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok((site_id, result)) => {
+                results.insert(site_id, result);
+            }
+            Err(e) => tracing::error!(error = %e, "Site fetch task failed"),
+        }
+    }
+    results
+}
+
+struct SiteData {
+    devices: Vec<DeviceOverview>,
+    clients: Vec<ClientOverview>,
+    details: Vec<(Uuid, DeviceDetails)>,
+    stats: Vec<(Uuid, DeviceStatistics)>,
+}
+
+/// Refetches one device's details and statistics outside the refresh cycle. Runs as
+/// a task, so the result is handed back to [`AppState::apply_device`] on the UI loop.
+pub async fn fetch_device(
+    source: Arc<dyn NetworkSource>,
+    site_id: Uuid,
+    device_id: Uuid,
+) -> Result<(DeviceDetails, DeviceStatistics)> {
+    let fetch = async {
+        let (details, stats) = tokio::join!(
+            source.device_details(site_id, device_id),
+            source.device_statistics(site_id, device_id)
+        );
+        Ok((details?, stats?))
+    };
+    match timeout(REQUEST_TIMEOUT, fetch).await {
+        Ok(result) => result,
+        Err(_) => Err(AppError::Timeout(format!(
+            "device refresh took longer than {}s",
+            REQUEST_TIMEOUT.as_secs()
+        ))),
+    }
+}
+
+pub async fn fetch_application_version(source: Arc<dyn NetworkSource>) -> Result<String> {
+    match timeout(REQUEST_TIMEOUT, source.application_version()).await {
+        Ok(result) => result,
+        Err(_) => Err(AppError::Timeout(format!(
+            "controller info took longer than {}s",
+            REQUEST_TIMEOUT.as_secs()
+        ))),
+    }
+}
+
+async fn fetch_site(
+    source: Arc<dyn NetworkSource>,
+    site_id: Uuid,
+    wanted: DetailFetch,
+) -> Result<SiteData> {
+    let (devices, clients) = tokio::join!(source.devices(site_id), source.clients(site_id));
+    let (devices, clients) = (devices?, clients?);
+
+    let mut details = Vec::new();
+    let mut stats = Vec::new();
+    for device in devices.iter().filter(|d| wanted.wants(d)) {
+        if let Ok(d) = source.device_details(site_id, device.id).await {
+            details.push((device.id, d));
+        }
+        if let Ok(s) = source.device_statistics(site_id, device.id).await {
+            stats.push((device.id, s));
+        }
+    }
+
+    Ok(SiteData {
+        devices,
+        clients,
+        details,
+        stats,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorTreatment;
+    use crate::events::ChangeTracker;
+    use crate::files;
+    use crate::script::Expr;
+    use crate::source::fixtures::{client, device, site};
+    use crate::source::MockSource;
+    use unifi_rs::UnifiClientBuilder;
+
+    async fn state_with(source: MockSource) -> AppState {
+        files::disable();
+        let client = UnifiClientBuilder::new("https://unifi.invalid".to_string())
+            .api_key("test")
+            .build()
+            .expect("client");
+        let mut state = AppState::new(client).await.expect("state");
+        state.source = Arc::new(source);
+        state
+    }
+
+    #[tokio::test]
+    async fn load_fetches_every_site() {
+        let (home, office) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut state = state_with(MockSource {
+            sites: vec![site(home, "Home"), site(office, "Office")],
+            ..Default::default()
+        })
+        .await;
+
+        state.load().await.expect("load");
+
+        assert_eq!(state.sites.len(), 2);
+        assert_eq!(state.filtered_sites.len(), 2);
+        assert!(state.site_errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn counts_clients_per_uplink_device() {
+        let (home, ap, switch) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut state = state_with(MockSource {
+            sites: vec![site(home, "Home")],
+            clients: HashMap::from([(
+                home,
+                vec![
+                    client("Phone", "WIRELESS", ap),
+                    client("Laptop", "WIRELESS", ap),
+                    client("Desktop", "WIRED", switch),
+                ],
+            )]),
+            ..Default::default()
+        })
+        .await;
+
+        state.load().await.expect("load");
+
+        assert_eq!(state.device_client_counts.get(&ap), Some(&2));
+        assert_eq!(state.device_client_counts.get(&switch), Some(&1));
+    }
+
+    #[tokio::test]
+    async fn failing_site_is_recorded_without_failing_the_refresh() {
+        let (home, office) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut state = state_with(MockSource {
+            sites: vec![site(home, "Home"), site(office, "Office")],
+            failing_sites: HashSet::from([office]),
+            ..Default::default()
+        })
+        .await;
+
+        state.load().await.expect("load");
+
+        assert_eq!(state.site_errors.len(), 1);
+        assert!(state.site_errors.contains_key(&office));
+    }
+
+    #[tokio::test]
+    async fn failing_site_does_not_report_its_devices_and_clients_as_changed() {
+        let (home, office) = (Uuid::new_v4(), Uuid::new_v4());
+        let switch = device("Office Switch", "USW-24-PoE");
+        let printer = client("Printer", "WIRED", switch.id);
+        let source = |failing: &[Uuid]| MockSource {
+            sites: vec![site(home, "Home"), site(office, "Office")],
+            devices: HashMap::from([(office, vec![switch.clone()])]),
+            clients: HashMap::from([(office, vec![printer.clone()])]),
+            failing_sites: failing.iter().copied().collect(),
+            ..Default::default()
+        };
+        let mut state = state_with(source(&[])).await;
+        let mut changes = ChangeTracker::new();
+        state.load().await.expect("load");
+        assert!(changes.diff(&state).is_empty());
+
+        state.source = Arc::new(source(&[office]));
+        state.load().await.expect("load with a failing site");
+        assert!(state.site_errors.contains_key(&office));
+        assert!(changes.diff(&state).is_empty());
+
+        state.source = Arc::new(source(&[]));
+        state.load().await.expect("load after recovery");
+        assert!(state.site_errors.is_empty());
+        assert!(changes.diff(&state).is_empty());
+    }
+
+    #[tokio::test]
+    async fn rate_limit_backs_off() {
+        let mut state = state_with(MockSource {
+            rate_limited: true,
+            ..Default::default()
+        })
+        .await;
+
+        let error = state.load().await.expect_err("rate limited");
+        assert_eq!(error.treatment(), ErrorTreatment::Backoff);
+
+        let interval = state.back_off();
+        assert_eq!(interval, Duration::from_secs(10));
+        assert!(state.is_backing_off());
+    }
+
+    #[tokio::test]
+    async fn switching_site_selects_it_and_refreshes_immediately() {
+        let (home, office) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut state = state_with(MockSource {
+            sites: vec![site(home, "Home"), site(office, "Office")],
+            ..Default::default()
+        })
+        .await;
+        state.load().await.expect("load");
+
+        state.set_site_context(Some(office));
+        assert_eq!(
+            state.selected_site.as_ref().map(|s| s.site_name.as_str()),
+            Some("Office")
+        );
+        assert!(state.last_update.elapsed() >= state.refresh_interval);
+
+        assert!(state.refresh_data().await.expect("refresh"));
+        assert!(state.site_errors.is_empty());
+
+        state.set_site_context(None);
+        assert!(state.selected_site.is_none());
+    }
+
+    #[tokio::test]
+    async fn search_filters_sites_by_name() {
+        let (home, office) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut state = state_with(MockSource {
+            sites: vec![site(home, "Home"), site(office, "Office")],
+            ..Default::default()
+        })
+        .await;
+        state.load().await.expect("load");
+
+        state.search("off");
+        assert_eq!(state.filtered_sites.len(), 1);
+        assert_eq!(state.filtered_sites[0].id, office);
+
+        state.apply_filters();
+        assert_eq!(state.filtered_sites.len(), 2);
+    }
+
+    fn home_network() -> (Uuid, MockSource) {
+        let home = Uuid::new_v4();
+        let switch = device("Office Switch", "USW-24-PoE");
+        let ap = device("Hallway AP", "U6-Pro");
+        let source = MockSource {
+            sites: vec![site(home, "Home")],
+            clients: HashMap::from([(
+                home,
+                vec![
+                    client("Laptop", "WIRELESS", ap.id),
+                    client("Printer", "WIRED", switch.id),
+                ],
+            )]),
+            devices: HashMap::from([(home, vec![switch, ap])]),
+            ..Default::default()
+        };
+        (home, source)
+    }
+
+    fn device_names(state: &AppState) -> Vec<&str> {
+        state
+            .filtered_devices
+            .iter()
+            .map(|d| d.name.as_str())
+            .collect()
+    }
+
+    fn client_names(state: &AppState) -> Vec<String> {
+        state
+            .filtered_clients
+            .iter()
+            .map(|c| client_record(state, c)["name"].to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn search_filters_devices_and_clients() {
+        let (home, source) = home_network();
+        let mut state = state_with(source).await;
+        state.load().await.expect("load");
+        state.set_site_context(Some(home));
+        state.load().await.expect("load site");
+
+        state.search("switch");
+        assert_eq!(device_names(&state), ["Office Switch"]);
+        assert!(state.filtered_clients.is_empty());
+
+        state.search("LAPTOP");
+        assert!(state.filtered_devices.is_empty());
+        assert_eq!(client_names(&state), ["Laptop"]);
+
+        state.search("");
+        assert_eq!(state.filtered_devices.len(), 2);
+        assert_eq!(state.filtered_clients.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn scripted_filters_narrow_devices_and_clients() {
+        let (_, source) = home_network();
+        let mut state = state_with(source).await;
+        state.load().await.expect("load");
+
+        state.device_filter = Some(Script {
+            name: "wifi".to_string(),
+            expr: Arc::new(Expr::parse(r#"model contains "u6""#).expect("device filter")),
+        });
+        state.client_filter = Some(Script {
+            name: "wired".to_string(),
+            expr: Arc::new(Expr::parse(r#"type == "wired""#).expect("client filter")),
+        });
+        state.apply_filters();
+        assert_eq!(device_names(&state), ["Hallway AP"]);
+        assert_eq!(client_names(&state), ["Printer"]);
+
+        state.search("laptop");
+        assert!(state.filtered_clients.is_empty());
+    }
+}
@@ -0,0 +1,62 @@
+use crate::files;
+use crate::state::AppState;
+use crate::ui::widgets::format_network_speed;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+use unifi_rs::device::DeviceState;
+
+/// One-line summary for tmux/polybar, e.g. "12/13 up · 87 clients · ↓214.0 Mbps".
+/// Throughput is the gateways' WAN download rate.
+pub fn render(state: &AppState) -> String {
+    let online = state
+        .devices
+        .iter()
+        .filter(|d| matches!(d.state, DeviceState::Online))
+        .count();
+    let download: i64 = state
+        .devices
+        .iter()
+        .filter(|d| state.is_gateway(d.id))
+        .filter_map(|d| state.device_stats.get(&d.id)?.uplink.as_ref())
+        .map(|u| u.rx_rate_bps)
+        .sum();
+
+    format!(
+        "{}/{} up · {} clients · ↓{}",
+        online,
+        state.devices.len(),
+        state.clients.len(),
+        format_network_speed(download)
+    )
+}
+
+fn cache_path() -> Option<PathBuf> {
+    files::project_dirs().and_then(|dirs| {
+        let cache_dir = dirs.cache_dir();
+        std::fs::create_dir_all(cache_dir).ok()?;
+        Some(cache_dir.join("status"))
+    })
+}
+
+/// Stores the line so `unifi-tui status` can answer without hitting the controller.
+/// Failures are ignored; the next call simply fetches fresh data.
+pub fn write_cache(line: &str) {
+    if let Some(path) = cache_path() {
+        if let Err(e) = std::fs::write(&path, line) {
+            tracing::debug!(error = %e, "Failed to write status cache");
+        }
+    }
+}
+
+/// Returns the cached line if it was written within `max_age`.
+pub fn read_cache(max_age: Duration) -> Option<String> {
+    let path = cache_path()?;
+    let age = std::fs::metadata(&path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())?;
+    if age > max_age {
+        return None;
+    }
+    std::fs::read_to_string(path).ok()
+}
@@ -0,0 +1,155 @@
+use crate::events::NetworkEvent;
+use chrono::Utc;
+use clap::{Args, ValueEnum};
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::net::{TcpStream, UdpSocket};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+const RECONNECT_DELAY: Duration = Duration::from_secs(10);
+const APP_NAME: &str = "unifi-tui";
+
+// RFC 5424 severities.
+const WARNING: u8 = 4;
+const NOTICE: u8 = 5;
+const INFORMATIONAL: u8 = 6;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum SyslogProtocol {
+    Udp,
+    Tcp,
+}
+
+#[derive(Args)]
+#[command(next_help_heading = "Syslog")]
+pub struct SyslogArgs {
+    /// Forward events and alerts to this syslog server (host:port)
+    #[arg(long, env)]
+    syslog_server: Option<String>,
+
+    /// Transport used to reach the syslog server
+    #[arg(long, value_enum, default_value = "udp")]
+    syslog_protocol: SyslogProtocol,
+
+    /// Facility number (16-23 are local0-local7)
+    #[arg(long, default_value = "16")]
+    syslog_facility: u8,
+}
+
+impl SyslogArgs {
+    pub fn forwarder(self) -> Option<SyslogForwarder> {
+        let server = self.syslog_server?;
+        Some(SyslogForwarder::start(
+            server,
+            self.syslog_protocol,
+            self.syslog_facility,
+        ))
+    }
+}
+
+/// Sends each event as an RFC 5424 message. Delivery happens on a background
+/// task; TCP uses octet-counted framing (RFC 6587) and reconnects after failures.
+pub struct SyslogForwarder {
+    tx: UnboundedSender<String>,
+    facility: u8,
+    hostname: String,
+}
+
+impl SyslogForwarder {
+    pub fn start(server: String, protocol: SyslogProtocol, facility: u8) -> Self {
+        let (tx, rx) = unbounded_channel();
+        tokio::spawn(run(server, protocol, rx));
+        Self {
+            tx,
+            facility: facility.min(23),
+            hostname: hostname(),
+        }
+    }
+
+    pub fn forward(&self, events: &[NetworkEvent]) {
+        for event in events {
+            let message = format!(
+                "<{}>1 {} {} {} {} {} - {}",
+                self.facility as u16 * 8 + severity(event) as u16,
+                Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+                self.hostname,
+                APP_NAME,
+                std::process::id(),
+                event.name(),
+                event.describe()
+            );
+            if self.tx.send(message).is_err() {
+                tracing::warn!("Syslog forwarder stopped");
+                return;
+            }
+        }
+    }
+}
+
+fn severity(event: &NetworkEvent) -> u8 {
+    match event {
+        NetworkEvent::Alert { .. } => WARNING,
+        NetworkEvent::DeviceState { to, .. } if to != "Online" => WARNING,
+        NetworkEvent::DeviceState { .. } | NetworkEvent::DeviceAdded { .. } => NOTICE,
+        NetworkEvent::ClientConnected { .. } | NetworkEvent::ClientDisconnected { .. } => {
+            INFORMATIONAL
+        }
+    }
+}
+
+fn hostname() -> String {
+    std::env::var("HOSTNAME")
+        .ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty() && !name.contains(' '))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+async fn run(server: String, protocol: SyslogProtocol, mut rx: UnboundedReceiver<String>) {
+    loop {
+        let result = match protocol {
+            SyslogProtocol::Udp => send_udp(&server, &mut rx).await,
+            SyslogProtocol::Tcp => send_tcp(&server, &mut rx).await,
+        };
+        match result {
+            Ok(()) => return,
+            Err(e) => tracing::warn!(server = %server, error = %e, "Syslog forwarding failed"),
+        }
+        tokio::time::sleep(RECONNECT_DELAY).await;
+        // Drop what piled up while the server was unreachable.
+        while rx.try_recv().is_ok() {}
+    }
+}
+
+async fn send_udp(server: &str, rx: &mut UnboundedReceiver<String>) -> std::io::Result<()> {
+    let addr = tokio::net::lookup_host(server)
+        .await?
+        .next()
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "Syslog server did not resolve",
+            )
+        })?;
+    let local = if addr.is_ipv4() {
+        "0.0.0.0:0"
+    } else {
+        "[::]:0"
+    };
+    let socket = UdpSocket::bind(local).await?;
+    socket.connect(addr).await?;
+    while let Some(message) = rx.recv().await {
+        socket.send(message.as_bytes()).await?;
+    }
+    Ok(())
+}
+
+async fn send_tcp(server: &str, rx: &mut UnboundedReceiver<String>) -> std::io::Result<()> {
+    let mut stream = TcpStream::connect(server).await?;
+    while let Some(message) = rx.recv().await {
+        let framed = format!("{} {}", message.len(), message);
+        stream.write_all(framed.as_bytes()).await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use crate::error::Result;
+use crate::state::AppState;
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::future::Future;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+/// Finished jobs kept for the jobs panel once their toast is gone.
+const MAX_FINISHED_JOBS: usize = 20;
+
+/// Applied to the state on the UI loop once a task finishes, for tasks that fetch
+/// something the UI shows rather than just reporting how they went.
+pub type StateUpdate = Box<dyn FnOnce(&mut AppState) + Send>;
+
+pub struct TaskOutcome {
+    pub label: String,
+    pub result: Result<String>,
+    pub update: Option<StateUpdate>,
+    id: u64,
+}
+
+#[derive(Clone)]
+pub enum JobStatus {
+    Running,
+    Succeeded(String),
+    Failed(String),
+}
+
+pub struct Job {
+    pub label: String,
+    pub started_at: DateTime<Local>,
+    pub finished_at: Option<DateTime<Local>>,
+    pub status: JobStatus,
+    id: u64,
+}
+
+/// Runs async work off the UI loop. Dialog callbacks and scheduled jobs enqueue
+/// their futures here and the loop drains the outcomes on every tick, so nothing
+/// on the input path ever waits on the controller. Every task is also tracked as
+/// a job so the jobs panel can list what is running and how recent ones ended.
+pub struct TaskRunner {
+    tx: UnboundedSender<TaskOutcome>,
+    rx: UnboundedReceiver<TaskOutcome>,
+    next_id: u64,
+    jobs: VecDeque<Job>,
+}
+
+impl TaskRunner {
+    pub fn new() -> Self {
+        let (tx, rx) = unbounded_channel();
+        Self {
+            tx,
+            rx,
+            next_id: 0,
+            jobs: VecDeque::new(),
+        }
+    }
+
+    pub fn spawn<F>(&mut self, label: impl Into<String>, task: F)
+    where
+        F: Future<Output = Result<String>> + Send + 'static,
+    {
+        self.spawn_with_update(label, async move { (task.await, None) });
+    }
+
+    /// Like [`TaskRunner::spawn`], but the task also hands back an update for the
+    /// state, applied whether or not it succeeded so it can clear any pending marker.
+    pub fn spawn_with_update<F>(&mut self, label: impl Into<String>, task: F)
+    where
+        F: Future<Output = (Result<String>, Option<StateUpdate>)> + Send + 'static,
+    {
+        let tx = self.tx.clone();
+        let label = label.into();
+        let id = self.next_id;
+        self.next_id += 1;
+        self.jobs.push_back(Job {
+            label: label.clone(),
+            started_at: Local::now(),
+            finished_at: None,
+            status: JobStatus::Running,
+            id,
+        });
+
+        tracing::debug!(task = %label, "Spawning task");
+        tokio::spawn(async move {
+            let (result, update) = task.await;
+            let outcome = TaskOutcome {
+                label,
+                result,
+                update,
+                id,
+            };
+            if tx.send(outcome).is_err() {
+                tracing::warn!("Task finished after the UI shut down");
+            }
+        });
+    }
+
+    pub fn drain(&mut self) -> Vec<TaskOutcome> {
+        let mut outcomes = Vec::new();
+        while let Ok(outcome) = self.rx.try_recv() {
+            if let Some(job) = self.jobs.iter_mut().find(|job| job.id == outcome.id) {
+                job.finished_at = Some(Local::now());
+                job.status = match &outcome.result {
+                    Ok(message) => JobStatus::Succeeded(message.clone()),
+                    Err(e) => JobStatus::Failed(e.to_string()),
+                };
+            }
+            outcomes.push(outcome);
+        }
+
+        let finished = self
+            .jobs
+            .iter()
+            .filter(|job| job.finished_at.is_some())
+            .count();
+        let mut excess = finished.saturating_sub(MAX_FINISHED_JOBS);
+        self.jobs.retain(|job| {
+            if excess > 0 && job.finished_at.is_some() {
+                excess -= 1;
+                return false;
+            }
+            true
+        });
+        outcomes
+    }
+
+    /// Running and recently finished jobs, oldest first.
+    pub fn jobs(&self) -> &VecDeque<Job> {
+        &self.jobs
+    }
+
+    pub fn running_count(&self) -> usize {
+        self.jobs
+            .iter()
+            .filter(|job| matches!(job.status, JobStatus::Running))
+            .count()
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+const MAX_TOASTS: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ToastLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastLevel {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ToastLevel::Info => "Info",
+            ToastLevel::Success => "Success",
+            ToastLevel::Warning => "Warning",
+            ToastLevel::Error => "Error",
+        }
+    }
+
+    fn lifetime(&self) -> Duration {
+        match self {
+            ToastLevel::Info | ToastLevel::Success => Duration::from_secs(4),
+            ToastLevel::Warning => Duration::from_secs(6),
+            ToastLevel::Error => Duration::from_secs(8),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub level: ToastLevel,
+    pub message: String,
+    pub count: usize,
+    pub shown_at: Instant,
+}
+
+impl Toast {
+    pub fn is_expired(&self) -> bool {
+        self.shown_at.elapsed() >= self.level.lifetime()
+    }
+}
+
+#[derive(Default)]
+pub struct Toasts {
+    queue: VecDeque<Toast>,
+}
+
+impl Toasts {
+    pub fn push(&mut self, level: ToastLevel, message: impl Into<String>) {
+        let message = message.into();
+
+        // Repeated messages (e.g. the same refresh error every cycle) bump a
+        // counter on the live toast instead of stacking copies of it.
+        if let Some(existing) = self
+            .queue
+            .iter_mut()
+            .find(|t| t.level == level && t.message == message && !t.is_expired())
+        {
+            existing.count += 1;
+            existing.shown_at = Instant::now();
+            return;
+        }
+
+        if self.queue.len() >= MAX_TOASTS {
+            self.queue.pop_front();
+        }
+        self.queue.push_back(Toast {
+            level,
+            message,
+            count: 1,
+            shown_at: Instant::now(),
+        });
+    }
+
+    pub fn prune(&mut self) {
+        self.queue.retain(|t| !t.is_expired());
+    }
+
+    /// The newest live toasts, newest first.
+    pub fn visible(&self, limit: usize) -> impl Iterator<Item = &Toast> {
+        self.queue
+            .iter()
+            .rev()
+            .filter(|t| !t.is_expired())
+            .take(limit)
+    }
+}
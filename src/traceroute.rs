@@ -0,0 +1,131 @@
+use std::process::Stdio;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+#[derive(Debug, Clone)]
+pub struct Hop {
+    pub ttl: u32,
+    /// `None` when the hop did not answer (`*`).
+    pub address: Option<String>,
+    pub rtt_ms: Option<f64>,
+}
+
+enum TraceEvent {
+    Hop(Hop),
+    Finished(Result<(), String>),
+}
+
+/// A traceroute run against a single target. Hops stream in from the system
+/// `traceroute` (or `tracert` on Windows) while the panel is open.
+pub struct Traceroute {
+    pub target: String,
+    pub label: String,
+    pub hops: Vec<Hop>,
+    pub finished: bool,
+    pub error: Option<String>,
+    rx: UnboundedReceiver<TraceEvent>,
+}
+
+impl Traceroute {
+    pub fn start(target: String, label: String) -> Self {
+        let (tx, rx) = unbounded_channel();
+        let address = target.clone();
+        tokio::spawn(async move {
+            let result = run(&address, &tx).await;
+            let _ = tx.send(TraceEvent::Finished(result));
+        });
+
+        Self {
+            target,
+            label,
+            hops: Vec::new(),
+            finished: false,
+            error: None,
+            rx,
+        }
+    }
+
+    pub fn poll(&mut self) {
+        while let Ok(event) = self.rx.try_recv() {
+            match event {
+                TraceEvent::Hop(hop) => self.hops.push(hop),
+                TraceEvent::Finished(result) => {
+                    self.finished = true;
+                    self.error = result.err();
+                }
+            }
+        }
+    }
+}
+
+async fn run(target: &str, tx: &UnboundedSender<TraceEvent>) -> Result<(), String> {
+    let mut command = if cfg!(windows) {
+        let mut command = Command::new("tracert");
+        command.args(["-d", "-w", "1000", target]);
+        command
+    } else {
+        let mut command = Command::new("traceroute");
+        command.args(["-n", "-q", "1", "-w", "1", target]);
+        command
+    };
+
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| format!("Failed to start traceroute: {}", e))?;
+
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| "traceroute produced no output".to_string())?;
+    let mut lines = BufReader::new(stdout).lines();
+
+    while let Ok(Some(line)) = lines.next_line().await {
+        if let Some(hop) = parse_hop(&line) {
+            if tx.send(TraceEvent::Hop(hop)).is_err() {
+                // The panel was closed, dropping the child kills the process.
+                return Ok(());
+            }
+        }
+    }
+
+    let status = child.wait().await.map_err(|e| e.to_string())?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("traceroute exited with {}", status))
+    }
+}
+
+/// Parses a hop line such as ` 3  10.0.0.1  4.211 ms` or ` 4  *`. Header
+/// lines and anything else without a leading TTL are skipped.
+fn parse_hop(line: &str) -> Option<Hop> {
+    let mut fields = line.split_whitespace();
+    let ttl = fields.next()?.parse().ok()?;
+
+    let mut address = None;
+    let mut rtt_ms = None;
+    let mut previous: Option<&str> = None;
+    for field in fields {
+        if field == "ms" {
+            rtt_ms = rtt_ms.or_else(|| previous?.trim_start_matches('<').parse().ok());
+        } else if let Some(ms) = field.strip_suffix("ms") {
+            rtt_ms = rtt_ms.or_else(|| ms.trim_start_matches('<').parse().ok());
+        } else if address.is_none() && field.parse::<std::net::IpAddr>().is_ok() {
+            address = Some(field.to_string());
+        }
+        previous = Some(field);
+        if address.is_some() && rtt_ms.is_some() {
+            break;
+        }
+    }
+
+    Some(Hop {
+        ttl,
+        address,
+        rtt_ms,
+    })
+}
@@ -0,0 +1,434 @@
+use crate::app::{App, Mode, Tab};
+use crate::oui;
+use crate::ui::widgets::format_network_speed;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+use std::collections::HashMap;
+use unifi_rs::device::DeviceState;
+use unifi_rs::models::client::ClientOverview;
+
+// Screen readers read the terminal line by line, so this mode draws each view as
+// plain sentences without borders, tables or symbols, and repeats the selected
+// line on a fixed announcement line.
+
+/// Draws the current view as plain text. Returns false for views that have no
+/// text rendering yet, so the normal layout is drawn instead.
+pub fn render_accessible(f: &mut Frame, app: &App, area: Rect) -> bool {
+    let (title, lines, selected) = match app.mode {
+        Mode::Overview => {
+            let (lines, selected) = match app.current_tab {
+                Tab::Dashboard => (dashboard_lines(app), None),
+                Tab::Sites => (site_lines(app), app.sites_table_state.selected()),
+                Tab::Devices => (device_lines(app), app.devices_table_state.selected()),
+                Tab::Clients => (client_lines(app), app.clients_table_state.selected()),
+                Tab::Topology => topology_lines(app),
+                Tab::Stats => (stats_lines(app), None),
+            };
+            (app.current_tab.title().to_string(), lines, selected)
+        }
+        Mode::DeviceDetail => ("Device details".to_string(), device_detail_lines(app), None),
+        Mode::ClientDetail => ("Client details".to_string(), client_detail_lines(app), None),
+        _ => return false,
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(1), // View
+                Constraint::Min(0),    // Content
+                Constraint::Length(1), // Announcement
+                Constraint::Length(1), // Status
+            ]
+            .as_ref(),
+        )
+        .split(area);
+
+    f.render_widget(
+        Paragraph::new(format!(
+            "View: {}. Tab for the next view, ? for help.",
+            title
+        ))
+        .style(Style::default().add_modifier(Modifier::BOLD)),
+        chunks[0],
+    );
+
+    let selected = selected.filter(|i| *i < lines.len());
+    let text: Vec<Line> = lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            if Some(i) == selected {
+                Line::styled(
+                    format!("> {}", line),
+                    Style::default().add_modifier(Modifier::REVERSED),
+                )
+            } else {
+                Line::from(format!("  {}", line))
+            }
+        })
+        .collect();
+    // Keep the selected line in view.
+    let scroll = selected.map_or(0, |i| i.saturating_sub(chunks[1].height as usize / 2));
+    f.render_widget(
+        Paragraph::new(text).scroll((scroll.min(u16::MAX as usize) as u16, 0)),
+        chunks[1],
+    );
+
+    let announcement = match selected {
+        Some(i) => format!("Selected: {}", lines[i]),
+        None if lines.is_empty() => "Nothing to show.".to_string(),
+        None => String::new(),
+    };
+    f.render_widget(Paragraph::new(announcement), chunks[2]);
+    f.render_widget(Paragraph::new(status_line(app)), chunks[3]);
+    true
+}
+
+fn status_line(app: &App) -> String {
+    let online = app
+        .state
+        .devices
+        .iter()
+        .filter(|d| matches!(d.state, DeviceState::Online))
+        .count();
+    let site = app
+        .state
+        .selected_site
+        .as_ref()
+        .map_or("All sites".to_string(), |s| s.site_name.clone());
+    format!(
+        "Site: {}. Devices: {}, {} online. Clients: {}. Unacknowledged alerts: {}.",
+        site,
+        app.state.devices.len(),
+        online,
+        app.state.clients.len(),
+        app.alerts.unacknowledged_count()
+    )
+}
+
+fn device_name(app: &App, device_id: uuid::Uuid) -> &str {
+    app.state
+        .devices
+        .iter()
+        .find(|d| d.id == device_id)
+        .map_or("an unknown device", |d| d.name.as_str())
+}
+
+fn dashboard_lines(app: &App) -> Vec<String> {
+    let offline: Vec<&str> = app
+        .state
+        .devices
+        .iter()
+        .filter(|d| !matches!(d.state, DeviceState::Online))
+        .map(|d| d.name.as_str())
+        .collect();
+
+    let mut lines = Vec::new();
+    for gateway in app
+        .state
+        .devices
+        .iter()
+        .filter(|d| app.state.is_gateway(d.id))
+    {
+        lines.push(format!("Gateway {}: {:?}.", gateway.name, gateway.state));
+    }
+    if offline.is_empty() {
+        lines.push(format!(
+            "All {} devices are online.",
+            app.state.devices.len()
+        ));
+    } else {
+        lines.push(format!(
+            "{} devices are not online: {}.",
+            offline.len(),
+            offline.join(", ")
+        ));
+    }
+
+    let alerts: Vec<_> = app
+        .alerts
+        .sorted()
+        .into_iter()
+        .filter(|alert| !alert.acknowledged)
+        .collect();
+    lines.push(format!("Unacknowledged alerts: {}.", alerts.len()));
+    lines.extend(
+        alerts
+            .iter()
+            .map(|alert| format!("Alert: {}.", alert.message)),
+    );
+    lines
+}
+
+fn site_lines(app: &App) -> Vec<String> {
+    let summaries = app.state.site_summaries();
+    let total = app.state.filtered_sites.len();
+    app.state
+        .filtered_sites
+        .iter()
+        .enumerate()
+        .map(|(i, site)| {
+            let name = site.name.as_deref().unwrap_or("Unnamed");
+            match summaries.get(&site.id) {
+                Some(summary) => format!(
+                    "Site {} of {}: {}, {} devices, {} online, {} clients.",
+                    i + 1,
+                    total,
+                    name,
+                    summary.devices,
+                    summary.online,
+                    summary.clients
+                ),
+                None => format!("Site {} of {}: {}.", i + 1, total, name),
+            }
+        })
+        .collect()
+}
+
+fn device_lines(app: &App) -> Vec<String> {
+    let total = app.state.filtered_devices.len();
+    app.state
+        .filtered_devices
+        .iter()
+        .enumerate()
+        .map(|(i, device)| {
+            let mut line = format!(
+                "Device {} of {}: {}, model {}, status {:?}, IP address {}",
+                i + 1,
+                total,
+                device.name,
+                device.model,
+                device.state,
+                device.ip_address
+            );
+            if let Some(stats) = app.state.device_stats.get(&device.id) {
+                if let Some(cpu) = stats.cpu_utilization_pct {
+                    line.push_str(&format!(", CPU {:.0} percent", cpu));
+                }
+                if let Some(memory) = stats.memory_utilization_pct {
+                    line.push_str(&format!(", memory {:.0} percent", memory));
+                }
+            }
+            line.push('.');
+            line
+        })
+        .collect()
+}
+
+fn client_lines(app: &App) -> Vec<String> {
+    let total = app.state.filtered_clients.len();
+    app.state
+        .filtered_clients
+        .iter()
+        .enumerate()
+        .map(|(i, client)| {
+            let (kind, name, ip, uplink) = match client {
+                ClientOverview::Wired(c) => (
+                    "wired",
+                    app.state
+                        .aliases
+                        .display_name(&c.mac_address, c.base.name.as_deref()),
+                    c.base.ip_address.as_deref(),
+                    c.uplink_device_id,
+                ),
+                ClientOverview::Wireless(c) => (
+                    "wireless",
+                    app.state
+                        .aliases
+                        .display_name(&c.mac_address, c.base.name.as_deref()),
+                    c.base.ip_address.as_deref(),
+                    c.uplink_device_id,
+                ),
+                _ => return format!("Client {} of {}: unknown type.", i + 1, total),
+            };
+            format!(
+                "Client {} of {}: {}, {}, IP address {}, connected to {}.",
+                i + 1,
+                total,
+                name,
+                kind,
+                ip.unwrap_or("unknown"),
+                device_name(app, uplink)
+            )
+        })
+        .collect()
+}
+
+/// Devices as an indented tree following their uplinks, with the selected device
+/// from the topology view.
+fn topology_lines(app: &App) -> (Vec<String>, Option<usize>) {
+    let mut devices: Vec<_> = app
+        .state
+        .devices
+        .iter()
+        .map(|device| {
+            let path: Vec<&str> = app
+                .state
+                .uplink_chain(device.id)
+                .into_iter()
+                .rev()
+                .map(|d| d.name.as_str())
+                .collect();
+            (path, device)
+        })
+        .collect();
+    devices.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let selected_name = app
+        .topology_view
+        .get_selected_node()
+        .map(|n| n.name.clone());
+    let mut selected = None;
+    let lines = devices
+        .iter()
+        .enumerate()
+        .map(|(i, (path, device))| {
+            if selected_name.as_deref() == Some(device.name.as_str()) {
+                selected = Some(i);
+            }
+            let indent = "  ".repeat(path.len().saturating_sub(1));
+            match path.len() {
+                0 | 1 => format!("{}{}, {:?}.", indent, device.name, device.state),
+                n => format!(
+                    "{}{}, {:?}, connected to {}.",
+                    indent,
+                    device.name,
+                    device.state,
+                    path[n - 2]
+                ),
+            }
+        })
+        .collect();
+    (lines, selected)
+}
+
+fn stats_lines(app: &App) -> Vec<String> {
+    let online = app
+        .state
+        .devices
+        .iter()
+        .filter(|d| matches!(d.state, DeviceState::Online))
+        .count();
+    let wireless = app
+        .state
+        .clients
+        .iter()
+        .filter(|c| matches!(c, ClientOverview::Wireless(_)))
+        .count();
+    let (tx, rx) = app
+        .state
+        .device_stats
+        .values()
+        .filter_map(|stats| stats.uplink.as_ref())
+        .fold((0, 0), |(tx, rx), u| {
+            (tx + u.tx_rate_bps, rx + u.rx_rate_bps)
+        });
+
+    let mut vendors: HashMap<&str, usize> = HashMap::new();
+    for client in &app.state.clients {
+        let mac = match client {
+            ClientOverview::Wired(c) => &c.mac_address,
+            ClientOverview::Wireless(c) => &c.mac_address,
+            _ => continue,
+        };
+        *vendors.entry(oui::vendor(mac)).or_default() += 1;
+    }
+    let mut vendors: Vec<_> = vendors.into_iter().collect();
+    vendors.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+    vec![
+        format!("Devices online: {} of {}.", online, app.state.devices.len()),
+        format!(
+            "Clients: {}, of which {} wireless and {} wired.",
+            app.state.clients.len(),
+            wireless,
+            app.state.clients.len() - wireless
+        ),
+        format!(
+            "Link speed: up {}, down {}.",
+            format_network_speed(tx),
+            format_network_speed(rx)
+        ),
+        format!(
+            "Clients by vendor: {}.",
+            vendors
+                .iter()
+                .map(|(vendor, count)| format!("{} {}", vendor, count))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    ]
+}
+
+fn device_detail_lines(app: &App) -> Vec<String> {
+    let Some(device) = app
+        .selected_device_id
+        .and_then(|id| app.state.devices.iter().find(|d| d.id == id))
+    else {
+        return Vec::new();
+    };
+    let mut lines = vec![
+        format!("Name: {}.", device.name),
+        format!("Model: {}.", device.model),
+        format!("Status: {:?}.", device.state),
+        format!("IP address: {}.", device.ip_address),
+        format!("MAC address: {}.", device.mac_address),
+    ];
+    if let Some(stats) = app.state.device_stats.get(&device.id) {
+        if let Some(cpu) = stats.cpu_utilization_pct {
+            lines.push(format!("CPU: {:.0} percent.", cpu));
+        }
+        if let Some(memory) = stats.memory_utilization_pct {
+            lines.push(format!("Memory: {:.0} percent.", memory));
+        }
+        if let Some(uplink) = &stats.uplink {
+            lines.push(format!(
+                "Uplink: up {}, down {}.",
+                format_network_speed(uplink.tx_rate_bps),
+                format_network_speed(uplink.rx_rate_bps)
+            ));
+        }
+    }
+    lines
+}
+
+fn client_detail_lines(app: &App) -> Vec<String> {
+    let Some(client) = app.selected_client_id.and_then(|id| {
+        app.state.clients.iter().find(|client| match client {
+            ClientOverview::Wired(c) => c.base.id == id,
+            ClientOverview::Wireless(c) => c.base.id == id,
+            _ => false,
+        })
+    }) else {
+        return Vec::new();
+    };
+    let (kind, mac, name, ip, uplink) = match client {
+        ClientOverview::Wired(c) => (
+            "Wired",
+            &c.mac_address,
+            c.base.name.as_deref(),
+            c.base.ip_address.as_deref(),
+            c.uplink_device_id,
+        ),
+        ClientOverview::Wireless(c) => (
+            "Wireless",
+            &c.mac_address,
+            c.base.name.as_deref(),
+            c.base.ip_address.as_deref(),
+            c.uplink_device_id,
+        ),
+        _ => return Vec::new(),
+    };
+    vec![
+        format!("Name: {}.", app.state.aliases.display_name(mac, name)),
+        format!("Connection: {}.", kind),
+        format!("IP address: {}.", ip.unwrap_or("unknown")),
+        format!("MAC address: {}.", mac),
+        format!("Vendor: {}.", oui::vendor(mac)),
+        format!("Connected to: {}.", device_name(app, uplink)),
+    ]
+}
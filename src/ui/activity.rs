@@ -0,0 +1,69 @@
+use crate::app::App;
+use crate::audit::AuditResult;
+use crate::ui::centered_rect;
+use chrono::Local;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, Cell, Clear, Row, Table};
+use ratatui::Frame;
+
+pub fn render_activity_panel(f: &mut Frame, app: &App, area: Rect) {
+    let panel_area = centered_rect(80, 20, area);
+
+    let header = Row::new(vec!["Time", "User", "Action", "Target", "Result"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> =
+        app.audit
+            .recent()
+            .into_iter()
+            .map(|entry| {
+                let result = match entry.result {
+                    AuditResult::Success => {
+                        Cell::from("Success").style(Style::default().fg(Color::Green))
+                    }
+                    AuditResult::Failed(reason) => Cell::from(format!("Failed: {}", reason))
+                        .style(Style::default().fg(Color::Red)),
+                };
+
+                Row::new(vec![
+                    Cell::from(
+                        entry
+                            .timestamp
+                            .with_timezone(&Local)
+                            .format("%Y-%m-%d %H:%M:%S")
+                            .to_string(),
+                    ),
+                    Cell::from(entry.user),
+                    Cell::from(entry.action),
+                    Cell::from(entry.target),
+                    result,
+                ])
+            })
+            .collect();
+
+    let widths = [
+        Constraint::Percentage(20),
+        Constraint::Percentage(10),
+        Constraint::Percentage(20),
+        Constraint::Percentage(20),
+        Constraint::Percentage(30),
+    ];
+
+    let table = Table::new(rows, widths).header(header).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Activity (Esc: Close)"),
+    );
+
+    f.render_widget(Clear, panel_area);
+    f.render_widget(table, panel_area);
+}
+
+pub async fn handle_activity_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
+    if key.code == KeyCode::Esc {
+        app.show_activity = false;
+    }
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use crate::app::App;
+use crate::toast::ToastLevel;
+use crate::ui::centered_rect;
+use chrono::Local;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, Cell, Clear, Row, Table};
+use ratatui::Frame;
+
+pub fn render_alerts_panel(f: &mut Frame, app: &mut App, area: Rect) {
+    let panel_area = centered_rect(80, 20, area);
+
+    let header = Row::new(vec!["Raised", "Alert", "Details", "Status"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let alerts = app.alerts.sorted();
+    let rows: Vec<Row> = alerts
+        .iter()
+        .map(|alert| {
+            let status = if alert.acknowledged {
+                Cell::from("Acknowledged").style(Style::default().fg(Color::DarkGray))
+            } else {
+                Cell::from("New").style(Style::default().fg(Color::Red))
+            };
+            Row::new(vec![
+                Cell::from(
+                    alert
+                        .raised_at
+                        .with_timezone(&Local)
+                        .format("%Y-%m-%d %H:%M:%S")
+                        .to_string(),
+                ),
+                Cell::from(alert.kind.label()),
+                Cell::from(alert.message.clone()),
+                status,
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(20),
+        Constraint::Length(12),
+        Constraint::Min(20),
+        Constraint::Length(13),
+    ];
+
+    let title = format!(
+        "Alerts [{} new] (a: Acknowledge | Esc: Close)",
+        app.alerts.unacknowledged_count()
+    );
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .row_highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol("➤ ");
+
+    f.render_widget(Clear, panel_area);
+    f.render_stateful_widget(table, panel_area, &mut app.alerts_table_state);
+}
+
+pub async fn handle_alerts_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
+    let alert_count = app.alerts.active_count();
+
+    match key.code {
+        KeyCode::Down => {
+            let i = match app.alerts_table_state.selected() {
+                Some(i) => {
+                    if i >= alert_count.saturating_sub(1) {
+                        0
+                    } else {
+                        i + 1
+                    }
+                }
+                None => 0,
+            };
+            app.alerts_table_state.select(Some(i));
+        }
+        KeyCode::Up => {
+            let i = match app.alerts_table_state.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        alert_count.saturating_sub(1)
+                    } else {
+                        i - 1
+                    }
+                }
+                None => 0,
+            };
+            app.alerts_table_state.select(Some(i));
+        }
+        KeyCode::Char('a') | KeyCode::Enter => {
+            let Some((device_id, kind, message)) = app
+                .alerts_table_state
+                .selected()
+                .and_then(|idx| app.alerts.sorted().get(idx).copied())
+                .map(|alert| (alert.device_id, alert.kind, alert.message.clone()))
+            else {
+                return Ok(());
+            };
+            match app.alerts.toggle_acknowledged(device_id, kind) {
+                Some(true) => app
+                    .state
+                    .notify(ToastLevel::Info, format!("Acknowledged: {}", message)),
+                Some(false) => app
+                    .state
+                    .notify(ToastLevel::Info, format!("Unacknowledged: {}", message)),
+                None => {}
+            }
+        }
+        KeyCode::Esc => {
+            app.show_alerts = false;
+        }
+        _ => {}
+    }
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use crate::app::{App, Mode, Tab};
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+use unifi_rs::models::client::ClientOverview;
+
+/// Where the current view sits, from the site context down, e.g.
+/// "Site: Office ▸ Devices ▸ UDM-Pro ▸ Ports". Esc returns to the second to last crumb.
+fn crumbs(app: &App) -> Vec<String> {
+    let mut crumbs = vec![app
+        .state
+        .selected_site
+        .as_ref()
+        .map_or("All Sites".to_string(), |s| {
+            format!("Site: {}", s.site_name)
+        })];
+
+    match app.mode {
+        Mode::Overview => {
+            crumbs.push(app.current_tab.title().to_string());
+            if app.current_tab == Tab::Topology {
+                if let Some(node) = app.topology_view.get_selected_node() {
+                    crumbs.push(node.name.clone());
+                }
+            }
+        }
+        Mode::DeviceDetail => {
+            crumbs.push(app.current_tab.title().to_string());
+            if let Some(view) = &app.device_stats_view {
+                if let Some(device) = app.state.devices.iter().find(|d| d.id == view.device_id) {
+                    crumbs.push(device.name.clone());
+                }
+                if let Some(tab) = view.tab_title(&app.state) {
+                    crumbs.push(tab.to_string());
+                }
+            }
+        }
+        Mode::ClientDetail => {
+            crumbs.push(app.current_tab.title().to_string());
+            let name = app.state.clients.iter().find_map(|client| match client {
+                ClientOverview::Wired(c) if Some(c.base.id) == app.selected_client_id => Some(
+                    app.state
+                        .aliases
+                        .display_name(&c.mac_address, c.base.name.as_deref()),
+                ),
+                ClientOverview::Wireless(c) if Some(c.base.id) == app.selected_client_id => Some(
+                    app.state
+                        .aliases
+                        .display_name(&c.mac_address, c.base.name.as_deref()),
+                ),
+                _ => None,
+            });
+            crumbs.extend(name);
+        }
+        Mode::FirmwareUpdates => crumbs.push("Firmware Updates".to_string()),
+        Mode::SiteDashboard => crumbs.push("Site Dashboard".to_string()),
+        Mode::Help => crumbs.push("Help".to_string()),
+    }
+
+    if app.show_help && app.mode != Mode::Help {
+        crumbs.push("Help".to_string());
+    }
+    crumbs
+}
+
+pub fn render_breadcrumb(f: &mut Frame, app: &App, area: Rect) {
+    let crumbs = crumbs(app);
+    let last = crumbs.len() - 1;
+
+    let mut spans = vec![Span::raw(" ")];
+    for (i, crumb) in crumbs.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(" ▸ ", Style::default().fg(Color::DarkGray)));
+        }
+        let style = if i == last {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        spans.push(Span::styled(crumb.clone(), style));
+    }
+    // The site crumb is left with the site context rather than with Esc.
+    if last >= 2 {
+        spans.push(Span::styled(
+            format!("   Esc ◂ {}", crumbs[last - 1]),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
@@ -1,19 +1,47 @@
 use crate::app::{App, SortOrder};
+use crate::audit::AuditResult;
+use crate::oui;
+use crate::script::{client_record, ScriptTarget};
+use crate::state::{frequency_label, RecentClient};
+use crate::toast::ToastLevel;
+use crate::ui::centered_rect;
+use crate::ui::icons::client_icon;
+use crate::ui::widgets::highlight_matches;
 use chrono::{DateTime, Utc};
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::Line;
-use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
+use ratatui::widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table};
 use ratatui::Frame;
 use unifi_rs::models::client::ClientOverview;
 
-pub fn render_clients(f: &mut Frame, app: &App, area: Rect) {
+pub fn render_clients(f: &mut Frame, app: &mut App, area: Rect) {
+    let site_id = app.state.selected_site.as_ref().map(|s| s.site_id);
+    let recent: Vec<&RecentClient> = app
+        .state
+        .recent_clients
+        .iter()
+        .filter(|recent| site_id.is_none_or(|id| recent.site_id == Some(id)))
+        .collect();
+    let recent_height = match recent.len() {
+        0 => 0,
+        n => n.min(5) as u16 + 3,
+    };
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
+        .constraints(
+            [
+                Constraint::Min(0),
+                Constraint::Length(recent_height),
+                Constraint::Length(3),
+            ]
+            .as_ref(),
+        )
         .split(area);
 
+    let columns = app.scripts.columns(ScriptTarget::Client);
     let clients: Vec<Row> = app
         .state
         .filtered_clients
@@ -29,7 +57,9 @@ pub fn render_clients(f: &mut Frame, app: &App, area: Rect) {
                         .map_or("Unknown", |d| d.name.as_str());
 
                     (
-                        c.base.name.as_deref().unwrap_or("Unnamed").to_string(),
+                        app.state
+                            .aliases
+                            .display_name(&c.mac_address, c.base.name.as_deref()),
                         c.base
                             .ip_address
                             .as_deref()
@@ -50,7 +80,9 @@ pub fn render_clients(f: &mut Frame, app: &App, area: Rect) {
                         .map_or("Unknown", |d| d.name.as_str());
 
                     (
-                        c.base.name.as_deref().unwrap_or("Unnamed").to_string(),
+                        app.state
+                            .aliases
+                            .display_name(&c.mac_address, c.base.name.as_deref()),
                         c.base
                             .ip_address
                             .as_deref()
@@ -78,39 +110,84 @@ pub fn render_clients(f: &mut Frame, app: &App, area: Rect) {
                 _ => "Unknown".to_string(),
             };
 
-            Row::new(vec![
-                Cell::from(name),
-                Cell::from(ip),
-                Cell::from(mac),
+            let (band, channel) = match app.state.client_band(client) {
+                Some((band, channel)) => (
+                    frequency_label(band).to_string(),
+                    channel.unwrap_or_else(|| "-".to_string()),
+                ),
+                None => ("-".to_string(), "-".to_string()),
+            };
+
+            let vendor = oui::vendor(&mac);
+            let (icon, icon_color) = client_icon(client, app.icons);
+            let mut cells = vec![
+                Cell::from(icon).style(Style::default().fg(icon_color)),
+                Cell::from(highlight_matches(name, &app.search_query)),
+                Cell::from(highlight_matches(ip, &app.search_query)),
+                Cell::from(highlight_matches(mac, &app.search_query)),
                 Cell::from(device_name),
                 r#type,
+                Cell::from(band),
+                Cell::from(channel),
                 Cell::from(connected_since),
                 status,
-            ])
+            ];
+            if app.show_client_vendor {
+                cells.insert(4, Cell::from(vendor));
+            }
+            if !columns.is_empty() {
+                let record = client_record(&app.state, client);
+                cells.extend(
+                    columns
+                        .iter()
+                        .map(|column| Cell::from(column.cell(&record))),
+                );
+            }
+            Row::new(cells)
         })
         .collect();
 
-    let header = Row::new(vec![
+    let mut header = vec![
+        Cell::from(""),
         Cell::from("Name").style(Style::default().add_modifier(Modifier::BOLD)),
         Cell::from("IP").style(Style::default().add_modifier(Modifier::BOLD)),
         Cell::from("MAC").style(Style::default().add_modifier(Modifier::BOLD)),
         Cell::from("Connected To").style(Style::default().add_modifier(Modifier::BOLD)),
         Cell::from("Type").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Band").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Ch").style(Style::default().add_modifier(Modifier::BOLD)),
         Cell::from("Duration").style(Style::default().add_modifier(Modifier::BOLD)),
         Cell::from("Status").style(Style::default().add_modifier(Modifier::BOLD)),
-    ]);
+    ];
 
-    let widths = [
-        Constraint::Percentage(20),
-        Constraint::Percentage(15),
+    let mut widths = vec![
+        Constraint::Length(2),
+        Constraint::Percentage(18),
+        Constraint::Percentage(13),
         Constraint::Percentage(15),
-        Constraint::Percentage(20),
+        Constraint::Percentage(17),
+        Constraint::Percentage(9),
+        Constraint::Length(8),
+        Constraint::Length(4),
         Constraint::Percentage(10),
-        Constraint::Percentage(12),
         Constraint::Percentage(8),
     ];
+    if app.show_client_vendor {
+        header.insert(
+            4,
+            Cell::from("Vendor").style(Style::default().add_modifier(Modifier::BOLD)),
+        );
+        widths.insert(4, Constraint::Length(12));
+    }
+    for column in columns {
+        header.push(
+            Cell::from(column.name.clone()).style(Style::default().add_modifier(Modifier::BOLD)),
+        );
+        widths.push(Constraint::Length(12));
+    }
+    let header = Row::new(header);
 
-    let title = match &app.state.selected_site {
+    let mut title = match &app.state.selected_site {
         Some(site) => format!(
             "Clients - {} [{}]",
             site.site_name,
@@ -118,6 +195,12 @@ pub fn render_clients(f: &mut Frame, app: &App, area: Rect) {
         ),
         None => format!("All Clients [{}]", app.state.filtered_clients.len()),
     };
+    if app.state.only_2ghz_clients {
+        title.push_str(" - 2.4 GHz only");
+    }
+    if let Some(filter) = &app.state.client_filter {
+        title.push_str(&format!(" - {}", filter.name));
+    }
 
     let table = Table::new(clients, widths)
         .header(header)
@@ -125,14 +208,76 @@ pub fn render_clients(f: &mut Frame, app: &App, area: Rect) {
         .row_highlight_style(Style::default().bg(Color::Gray))
         .highlight_symbol("➤ ");
 
-    f.render_stateful_widget(table, chunks[0], &mut app.clients_table_state.clone());
+    f.render_stateful_widget(table, chunks[0], &mut app.clients_table_state);
+
+    if !recent.is_empty() {
+        render_recent_clients(f, app, &recent, chunks[1]);
+    }
 
     let help_text = vec![Line::from(
-        "↑/↓: Select | Enter: Details | s: Sort | /: Search | ESC: Back",
+        "↑/↓ | Enter: Details | n: Alias | t: Trace | w: Web | s: Sort | g: 2.4GHz | \
+         v: Vendor | f: Filter | /: Search",
     )];
     let help =
         Paragraph::new(help_text).block(Block::default().borders(Borders::ALL).title("Controls"));
-    f.render_widget(help, chunks[1]);
+    f.render_widget(help, chunks[2]);
+}
+
+fn render_recent_clients(f: &mut Frame, app: &App, recent: &[&RecentClient], area: Rect) {
+    let rows: Vec<Row> = recent
+        .iter()
+        .filter_map(|recent| {
+            let (name, mac, ip, uplink) = match &recent.client {
+                ClientOverview::Wired(c) => (
+                    c.base.name.as_deref(),
+                    &c.mac_address,
+                    c.base.ip_address.as_deref(),
+                    c.uplink_device_id,
+                ),
+                ClientOverview::Wireless(c) => (
+                    c.base.name.as_deref(),
+                    &c.mac_address,
+                    c.base.ip_address.as_deref(),
+                    c.uplink_device_id,
+                ),
+                _ => return None,
+            };
+            let device_name = app
+                .state
+                .devices
+                .iter()
+                .find(|d| d.id == uplink)
+                .map_or("Unknown", |d| d.name.as_str());
+
+            Some(
+                Row::new(vec![
+                    Cell::from(""),
+                    Cell::from(app.state.aliases.display_name(mac, name)),
+                    Cell::from(ip.unwrap_or("Unknown").to_string()),
+                    Cell::from(mac.clone()),
+                    Cell::from(device_name.to_string()),
+                    Cell::from(format!("{} ago", format_duration(recent.last_seen))),
+                ])
+                .style(Style::default().fg(Color::DarkGray)),
+            )
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(2),
+        Constraint::Percentage(20),
+        Constraint::Percentage(15),
+        Constraint::Percentage(15),
+        Constraint::Percentage(20),
+        Constraint::Percentage(20),
+    ];
+
+    let table = Table::new(rows, widths).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Recently Seen [{}]", recent.len())),
+    );
+    f.render_widget(table, area);
 }
 
 fn format_duration(connected_at: DateTime<Utc>) -> String {
@@ -189,6 +334,58 @@ pub async fn handle_client_input(app: &mut App, key: KeyEvent) -> anyhow::Result
                 }
             }
         }
+        KeyCode::Char('n') => {
+            if let Some(client_id) = app
+                .clients_table_state
+                .selected()
+                .and_then(|idx| app.state.filtered_clients.get(idx))
+                .and_then(|client| match client {
+                    ClientOverview::Wired(c) => Some(c.base.id),
+                    ClientOverview::Wireless(c) => Some(c.base.id),
+                    _ => None,
+                })
+            {
+                app.open_alias_prompt(client_id);
+            }
+        }
+        KeyCode::Char('w') => {
+            if let Some(client_id) = app
+                .clients_table_state
+                .selected()
+                .and_then(|idx| app.state.filtered_clients.get(idx))
+                .and_then(|client| match client {
+                    ClientOverview::Wired(c) => Some(c.base.id),
+                    ClientOverview::Wireless(c) => Some(c.base.id),
+                    _ => None,
+                })
+            {
+                app.open_client_in_browser(client_id);
+            }
+        }
+        KeyCode::Char('t') => {
+            if let Some((target, label)) = app
+                .clients_table_state
+                .selected()
+                .and_then(|idx| app.state.filtered_clients.get(idx))
+                .and_then(|client| match client {
+                    ClientOverview::Wired(c) => Some((
+                        c.base.ip_address.clone()?,
+                        app.state
+                            .aliases
+                            .display_name(&c.mac_address, c.base.name.as_deref()),
+                    )),
+                    ClientOverview::Wireless(c) => Some((
+                        c.base.ip_address.clone()?,
+                        app.state
+                            .aliases
+                            .display_name(&c.mac_address, c.base.name.as_deref()),
+                    )),
+                    _ => None,
+                })
+            {
+                app.open_traceroute(target, label);
+            }
+        }
         KeyCode::Char('s') => {
             match app.client_sort_order {
                 SortOrder::None => app.client_sort_order = SortOrder::Ascending,
@@ -197,6 +394,17 @@ pub async fn handle_client_input(app: &mut App, key: KeyEvent) -> anyhow::Result
             }
             app.sort_clients();
         }
+        KeyCode::Char('v') => {
+            app.show_client_vendor = !app.show_client_vendor;
+        }
+        KeyCode::Char('f') => {
+            app.cycle_script_filter(ScriptTarget::Client);
+        }
+        KeyCode::Char('g') => {
+            app.state.only_2ghz_clients = !app.state.only_2ghz_clients;
+            app.state.search(&app.search_query);
+            app.sort_clients();
+        }
         KeyCode::Esc => {
             app.back_to_overview();
         }
@@ -204,3 +412,67 @@ pub async fn handle_client_input(app: &mut App, key: KeyEvent) -> anyhow::Result
     }
     Ok(())
 }
+
+pub fn render_alias_prompt(f: &mut Frame, app: &App, area: Rect) {
+    if let Some(prompt) = &app.alias_prompt {
+        let prompt_area = centered_rect(60, 6, area);
+
+        let text = vec![
+            Line::from(format!("Alias for {}:", prompt.mac_address)),
+            Line::styled(
+                format!("> {}", prompt.input),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Line::from(""),
+            Line::from("Enter: Save (empty clears) | Esc: Cancel"),
+        ];
+
+        let widget = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title("Client Alias"));
+
+        f.render_widget(Clear, prompt_area);
+        f.render_widget(widget, prompt_area);
+    }
+}
+
+pub async fn handle_alias_prompt_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
+    let Some(prompt) = app.alias_prompt.as_mut() else {
+        return Ok(());
+    };
+
+    match key.code {
+        KeyCode::Esc => {
+            app.alias_prompt = None;
+        }
+        KeyCode::Char(c) => {
+            prompt.input.push(c);
+        }
+        KeyCode::Backspace => {
+            prompt.input.pop();
+        }
+        KeyCode::Enter => {
+            if let Some(prompt) = app.alias_prompt.take() {
+                match app.state.aliases.set(&prompt.mac_address, &prompt.input) {
+                    Ok(()) => {
+                        app.audit.record(
+                            "Set client alias",
+                            &format!("{} = {}", prompt.mac_address, prompt.input.trim()),
+                            AuditResult::Success,
+                        );
+                        app.state.notify(ToastLevel::Success, "Client alias saved");
+                        if !app.search_query.is_empty() {
+                            app.state.search(&app.search_query);
+                        }
+                        app.sort_clients();
+                    }
+                    Err(e) => {
+                        app.state
+                            .set_error(format!("Failed to save client alias: {}", e));
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
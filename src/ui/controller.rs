@@ -0,0 +1,88 @@
+use crate::app::App;
+use crate::ui::centered_rect;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+// The Integration API only reports the application version; console model,
+// pending updates and uptime are listed so it is clear they were not omitted by accident.
+const NOT_REPORTED: &str = "Not reported by the API";
+
+pub fn render_controller_panel(f: &mut Frame, app: &App, area: Rect) {
+    let panel_area = centered_rect(60, 12, area);
+
+    let field = |label: &'static str, value: String, style: Style| {
+        Line::from(vec![
+            Span::styled(
+                format!("{:<14}", label),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(value, style),
+        ])
+    };
+    let missing = Style::default().fg(Color::DarkGray);
+
+    let url = app
+        .connection
+        .as_ref()
+        .map_or("Unknown".to_string(), |c| c.url.clone());
+
+    let socket = app
+        .control_socket
+        .as_ref()
+        .map_or("Disabled".to_string(), |s| s.path().display().to_string());
+
+    let mut text = vec![
+        field("Controller", url, Style::default()),
+        field("Control", socket, Style::default()),
+    ];
+    match &app.state.controller_info {
+        Some(info) => {
+            text.push(field(
+                "Version",
+                info.application_version.clone(),
+                Style::default().fg(Color::Green),
+            ));
+            text.push(field("Console model", NOT_REPORTED.to_string(), missing));
+            text.push(field("Update", NOT_REPORTED.to_string(), missing));
+            text.push(field("Uptime", NOT_REPORTED.to_string(), missing));
+            text.push(field(
+                "Sites",
+                app.state.sites.len().to_string(),
+                Style::default(),
+            ));
+            text.push(Line::from(""));
+            text.push(Line::from(format!(
+                "Fetched at {}",
+                info.fetched_at.format("%Y-%m-%d %H:%M:%S")
+            )));
+        }
+        None => {
+            text.push(Line::from(""));
+            text.push(Line::from("Controller info could not be fetched"));
+        }
+    }
+
+    let widget = Paragraph::new(text).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("About Controller (r: Refresh | Esc: Close)"),
+    );
+
+    f.render_widget(Clear, panel_area);
+    f.render_widget(widget, panel_area);
+}
+
+pub async fn handle_controller_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
+    match key.code {
+        KeyCode::Char('r') => app.refresh_controller_info(),
+        KeyCode::Esc => {
+            app.show_controller = false;
+        }
+        _ => {}
+    }
+    Ok(())
+}
@@ -0,0 +1,277 @@
+use crate::app::App;
+use crate::probe::format_latency;
+use crate::ui::widgets::format_network_speed;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Sparkline, Table};
+use ratatui::Frame;
+use std::collections::HashMap;
+use unifi_rs::device::DeviceState;
+use unifi_rs::models::client::ClientOverview;
+use uuid::Uuid;
+
+const TOP_AP_COUNT: usize = 5;
+
+/// One screen answering "is everything ok": WAN, offline devices and alerts across the
+/// top, the busiest access points, and recent link speed, unless the config file
+/// lays it out differently.
+pub fn render_dashboard(f: &mut Frame, app: &App, area: Rect) {
+    let share = app.pane_ratios.get("dashboard");
+    app.dashboard_layout.render(f, app, area, share);
+}
+
+fn offline_devices(app: &App) -> Vec<&str> {
+    app.state
+        .devices
+        .iter()
+        .filter(|d| !matches!(d.state, DeviceState::Online))
+        .map(|d| d.name.as_str())
+        .collect()
+}
+
+pub(super) fn render_verdict(f: &mut Frame, app: &App, area: Rect) {
+    let offline = offline_devices(app).len();
+    let alerts = app.alerts.unacknowledged_count();
+    let site_errors = app.state.site_errors.len();
+
+    let mut problems = Vec::new();
+    if offline > 0 {
+        problems.push(format!("{} devices offline", offline));
+    }
+    if alerts > 0 {
+        problems.push(format!("{} unacknowledged alerts", alerts));
+    }
+    if site_errors > 0 {
+        problems.push(format!("{} sites failed to load", site_errors));
+    }
+
+    let (text, color) = if problems.is_empty() {
+        ("✔ Everything looks OK".to_string(), Color::Green)
+    } else {
+        (format!("✘ {}", problems.join(" · ")), Color::Red)
+    };
+
+    let verdict = Paragraph::new(Line::styled(
+        text,
+        Style::default().fg(color).add_modifier(Modifier::BOLD),
+    ))
+    .block(Block::default().borders(Borders::ALL).title("Status"));
+    f.render_widget(verdict, area);
+}
+
+pub(super) fn render_wan(f: &mut Frame, app: &App, area: Rect) {
+    let gateways: Vec<_> = app
+        .state
+        .devices
+        .iter()
+        .filter(|d| app.state.is_gateway(d.id))
+        .collect();
+
+    let lines: Vec<Line> = if gateways.is_empty() {
+        vec![Line::styled(
+            "No gateway reported",
+            Style::default().fg(Color::DarkGray),
+        )]
+    } else {
+        gateways
+            .iter()
+            .flat_map(|gateway| {
+                let (status, color) = match gateway.state {
+                    DeviceState::Online => ("Online", Color::Green),
+                    DeviceState::Offline => ("Offline", Color::Red),
+                    _ => ("Degraded", Color::Yellow),
+                };
+                let mut lines = vec![Line::from(vec![
+                    Span::styled("● ", Style::default().fg(color)),
+                    Span::styled(
+                        gateway.name.clone(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(format!(" {}", status)),
+                ])];
+                if let Some(uplink) = app
+                    .state
+                    .device_stats
+                    .get(&gateway.id)
+                    .and_then(|stats| stats.uplink.as_ref())
+                {
+                    lines.push(Line::from(format!(
+                        "  ↑ {}  ↓ {}",
+                        format_network_speed(uplink.tx_rate_bps),
+                        format_network_speed(uplink.rx_rate_bps)
+                    )));
+                }
+                if let Some(sample) = app
+                    .state
+                    .latency
+                    .as_ref()
+                    .and_then(|latency| latency.latest(&gateway.id))
+                {
+                    lines.push(Line::from(format!(
+                        "  Latency {}",
+                        format_latency(sample.latency)
+                    )));
+                }
+                lines
+            })
+            .collect()
+    };
+
+    let wan = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("WAN"));
+    f.render_widget(wan, area);
+}
+
+pub(super) fn render_offline_devices(f: &mut Frame, app: &App, area: Rect) {
+    let offline = offline_devices(app);
+    let lines: Vec<Line> = if offline.is_empty() {
+        vec![Line::styled(
+            format!("All {} devices online", app.state.devices.len()),
+            Style::default().fg(Color::Green),
+        )]
+    } else {
+        offline
+            .iter()
+            .map(|name| Line::styled(format!("✘ {}", name), Style::default().fg(Color::Red)))
+            .collect()
+    };
+
+    let title = format!("Devices Offline [{}]", offline.len());
+    let devices = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(devices, area);
+}
+
+pub(super) fn render_alerts(f: &mut Frame, app: &App, area: Rect) {
+    let alerts: Vec<_> = app
+        .alerts
+        .sorted()
+        .into_iter()
+        .filter(|alert| !alert.acknowledged)
+        .collect();
+    let lines: Vec<Line> = if alerts.is_empty() {
+        vec![Line::styled(
+            "No unacknowledged alerts",
+            Style::default().fg(Color::Green),
+        )]
+    } else {
+        alerts
+            .iter()
+            .map(|alert| Line::styled(alert.message.clone(), Style::default().fg(Color::Red)))
+            .collect()
+    };
+
+    let title = format!("Alerts [{}] (W: Manage)", alerts.len());
+    let panel = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(panel, area);
+}
+
+/// Access points with the most wireless clients attached.
+pub(super) fn render_top_aps(f: &mut Frame, app: &App, area: Rect) {
+    let mut counts: HashMap<Uuid, usize> = HashMap::new();
+    for client in &app.state.clients {
+        if let ClientOverview::Wireless(c) = client {
+            *counts.entry(c.uplink_device_id).or_default() += 1;
+        }
+    }
+
+    let mut aps: Vec<_> = app
+        .state
+        .devices
+        .iter()
+        .filter_map(|device| counts.get(&device.id).map(|count| (device, *count)))
+        .collect();
+    aps.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.name.cmp(&b.0.name)));
+
+    let header = Row::new(vec!["Access Point", "Clients", "CPU"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = aps
+        .iter()
+        .take(TOP_AP_COUNT)
+        .map(|(device, count)| {
+            let cpu = app
+                .state
+                .device_stats
+                .get(&device.id)
+                .and_then(|stats| stats.cpu_utilization_pct)
+                .map_or("N/A".to_string(), |cpu| format!("{:.1}%", cpu));
+            Row::new(vec![
+                Cell::from(device.name.clone()),
+                Cell::from(count.to_string()),
+                Cell::from(cpu),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Percentage(60),
+        Constraint::Percentage(20),
+        Constraint::Percentage(20),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title("Top APs"));
+    f.render_widget(table, area);
+}
+
+/// Combined link speed of all devices over the stats history, as download and upload
+/// sparklines.
+pub(super) fn render_throughput(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Network Link Speed");
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.state.stats_history.is_empty() {
+        let placeholder = Paragraph::new(Line::styled(
+            "Collecting data…",
+            Style::default().fg(Color::DarkGray),
+        ));
+        f.render_widget(placeholder, inner);
+        return;
+    }
+
+    let (rx, tx): (Vec<u64>, Vec<u64>) = app
+        .state
+        .stats_history
+        .iter()
+        .map(|stats| {
+            stats.device_stats.iter().fold((0, 0), |(rx, tx), metrics| {
+                (
+                    rx + metrics.rx_rate.unwrap_or(0).max(0) as u64,
+                    tx + metrics.tx_rate.unwrap_or(0).max(0) as u64,
+                )
+            })
+        })
+        .unzip();
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .split(inner);
+
+    for (data, label, color, row) in [
+        (&rx, "↓", Color::Green, rows[0]),
+        (&tx, "↑", Color::Blue, rows[1]),
+    ] {
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)].as_ref())
+            .split(row);
+        let latest = data.last().copied().unwrap_or(0);
+        f.render_widget(
+            Paragraph::new(format!("{} {}", label, format_network_speed(latest as i64))),
+            parts[0],
+        );
+        // Show the most recent samples that fit the width.
+        let visible = &data[data.len().saturating_sub(parts[1].width as usize)..];
+        f.render_widget(
+            Sparkline::default()
+                .data(visible)
+                .style(Style::default().fg(color)),
+            parts[1],
+        );
+    }
+}
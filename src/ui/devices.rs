@@ -1,5 +1,10 @@
+use crate::actions::DeviceAction;
 use crate::app::{App, SortOrder};
-use crate::ui::widgets::format_network_speed;
+use crate::availability::{Availability, BOUNCE_THRESHOLD};
+use crate::probe::format_latency;
+use crate::script::{device_record, ScriptTarget};
+use crate::ui::icons::device_icon;
+use crate::ui::widgets::{format_network_speed, highlight_matches};
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
@@ -7,6 +12,7 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
 use ratatui::Frame;
 use unifi_rs::device::DeviceState;
+use uuid::Uuid;
 
 pub fn render_devices(f: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::default()
@@ -142,7 +148,10 @@ fn get_resource_style(utilization: f64) -> Style {
 }
 
 fn render_device_table(f: &mut Frame, app: &mut App, area: Rect) {
-    let header = Row::new(vec![
+    let latency = app.state.latency.as_ref();
+
+    let mut header_cells = vec![
+        Cell::from(""),
         Cell::from("Name").style(Style::default().add_modifier(Modifier::BOLD)),
         Cell::from("Model").style(Style::default().add_modifier(Modifier::BOLD)),
         Cell::from("Status").style(Style::default().add_modifier(Modifier::BOLD)),
@@ -151,7 +160,19 @@ fn render_device_table(f: &mut Frame, app: &mut App, area: Rect) {
         Cell::from("TX/RX").style(Style::default().add_modifier(Modifier::BOLD)),
         Cell::from("Firmware").style(Style::default().add_modifier(Modifier::BOLD)),
         Cell::from("Uptime").style(Style::default().add_modifier(Modifier::BOLD)),
-    ]);
+        Cell::from("Avail 24h/7d").style(Style::default().add_modifier(Modifier::BOLD)),
+    ];
+    if latency.is_some() {
+        header_cells
+            .push(Cell::from("Latency").style(Style::default().add_modifier(Modifier::BOLD)));
+    }
+    let columns = app.scripts.columns(ScriptTarget::Device);
+    for column in columns {
+        header_cells.push(
+            Cell::from(column.name.clone()).style(Style::default().add_modifier(Modifier::BOLD)),
+        );
+    }
+    let header = Row::new(header_cells);
 
     let rows: Vec<Row> = app
         .state
@@ -192,9 +213,11 @@ fn render_device_table(f: &mut Frame, app: &mut App, area: Rect) {
                 }
             });
 
-            Row::new(vec![
-                Cell::from(device.name.clone()),
-                Cell::from(device.model.clone()),
+            let mut cells = vec![
+                Cell::from(device_icon(&app.state, device, app.icons))
+                    .style(get_status_style(&device.state)),
+                Cell::from(highlight_matches(device.name.clone(), &app.search_query)),
+                Cell::from(highlight_matches(device.model.clone(), &app.search_query)),
                 Cell::from(format!("{:?}", device.state)).style(get_status_style(&device.state)),
                 Cell::from(cpu_text).style(
                     stats
@@ -209,22 +232,58 @@ fn render_device_table(f: &mut Frame, app: &mut App, area: Rect) {
                 Cell::from(network_text),
                 Cell::from(details.map_or("N/A".to_string(), |d| d.firmware_version.clone())),
                 Cell::from(uptime_text),
-            ])
+                availability_cell(&app.state.availability, device.id),
+            ];
+            if let Some(monitor) = latency {
+                cells.push(match monitor.latest(&device.id) {
+                    Some(sample) => Cell::from(format_latency(sample.latency))
+                        .style(get_latency_style(sample.latency)),
+                    None => Cell::from("..."),
+                });
+            }
+            if !columns.is_empty() {
+                let record = device_record(&app.state, device);
+                cells.extend(
+                    columns
+                        .iter()
+                        .map(|column| Cell::from(column.cell(&record))),
+                );
+            }
+            Row::new(cells)
         })
         .collect();
 
-    let widths = [
-        Constraint::Percentage(20), // Name
-        Constraint::Percentage(15), // Model
-        Constraint::Percentage(10), // Status
-        Constraint::Percentage(10), // CPU
-        Constraint::Percentage(10), // Memory
-        Constraint::Percentage(15), // Network
-        Constraint::Percentage(10), // Firmware
-        Constraint::Percentage(10), // Uptime
-    ];
+    let mut widths = if latency.is_some() {
+        vec![
+            Constraint::Length(2),      // Icon
+            Constraint::Percentage(13), // Name
+            Constraint::Percentage(9),  // Model
+            Constraint::Percentage(8),  // Status
+            Constraint::Percentage(8),  // CPU
+            Constraint::Percentage(8),  // Memory
+            Constraint::Percentage(13), // Network
+            Constraint::Percentage(8),  // Firmware
+            Constraint::Percentage(8),  // Uptime
+            Constraint::Percentage(16), // Availability
+            Constraint::Percentage(9),  // Latency
+        ]
+    } else {
+        vec![
+            Constraint::Length(2),      // Icon
+            Constraint::Percentage(15), // Name
+            Constraint::Percentage(11), // Model
+            Constraint::Percentage(8),  // Status
+            Constraint::Percentage(9),  // CPU
+            Constraint::Percentage(9),  // Memory
+            Constraint::Percentage(14), // Network
+            Constraint::Percentage(9),  // Firmware
+            Constraint::Percentage(9),  // Uptime
+            Constraint::Percentage(16), // Availability
+        ]
+    };
+    widths.extend(columns.iter().map(|_| Constraint::Length(12)));
 
-    let title = match &app.state.selected_site {
+    let mut title = match &app.state.selected_site {
         Some(site) => format!(
             "Devices - {} [{}]",
             site.site_name,
@@ -232,6 +291,9 @@ fn render_device_table(f: &mut Frame, app: &mut App, area: Rect) {
         ),
         None => format!("All Devices [{}]", app.state.filtered_devices.len()),
     };
+    if let Some(filter) = &app.state.device_filter {
+        title.push_str(&format!(" - {}", filter.name));
+    }
 
     let table = Table::new(rows, widths)
         .header(header)
@@ -242,6 +304,48 @@ fn render_device_table(f: &mut Frame, app: &mut App, area: Rect) {
     f.render_stateful_widget(table, area, &mut app.devices_table_state);
 }
 
+/// Share of the last day the device was seen online as a five-cell bar, followed by
+/// the day and week percentages. Devices that keep dropping off are flagged.
+fn availability_cell(availability: &Availability, device_id: Uuid) -> Cell<'static> {
+    let Some(day) = availability.percent(device_id, chrono::Duration::hours(24)) else {
+        return Cell::from("...");
+    };
+    let week = availability
+        .percent(device_id, chrono::Duration::days(7))
+        .unwrap_or(day);
+    let bounces = availability.bounces(device_id, chrono::Duration::hours(24));
+
+    let filled = ((day / 20.0).round() as usize).min(5);
+    let mut text = format!(
+        "{}{} {:.1}%/{:.0}%",
+        "█".repeat(filled),
+        "░".repeat(5 - filled),
+        day,
+        week
+    );
+    let style = if bounces >= BOUNCE_THRESHOLD {
+        text.push_str(&format!(" ↯{}", bounces));
+        Style::default()
+            .fg(Color::Magenta)
+            .add_modifier(Modifier::BOLD)
+    } else if day >= 99.0 {
+        Style::default().fg(Color::Green)
+    } else if day >= 95.0 {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::Red)
+    };
+    Cell::from(text).style(style)
+}
+
+fn get_latency_style(latency: Option<std::time::Duration>) -> Style {
+    match latency.map(|l| l.as_millis()) {
+        Some(ms) if ms < 50 => Style::default().fg(Color::Green),
+        Some(ms) if ms < 200 => Style::default().fg(Color::Yellow),
+        _ => Style::default().fg(Color::Red),
+    }
+}
+
 fn sparkline(mem: f64) -> String {
     let sparkline = match mem {
         m if m >= 90.0 => "█",
@@ -259,7 +363,13 @@ fn render_device_controls(f: &mut Frame, area: Rect) {
         Span::raw("Enter: Details  "),
         Span::raw("s: Sort  "),
         Span::raw("/: Search  "),
+        Span::raw("f: Filter  "),
         Span::raw("r: Restart  "),
+        Span::raw("u: Updates  "),
+        Span::raw("S: Schedule  "),
+        Span::raw("t: Traceroute  "),
+        Span::raw("x: SSH  "),
+        Span::raw("w: Web UI  "),
         Span::raw("ESC: Back"),
     ])];
 
@@ -317,19 +427,25 @@ pub async fn handle_device_input(app: &mut App, key: KeyEvent) -> anyhow::Result
                 if let Some(device) = app.state.filtered_devices.get(idx).cloned() {
                     if let Some(site) = app.state.selected_site.clone() {
                         let device_name = device.name.clone();
+                        let dialog_type = if app.state.is_gateway(device.id) {
+                            crate::app::DialogType::TypedConfirmation {
+                                expected: device_name.clone(),
+                            }
+                        } else {
+                            crate::app::DialogType::Confirmation
+                        };
                         app.dialog = Some(crate::app::Dialog {
                             title: "Confirm Device Restart".to_string(),
                             message: format!("Are you sure you want to restart {}?", device_name),
-                            dialog_type: crate::app::DialogType::Confirmation,
+                            dialog_type,
+                            input: String::new(),
                             callback: Some(Box::new(move |app| {
-                                let client = app.state.client.clone();
-                                let site_id = site.site_id;
-                                tokio::spawn(async move {
-                                    if let Err(e) = client.restart_device(site_id, device.id).await
-                                    {
-                                        eprintln!("Failed to restart device: {}", e);
-                                    }
-                                });
+                                app.spawn_device_action(
+                                    site.site_id,
+                                    device.id,
+                                    device.name,
+                                    DeviceAction::Restart,
+                                );
                                 Ok(())
                             })),
                         });
@@ -337,6 +453,52 @@ pub async fn handle_device_input(app: &mut App, key: KeyEvent) -> anyhow::Result
                 }
             }
         }
+        KeyCode::Char('u') => {
+            app.show_firmware_updates();
+        }
+        KeyCode::Char('f') => {
+            app.cycle_script_filter(ScriptTarget::Device);
+        }
+        KeyCode::Char('w') => {
+            if let Some(device_id) = app
+                .devices_table_state
+                .selected()
+                .and_then(|idx| app.state.filtered_devices.get(idx))
+                .map(|d| d.id)
+            {
+                app.open_device_in_browser(device_id);
+            }
+        }
+        KeyCode::Char('x') => {
+            if let Some(device_id) = app
+                .devices_table_state
+                .selected()
+                .and_then(|idx| app.state.filtered_devices.get(idx))
+                .map(|d| d.id)
+            {
+                app.request_ssh(device_id);
+            }
+        }
+        KeyCode::Char('t') => {
+            if let Some(device) = app
+                .devices_table_state
+                .selected()
+                .and_then(|idx| app.state.filtered_devices.get(idx))
+            {
+                let (target, label) = (device.ip_address.clone(), device.name.clone());
+                app.open_traceroute(target, label);
+            }
+        }
+        KeyCode::Char('S') => {
+            if let Some(device_id) = app
+                .devices_table_state
+                .selected()
+                .and_then(|idx| app.state.filtered_devices.get(idx))
+                .map(|d| d.id)
+            {
+                app.open_schedule_prompt(device_id);
+            }
+        }
         KeyCode::Esc => {
             app.back_to_overview();
         }
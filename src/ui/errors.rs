@@ -0,0 +1,68 @@
+use crate::app::App;
+use crate::ui::centered_rect;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+pub fn render_error_console(f: &mut Frame, app: &App, area: Rect) {
+    let console_area = centered_rect(80, area.height.saturating_sub(6), area);
+
+    let lines: Vec<Line> = if app.state.error_log.is_empty() {
+        vec![Line::from("No errors recorded this session")]
+    } else {
+        app.state
+            .error_log
+            .iter()
+            .rev()
+            .flat_map(|entry| {
+                [
+                    Line::from(vec![
+                        Span::styled(
+                            entry.timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+                            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                        ),
+                        Span::raw("  "),
+                        Span::raw(entry.message.clone()),
+                    ]),
+                    Line::from(""),
+                ]
+            })
+            .collect()
+    };
+
+    let title = format!(
+        "Error Console [{}] (↑/↓: Scroll | c: Clear | Esc: Close)",
+        app.state.error_log.len()
+    );
+
+    let console = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .scroll((app.error_scroll, 0))
+        .block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(Clear, console_area);
+    f.render_widget(console, console_area);
+}
+
+pub async fn handle_error_console_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
+    match key.code {
+        KeyCode::Down => {
+            app.error_scroll = app.error_scroll.saturating_add(1);
+        }
+        KeyCode::Up => {
+            app.error_scroll = app.error_scroll.saturating_sub(1);
+        }
+        KeyCode::Char('c') => {
+            app.state.error_log.clear();
+            app.error_scroll = 0;
+        }
+        KeyCode::Esc => {
+            app.show_errors = false;
+        }
+        _ => {}
+    }
+    Ok(())
+}
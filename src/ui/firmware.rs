@@ -0,0 +1,119 @@
+use crate::app::App;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
+use ratatui::Frame;
+use uuid::Uuid;
+
+pub fn render_firmware_updates(f: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(area);
+
+    let pending = app.state.pending_firmware_updates();
+
+    let header = Row::new(vec![
+        Cell::from("Name").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Model").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("IP").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Current Firmware").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Site").style(Style::default().add_modifier(Modifier::BOLD)),
+    ]);
+
+    let rows: Vec<Row> = pending
+        .iter()
+        .map(|device| {
+            let firmware = app
+                .state
+                .device_details
+                .get(&device.id)
+                .map_or("N/A".to_string(), |d| d.firmware_version.clone());
+            let site = app
+                .state
+                .device_sites
+                .get(&device.id)
+                .and_then(|site_id| app.state.sites.iter().find(|s| s.id == *site_id))
+                .and_then(|s| s.name.clone())
+                .unwrap_or_else(|| "Unknown".to_string());
+
+            Row::new(vec![
+                Cell::from(device.name.clone()),
+                Cell::from(device.model.clone()),
+                Cell::from(device.ip_address.clone()),
+                Cell::from(firmware).style(Style::default().fg(Color::Yellow)),
+                Cell::from(site),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Percentage(25),
+        Constraint::Percentage(15),
+        Constraint::Percentage(15),
+        Constraint::Percentage(20),
+        Constraint::Percentage(25),
+    ];
+
+    let title = format!("Pending Firmware Updates [{}]", pending.len());
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .row_highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol("➤ ");
+
+    f.render_stateful_widget(table, chunks[0], &mut app.firmware_table_state);
+
+    let help_text = vec![Line::from(
+        "↑/↓: Select | ESC: Back | Upgrades are started from the controller web UI",
+    )];
+    let help =
+        Paragraph::new(help_text).block(Block::default().borders(Borders::ALL).title("Controls"));
+    f.render_widget(help, chunks[1]);
+}
+
+pub async fn handle_firmware_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
+    let pending: Vec<Uuid> = app
+        .state
+        .pending_firmware_updates()
+        .iter()
+        .map(|d| d.id)
+        .collect();
+
+    match key.code {
+        KeyCode::Down => {
+            let i = match app.firmware_table_state.selected() {
+                Some(i) => {
+                    if i >= pending.len().saturating_sub(1) {
+                        0
+                    } else {
+                        i + 1
+                    }
+                }
+                None => 0,
+            };
+            app.firmware_table_state.select(Some(i));
+        }
+        KeyCode::Up => {
+            let i = match app.firmware_table_state.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        pending.len().saturating_sub(1)
+                    } else {
+                        i - 1
+                    }
+                }
+                None => 0,
+            };
+            app.firmware_table_state.select(Some(i));
+        }
+        KeyCode::Esc => {
+            app.back_to_overview();
+        }
+        _ => {}
+    }
+    Ok(())
+}
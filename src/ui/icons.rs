@@ -0,0 +1,44 @@
+use crate::state::AppState;
+use clap::ValueEnum;
+use ratatui::style::Color;
+use unifi_rs::device::DeviceOverview;
+use unifi_rs::models::client::ClientOverview;
+
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum IconStyle {
+    /// Two-letter tags that render in any font
+    #[default]
+    Ascii,
+    /// Glyphs from a patched Nerd Font
+    NerdFont,
+}
+
+pub fn device_icon(state: &AppState, device: &DeviceOverview, style: IconStyle) -> &'static str {
+    let (ascii, nerd) = if state.is_gateway(device.id) {
+        ("GW", "\u{f0ac}")
+    } else if device.features.contains(&"accessPoint".to_string()) {
+        ("AP", "\u{f1eb}")
+    } else if device.features.contains(&"switching".to_string()) {
+        ("SW", "\u{f0e8}")
+    } else {
+        ("--", "\u{f233}")
+    };
+    match style {
+        IconStyle::Ascii => ascii,
+        IconStyle::NerdFont => nerd,
+    }
+}
+
+// The Integration API does not expose client fingerprints (OS or device class),
+// so clients are told apart by how they connect.
+pub fn client_icon(client: &ClientOverview, style: IconStyle) -> (&'static str, Color) {
+    let (ascii, nerd, color) = match client {
+        ClientOverview::Wired(_) => ("WR", "\u{f108}", Color::Blue),
+        ClientOverview::Wireless(_) => ("WL", "\u{f10b}", Color::Yellow),
+        _ => ("??", "\u{f023}", Color::Red),
+    };
+    match style {
+        IconStyle::Ascii => (ascii, color),
+        IconStyle::NerdFont => (nerd, color),
+    }
+}
@@ -0,0 +1,257 @@
+use crate::app::App;
+use crate::audit::AuditResult;
+use crate::scheduler::{format_countdown, parse_run_at, ScheduledJob};
+use crate::tasks::JobStatus;
+use crate::ui::centered_rect;
+use chrono::Local;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table};
+use ratatui::Frame;
+use uuid::Uuid;
+
+pub fn render_schedule_prompt(f: &mut Frame, app: &App, area: Rect) {
+    if let Some(prompt) = &app.schedule_prompt {
+        let prompt_area = centered_rect(60, 6, area);
+
+        let valid = parse_run_at(&prompt.input, Local::now()).is_some();
+        let input_style = if valid || prompt.input.is_empty() {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Red)
+        };
+
+        let text = vec![
+            Line::from(vec![
+                Span::raw("Action: "),
+                Span::styled(
+                    prompt.action.label(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                Span::raw("Run at (HH:MM): "),
+                Span::styled(prompt.input.clone(), input_style),
+            ]),
+            Line::from(""),
+            Line::from("Enter: Schedule | Esc: Cancel"),
+        ];
+
+        let widget = Paragraph::new(text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Schedule Action - {}", prompt.device_name)),
+        );
+
+        f.render_widget(Clear, prompt_area);
+        f.render_widget(widget, prompt_area);
+    }
+}
+
+pub async fn handle_schedule_prompt_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
+    let Some(prompt) = app.schedule_prompt.as_mut() else {
+        return Ok(());
+    };
+
+    match key.code {
+        KeyCode::Esc => {
+            app.schedule_prompt = None;
+        }
+        KeyCode::Char(c) if (c.is_ascii_digit() || c == ':') && prompt.input.len() < 5 => {
+            prompt.input.push(c);
+        }
+        KeyCode::Backspace => {
+            prompt.input.pop();
+        }
+        KeyCode::Enter => {
+            if let Some(run_at) = parse_run_at(&prompt.input, Local::now()) {
+                if let Some(prompt) = app.schedule_prompt.take() {
+                    app.audit.record(
+                        &format!("Schedule {}", prompt.action.label().to_lowercase()),
+                        &format!(
+                            "{} at {}",
+                            prompt.device_name,
+                            run_at.format("%Y-%m-%d %H:%M")
+                        ),
+                        AuditResult::Success,
+                    );
+                    app.scheduler.schedule(ScheduledJob {
+                        id: Uuid::new_v4(),
+                        site_id: prompt.site_id,
+                        device_id: prompt.device_id,
+                        device_name: prompt.device_name,
+                        action: prompt.action,
+                        run_at,
+                    });
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+pub fn render_jobs_panel(f: &mut Frame, app: &mut App, area: Rect) {
+    let panel_area = centered_rect(70, 26, area);
+    let now = Local::now();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(13), Constraint::Min(0)])
+        .split(panel_area);
+
+    f.render_widget(Clear, panel_area);
+    render_task_jobs(f, app, chunks[0]);
+
+    let header = Row::new(vec!["Run At", "Countdown", "Action", "Device"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = app
+        .scheduler
+        .jobs()
+        .iter()
+        .map(|job| {
+            Row::new(vec![
+                Cell::from(job.run_at.format("%Y-%m-%d %H:%M").to_string()),
+                Cell::from(format_countdown(job.run_at, now)),
+                Cell::from(job.action.label()),
+                Cell::from(job.device_name.clone()),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Percentage(30),
+        Constraint::Percentage(20),
+        Constraint::Percentage(20),
+        Constraint::Percentage(30),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Scheduled Jobs (d d: Cancel | Esc: Close)"),
+        )
+        .row_highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol("➤ ");
+
+    f.render_stateful_widget(table, chunks[1], &mut app.jobs_table_state);
+}
+
+/// Actions spawned on the task runner, newest first, with how each one ended.
+fn render_task_jobs(f: &mut Frame, app: &App, area: Rect) {
+    let header = Row::new(vec!["Started", "Job", "Status"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> =
+        app.tasks
+            .jobs()
+            .iter()
+            .rev()
+            .map(|job| {
+                let status = match &job.status {
+                    JobStatus::Running => {
+                        let elapsed = Local::now().signed_duration_since(job.started_at);
+                        Cell::from(format!("Running ({}s)", elapsed.num_seconds()))
+                            .style(Style::default().fg(Color::Yellow))
+                    }
+                    JobStatus::Succeeded(message) => {
+                        Cell::from(message.clone()).style(Style::default().fg(Color::Green))
+                    }
+                    JobStatus::Failed(error) => Cell::from(format!("Failed: {}", error))
+                        .style(Style::default().fg(Color::Red)),
+                };
+                Row::new(vec![
+                    Cell::from(job.started_at.format("%H:%M:%S").to_string()),
+                    Cell::from(job.label.clone()),
+                    status,
+                ])
+            })
+            .collect();
+
+    let widths = [
+        Constraint::Length(10),
+        Constraint::Percentage(35),
+        Constraint::Min(20),
+    ];
+
+    let title = format!("Jobs [{} running]", app.tasks.running_count());
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(table, area);
+}
+
+pub async fn handle_jobs_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
+    let job_count = app.scheduler.jobs().len();
+
+    match key.code {
+        KeyCode::Down => {
+            let i = match app.jobs_table_state.selected() {
+                Some(i) => {
+                    if i >= job_count.saturating_sub(1) {
+                        0
+                    } else {
+                        i + 1
+                    }
+                }
+                None => 0,
+            };
+            app.jobs_table_state.select(Some(i));
+        }
+        KeyCode::Up => {
+            let i = match app.jobs_table_state.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        job_count.saturating_sub(1)
+                    } else {
+                        i - 1
+                    }
+                }
+                None => 0,
+            };
+            app.jobs_table_state.select(Some(i));
+        }
+        KeyCode::Char('d') | KeyCode::Delete => {
+            if let Some((job_id, prompt)) = app
+                .jobs_table_state
+                .selected()
+                .and_then(|idx| app.scheduler.jobs().get(idx))
+                .map(|job| {
+                    let prompt = format!(
+                        "Press d again to cancel the scheduled {} of {}",
+                        job.action.label().to_lowercase(),
+                        job.device_name
+                    );
+                    (job.id, prompt)
+                })
+            {
+                if !app.confirm_inline('d', job_id, prompt) {
+                    return Ok(());
+                }
+                if let Some(job) = app.scheduler.cancel(job_id) {
+                    app.audit.record(
+                        &format!("Cancel scheduled {}", job.action.label().to_lowercase()),
+                        &job.device_name,
+                        AuditResult::Success,
+                    );
+                }
+                let remaining = app.scheduler.jobs().len();
+                if remaining == 0 {
+                    app.jobs_table_state.select(None);
+                } else if app.jobs_table_state.selected() >= Some(remaining) {
+                    app.jobs_table_state.select(Some(remaining - 1));
+                }
+            }
+        }
+        KeyCode::Esc => {
+            app.show_jobs = false;
+        }
+        _ => {}
+    }
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use crate::app::App;
+use crate::ui::centered_rect;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+/// Blanks the screen so nothing about the network is visible while locked.
+pub fn render_lock_screen(f: &mut Frame, app: &App, area: Rect) {
+    f.render_widget(Clear, area);
+
+    let Some(screen) = &app.lock_screen else {
+        return;
+    };
+    let has_passphrase = app
+        .idle_lock
+        .as_ref()
+        .is_some_and(|lock| lock.passphrase.is_some());
+
+    let mut text = vec![Line::from("")];
+    if has_passphrase {
+        text.push(Line::from("Enter the passphrase to unlock:"));
+        text.push(Line::styled(
+            format!("> {}", "*".repeat(screen.input.chars().count())),
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        if screen.failed {
+            text.push(Line::styled(
+                "Wrong passphrase",
+                Style::default().fg(Color::Red),
+            ));
+        }
+    } else {
+        text.push(Line::from("Press any key to unlock"));
+    }
+
+    let widget = Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).title("Locked"));
+
+    f.render_widget(widget, centered_rect(50, 7, area));
+}
+
+pub fn handle_lock_input(app: &mut App, key: KeyEvent) {
+    let has_passphrase = app
+        .idle_lock
+        .as_ref()
+        .is_some_and(|lock| lock.passphrase.is_some());
+    let Some(screen) = app.lock_screen.as_mut() else {
+        return;
+    };
+    if !has_passphrase {
+        app.try_unlock();
+        return;
+    }
+
+    match key.code {
+        KeyCode::Char(c) => screen.input.push(c),
+        KeyCode::Backspace => {
+            screen.input.pop();
+        }
+        KeyCode::Esc => screen.input.clear(),
+        KeyCode::Enter => app.try_unlock(),
+        _ => {}
+    }
+}
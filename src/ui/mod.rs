@@ -1,16 +1,40 @@
+pub mod accessible;
+pub mod activity;
+pub mod alerts;
+pub mod breadcrumb;
 pub mod clients;
+pub mod controller;
+pub mod dashboard;
 pub mod devices;
+pub mod errors;
+pub mod firmware;
+pub mod icons;
+pub mod jobs;
+pub mod lock;
+pub mod page;
+pub mod panels;
+pub mod reauth;
+pub mod row_popup;
+pub mod site_dashboard;
+pub mod site_switcher;
 pub mod sites;
 pub mod stats;
 pub mod status_bar;
+#[cfg(test)]
+mod tests;
+pub mod toast;
 pub mod topology;
+pub mod traceroute;
 pub mod widgets;
 
-use crate::app::{App, DialogType, Mode};
-use crate::ui::topology::topology::render_topology;
+use crate::app::{App, DialogType, Mode, Tab};
 use crate::ui::{
-    clients::render_clients, devices::render_devices, sites::render_sites, stats::render_stats,
-    status_bar::render_status_bar,
+    activity::render_activity_panel, alerts::render_alerts_panel, breadcrumb::render_breadcrumb,
+    clients::render_alias_prompt, controller::render_controller_panel,
+    errors::render_error_console, jobs::render_jobs_panel, jobs::render_schedule_prompt,
+    lock::render_lock_screen, reauth::render_reauth_prompt, row_popup::render_row_popup,
+    site_switcher::render_site_switcher, status_bar::render_status_bar, toast::render_toasts,
+    traceroute::render_traceroute_panel,
 };
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::prelude::Alignment;
@@ -22,11 +46,72 @@ use ratatui::Frame;
 pub fn render(app: &mut App, f: &mut Frame) {
     let size = f.area();
 
+    if app.lock_screen.is_some() {
+        render_lock_screen(f, app, size);
+        return;
+    }
+
+    if !(app.chart_fullscreen && render_fullscreen_chart(f, app, size)) {
+        render_screen(f, app, size);
+    }
+
+    if app.show_jobs {
+        render_jobs_panel(f, app, size);
+    }
+    if app.show_alerts {
+        render_alerts_panel(f, app, size);
+    }
+    if app.show_activity {
+        render_activity_panel(f, app, size);
+    }
+    if app.traceroute.is_some() {
+        render_traceroute_panel(f, app, size);
+    }
+    if app.show_controller {
+        render_controller_panel(f, app, size);
+    }
+    if app.show_errors {
+        render_error_console(f, app, size);
+    }
+    if app.site_switcher.is_some() {
+        render_site_switcher(f, app, size);
+    }
+    if app.schedule_prompt.is_some() {
+        render_schedule_prompt(f, app, size);
+    }
+    if app.alias_prompt.is_some() {
+        render_alias_prompt(f, app, size);
+    }
+    if app.reauth_prompt.is_some() {
+        render_reauth_prompt(f, app, size);
+    }
+
+    render_toasts(f, app, size);
+}
+
+/// Tabs, the breadcrumb, the current view and the status bar.
+fn render_screen(f: &mut Frame, app: &mut App, size: Rect) {
+    if app.kiosk.is_some() {
+        render_kiosk(f, app, size);
+        return;
+    }
+    if app.screen_reader
+        && app.dialog.is_none()
+        && !app.show_help
+        && accessible::render_accessible(f, app, size)
+    {
+        if app.search_mode {
+            render_search(f, app, size);
+        }
+        return;
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints(
             [
                 Constraint::Length(3), // Tabs
+                Constraint::Length(1), // Breadcrumb
                 Constraint::Min(0),    // Content
                 Constraint::Length(1), // Status bar
             ]
@@ -35,44 +120,62 @@ pub fn render(app: &mut App, f: &mut Frame) {
         .split(size);
 
     render_tabs(f, app, chunks[0]);
+    render_breadcrumb(f, app, chunks[1]);
 
     if app.dialog.is_some() {
         render_dialog(f, app, size);
     } else if app.show_help {
-        render_help(f, app, chunks[1]);
-    } else if app.search_mode {
-        match app.mode {
-            Mode::Overview => render_overview(f, app, chunks[1]),
-            Mode::DeviceDetail => render_device_detail(f, app, chunks[1]),
-            Mode::ClientDetail => render_client_detail(f, app, chunks[1]),
-            Mode::Help => render_help(f, app, chunks[1]),
-        }
-        render_search(f, app, size);
+        render_help(f, app, chunks[2]);
     } else {
-        match app.mode {
-            Mode::Overview => render_overview(f, app, chunks[1]),
-            Mode::DeviceDetail => render_device_detail(f, app, chunks[1]),
-            Mode::ClientDetail => render_client_detail(f, app, chunks[1]),
-            Mode::Help => render_help(f, app, chunks[1]),
+        if let Some(page) = page::current(app) {
+            page.render(f, app, chunks[2]);
+        }
+        if app.show_row_popup {
+            render_row_popup(f, app, chunks[2]);
+        }
+        if app.search_mode {
+            render_search(f, app, size);
         }
     }
 
-    render_status_bar(f, app, chunks[2]);
+    render_status_bar(f, app, chunks[3]);
+}
 
-    if let Some(error) = &app.state.error_message {
-        if let Some(timestamp) = app.state.error_timestamp {
-            if timestamp.elapsed() < std::time::Duration::from_secs(5) {
-                render_error(f, error, size);
-            }
+/// The current view and the status bar only, leaving the rest of the screen to the
+/// view on a wallboard.
+fn render_kiosk(f: &mut Frame, app: &mut App, size: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)].as_ref())
+        .split(size);
+
+    if let Some(page) = page::for_tab(app.current_tab) {
+        page.render(f, app, chunks[0]);
+    }
+    render_status_bar(f, app, chunks[1]);
+}
+
+/// Draws the focused chart of the current view over the whole terminal. Returns
+/// false when the view has no charts, so the normal layout is drawn instead.
+fn render_fullscreen_chart(f: &mut Frame, app: &App, area: Rect) -> bool {
+    match app.mode {
+        Mode::Overview if app.current_tab == Tab::Stats => {
+            stats::render_focused_chart(f, app, area)
         }
+        Mode::DeviceDetail => match &app.device_stats_view {
+            Some(view) => view.render_focused_chart(f, area, &app.state),
+            None => return false,
+        },
+        _ => return false,
     }
+    true
 }
 
 fn render_tabs(f: &mut Frame, app: &App, area: Rect) {
-    let titles = ["Sites", "Devices", "Clients", "Topology", "Stats"];
-    let tabs = Tabs::new(titles.iter().map(|t| Line::from(*t)).collect::<Vec<_>>())
+    let titles = Tab::ALL.iter().map(|tab| Line::from(tab.title()));
+    let tabs = Tabs::new(titles)
         .block(Block::default().borders(Borders::ALL).title("Tabs"))
-        .select(app.current_tab)
+        .select(app.current_tab.index())
         .highlight_style(
             Style::default()
                 .add_modifier(Modifier::BOLD)
@@ -81,46 +184,34 @@ fn render_tabs(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(tabs, area);
 }
 
-fn render_overview(f: &mut Frame, app: &mut App, area: Rect) {
-    match app.current_tab {
-        0 => render_sites(f, app, area),
-        1 => render_devices(f, app, area),
-        2 => render_clients(f, app, area),
-        3 => render_topology(f, app, area),
-        4 => render_stats(f, app, area),
-        _ => unreachable!(),
-    }
-}
-
-fn render_device_detail(f: &mut Frame, app: &App, area: Rect) {
-    if let Some(_device_id) = app.selected_device_id {
-        if let Some(view) = &app.device_stats_view {
-            view.render(f, area, &app.state);
-        }
-    }
-}
-fn render_client_detail(f: &mut Frame, app: &App, area: Rect) {
-    if let Some(client_id) = app.selected_client_id {
-        widgets::client_stats::ClientStatsView::new(client_id, &app.state).render(f, area);
-    }
-}
-
 pub fn render_dialog(f: &mut Frame, app: &mut App, area: Rect) {
     if let Some(dialog) = &app.dialog {
         let dialog_area = centered_rect(60, 15, area);
 
         f.render_widget(Clear, dialog_area);
 
-        let text = vec![
+        let mut text = vec![
             Line::from(""),
             Line::from(dialog.message.clone()),
             Line::from(""),
-            Line::from(match dialog.dialog_type {
-                DialogType::Confirmation => "(y) Confirm  (n) Cancel",
-                DialogType::Message => "Press any key to close",
-                DialogType::Error => "Press any key to close",
-            }),
         ];
+        match &dialog.dialog_type {
+            DialogType::Confirmation => text.push(Line::from("(y) Confirm  (n) Cancel")),
+            DialogType::TypedConfirmation { expected } => {
+                text.push(Line::from(format!("Type '{}' to confirm:", expected)));
+                let input_style = if dialog.input == *expected {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default().add_modifier(Modifier::BOLD)
+                };
+                text.push(Line::styled(format!("> {}", dialog.input), input_style));
+                text.push(Line::from(""));
+                text.push(Line::from("(Enter) Confirm  (Esc) Cancel"));
+            }
+            DialogType::Message | DialogType::Error => {
+                text.push(Line::from("Press any key to close"))
+            }
+        }
 
         let dialog_widget = Paragraph::new(text)
             .block(
@@ -141,54 +232,91 @@ fn render_search(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(Clear, search_area);
     f.render_widget(shadow_block, search_area);
 
+    let matches = app.search_match_count();
     let search_text = Paragraph::new(app.search_query.as_str())
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_style(Style::default())
-                .title("Search (Esc to close)"),
+                .title(format!(
+                    "Search - {} {} (↑/↓ history, Esc to close)",
+                    matches,
+                    if matches == 1 { "match" } else { "matches" }
+                )),
         )
         .style(Style::default());
 
     f.render_widget(search_text, search_area);
 }
 
-fn render_error(f: &mut Frame, error: &str, area: Rect) {
-    let area = centered_rect(60, 15, area);
-    let error_widget = Paragraph::new(error)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default())
-                .title("Error"),
-        )
-        .style(Style::default());
-    f.render_widget(Clear, area);
-    f.render_widget(error_widget, area);
-}
-
-fn render_help(f: &mut Frame, app: &App, area: Rect) {
+pub(super) fn render_help(f: &mut Frame, app: &App, area: Rect) {
     let help_text = match app.mode {
         Mode::Overview => {
             match app.current_tab {
-                0 => vec![
+                Tab::Dashboard => vec![
+                    // Dashboard tab
+                    Line::from("UniFi Network TUI Help - Dashboard"),
+                    Line::from(""),
+                    Line::from("Global Commands:"),
+                    Line::from("  q      - Quit application"),
+                    Line::from("  ?      - Toggle this help screen"),
+                    Line::from("  Tab    - Next view"),
+                    Line::from("  S-Tab  - Previous view"),
+                    Line::from("  F5     - Force refresh data"),
+                    Line::from("  J      - Show running, recent and scheduled jobs"),
+                    Line::from("  W      - Show alerts (a: acknowledge)"),
+                    Line::from("  A      - Show activity (audit log)"),
+                    Line::from("  I      - Show controller info"),
+                    Line::from("  R      - Export health report"),
+                    Line::from("  P      - Save screen snapshot (.txt and .ans)"),
+                    Line::from("  !      - Show error console"),
+                    Line::from("  M      - Toggle maintenance mode (selected site or all sites)"),
+                    Line::from("  D      - Show the dashboard of all sites"),
+                    Line::from("  Ctrl+S - Switch site from anywhere"),
+                    Line::from("  Ctrl+Z - Undo the last tab, search or dashboard change"),
+                    Line::from("  1-9    - Switch to a favorite site"),
+                    Line::from("  </>    - Shrink/grow the panels above the charts"),
+                    Line::from(""),
+                    Line::from("Dashboard Information:"),
+                    Line::from("  - Summarises the WAN, offline devices and open alerts"),
+                    Line::from("  - Lists the access points with the most wireless clients"),
+                    Line::from("  - Link speed sparklines cover the stats history"),
+                ],
+                Tab::Sites => vec![
                     // Sites tab
                     Line::from("UniFi Network TUI Help - Sites View"),
                     Line::from(""),
                     Line::from("Global Commands:"),
                     Line::from("  q      - Quit application"),
                     Line::from("  ?      - Toggle this help screen"),
-                    Line::from("  /      - Enter search mode"),
+                    Line::from("  /      - Search sites by name or ID"),
+                    Line::from("  n/N    - Next/previous match while a search is active"),
+                    Line::from("  '      - Jump to the first row starting with the typed name"),
+                    Line::from("  i      - Show the full values of the highlighted row"),
                     Line::from("  Tab    - Next view"),
                     Line::from("  S-Tab  - Previous view"),
                     Line::from("  F5     - Force refresh data"),
+                    Line::from("  J      - Show running, recent and scheduled jobs"),
+                    Line::from("  W      - Show alerts (a: acknowledge)"),
+                    Line::from("  A      - Show activity (audit log)"),
+                    Line::from("  I      - Show controller info"),
+                    Line::from("  R      - Export health report"),
+                    Line::from("  P      - Save screen snapshot (.txt and .ans)"),
+                    Line::from("  !      - Show error console"),
+                    Line::from("  M      - Toggle maintenance mode (selected site or all sites)"),
+                    Line::from("  D      - Show the dashboard of all sites"),
+                    Line::from("  Ctrl+S - Switch site from anywhere"),
+                    Line::from("  Ctrl+Z - Undo the last tab, search or dashboard change"),
+                    Line::from("  1-9    - Switch to a favorite site"),
                     Line::from(""),
                     Line::from("Site Navigation:"),
                     Line::from("  ↑/↓    - Select site"),
                     Line::from("  Enter  - View selected site"),
+                    Line::from("  f      - Pin or unpin the selected site as a favorite"),
+                    Line::from("  s      - Sort sites by name (favorites stay on top)"),
                     Line::from("  Esc    - Show all sites"),
                 ],
-                1 => vec![
+                Tab::Devices => vec![
                     // Devices tab
                     Line::from("UniFi Network TUI Help - Devices View"),
                     Line::from(""),
@@ -196,17 +324,49 @@ fn render_help(f: &mut Frame, app: &App, area: Rect) {
                     Line::from("  q      - Quit application"),
                     Line::from("  ?      - Toggle this help screen"),
                     Line::from("  /      - Search devices by name, model, MAC, or IP"),
+                    Line::from("  n/N    - Next/previous match while a search is active"),
+                    Line::from("  '      - Jump to the first row starting with the typed name"),
+                    Line::from("  i      - Show the full values of the highlighted row"),
                     Line::from("  Tab    - Next view"),
                     Line::from("  S-Tab  - Previous view"),
                     Line::from("  F5     - Force refresh data"),
+                    Line::from("  J      - Show running, recent and scheduled jobs"),
+                    Line::from("  W      - Show alerts (a: acknowledge)"),
+                    Line::from("  A      - Show activity (audit log)"),
+                    Line::from("  I      - Show controller info"),
+                    Line::from("  R      - Export health report"),
+                    Line::from("  P      - Save screen snapshot (.txt and .ans)"),
+                    Line::from("  !      - Show error console"),
+                    Line::from("  M      - Toggle maintenance mode (selected site or all sites)"),
+                    Line::from("  D      - Show the dashboard of all sites"),
+                    Line::from("  Ctrl+S - Switch site from anywhere"),
+                    Line::from("  Ctrl+Z - Undo the last tab, search or dashboard change"),
+                    Line::from("  1-9    - Switch to a favorite site"),
                     Line::from("  r      - Restart device (a site has to be selected)"),
+                    Line::from("  u      - Show pending firmware updates"),
+                    Line::from("  S      - Schedule a restart for the selected device"),
+                    Line::from("  t      - Traceroute to the selected device"),
+                    Line::from("  x      - SSH to the selected device (--ssh-user)"),
+                    Line::from("  w      - Open the web UI to look up the selected device"),
                     Line::from(""),
                     Line::from("Device Navigation:"),
                     Line::from("  ↑/↓    - Select device"),
                     Line::from("  Enter  - View device details"),
                     Line::from("  s      - Sort devices (cycles through sorting options)"),
+                    Line::from("  f      - Cycle filters from the scripts file"),
+                    Line::from(""),
+                    Line::from("Device Details:"),
+                    Line::from("  r      - Refetch this device now"),
+                    Line::from("  x      - SSH to this device"),
+                    Line::from("  ←/→    - Switch detail tab"),
+                    Line::from("  c      - Inspect performance charts (←/→ move, Esc ends)"),
+                    Line::from("  +/-    - Zoom performance charts in/out"),
+                    Line::from("  [/]    - Pan a zoomed chart to older/newer samples"),
+                    Line::from("  0      - Reset zoom"),
+                    Line::from("  ↑/↓    - Focus the link speed or latency chart"),
+                    Line::from("  f      - Toggle full-screen for the focused chart"),
                 ],
-                2 => vec![
+                Tab::Clients => vec![
                     // Clients tab
                     Line::from("UniFi Network TUI Help - Clients View"),
                     Line::from(""),
@@ -214,16 +374,37 @@ fn render_help(f: &mut Frame, app: &App, area: Rect) {
                     Line::from("  q      - Quit application"),
                     Line::from("  ?      - Toggle this help screen"),
                     Line::from("  /      - Search clients by name, MAC, or IP"),
+                    Line::from("  n/N    - Next/previous match while a search is active"),
+                    Line::from("  '      - Jump to the first row starting with the typed name"),
+                    Line::from("  i      - Show the full values of the highlighted row"),
                     Line::from("  Tab    - Next view"),
                     Line::from("  S-Tab  - Previous view"),
                     Line::from("  F5     - Force refresh data"),
+                    Line::from("  J      - Show running, recent and scheduled jobs"),
+                    Line::from("  W      - Show alerts (a: acknowledge)"),
+                    Line::from("  A      - Show activity (audit log)"),
+                    Line::from("  I      - Show controller info"),
+                    Line::from("  R      - Export health report"),
+                    Line::from("  P      - Save screen snapshot (.txt and .ans)"),
+                    Line::from("  !      - Show error console"),
+                    Line::from("  M      - Toggle maintenance mode (selected site or all sites)"),
+                    Line::from("  D      - Show the dashboard of all sites"),
+                    Line::from("  Ctrl+S - Switch site from anywhere"),
+                    Line::from("  Ctrl+Z - Undo the last tab, search or dashboard change"),
+                    Line::from("  1-9    - Switch to a favorite site"),
                     Line::from(""),
                     Line::from("Client Navigation:"),
                     Line::from("  ↑/↓    - Select client"),
                     Line::from("  Enter  - View client details"),
+                    Line::from("  n      - Set a local alias for the selected client"),
+                    Line::from("  t      - Traceroute to the selected client"),
+                    Line::from("  w      - Open the web UI to look up the selected client"),
                     Line::from("  s      - Sort clients (cycles through sorting options)"),
+                    Line::from("  f      - Cycle filters from the scripts file"),
+                    Line::from("  g      - Only show clients known to be on 2.4 GHz"),
+                    Line::from("  v      - Show the vendor column (looked up from the MAC)"),
                 ],
-                3 => vec![
+                Tab::Topology => vec![
                     // Topology tab
                     Line::from("UniFi Network TUI Help - Topology View"),
                     Line::from(""),
@@ -233,12 +414,33 @@ fn render_help(f: &mut Frame, app: &App, area: Rect) {
                     Line::from("  Tab    - Next view"),
                     Line::from("  S-Tab  - Previous view"),
                     Line::from("  F5     - Force refresh data"),
+                    Line::from("  J      - Show running, recent and scheduled jobs"),
+                    Line::from("  W      - Show alerts (a: acknowledge)"),
+                    Line::from("  A      - Show activity (audit log)"),
+                    Line::from("  I      - Show controller info"),
+                    Line::from("  R      - Export health report"),
+                    Line::from("  P      - Save screen snapshot (.txt and .ans)"),
+                    Line::from("  !      - Show error console"),
+                    Line::from("  M      - Toggle maintenance mode (selected site or all sites)"),
+                    Line::from("  D      - Show the dashboard of all sites"),
+                    Line::from("  Ctrl+S - Switch site from anywhere"),
+                    Line::from("  Ctrl+Z - Undo the last tab, search or dashboard change"),
+                    Line::from("  1-9    - Switch to a favorite site"),
+                    Line::from(""),
+                    Line::from("Topology Commands:"),
+                    Line::from("  ←/→    - Select previous/next device"),
+                    Line::from("  c      - List clients of the selected device"),
+                    Line::from("  ↑/↓    - Move through the client list"),
+                    Line::from("  Enter  - Open selected device or client"),
+                    Line::from("  +/-    - Zoom, r - Reset view"),
+                    Line::from("  L      - Re-layout the tree, keeping zoom and pan"),
                     Line::from(""),
                     Line::from("Topology Information:"),
                     Line::from("  - Shows network topology and device connectivity"),
+                    Line::from("  - Dashed red links are down or have no reported uplink"),
                     Line::from("  - Updates every refresh cycle (5s by default)"),
                 ],
-                4 => vec![
+                Tab::Stats => vec![
                     // Stats tab
                     Line::from("UniFi Network TUI Help - Statistics View"),
                     Line::from(""),
@@ -248,15 +450,54 @@ fn render_help(f: &mut Frame, app: &App, area: Rect) {
                     Line::from("  Tab    - Next view"),
                     Line::from("  S-Tab  - Previous view"),
                     Line::from("  F5     - Force refresh data"),
+                    Line::from("  J      - Show running, recent and scheduled jobs"),
+                    Line::from("  W      - Show alerts (a: acknowledge)"),
+                    Line::from("  A      - Show activity (audit log)"),
+                    Line::from("  I      - Show controller info"),
+                    Line::from("  R      - Export health report"),
+                    Line::from("  P      - Save screen snapshot (.txt and .ans)"),
+                    Line::from("  !      - Show error console"),
+                    Line::from("  M      - Toggle maintenance mode (selected site or all sites)"),
+                    Line::from("  D      - Show the dashboard of all sites"),
+                    Line::from("  Ctrl+S - Switch site from anywhere"),
+                    Line::from("  Ctrl+Z - Undo the last tab, search or dashboard change"),
+                    Line::from("  1-9    - Switch to a favorite site"),
                     Line::from(""),
                     Line::from("Statistics Information:"),
                     Line::from("  - Shows network overview and device metrics"),
                     Line::from("  - Updates every refresh cycle (5s by default)"),
                     Line::from("  - Maintains history of last 100 data points"),
+                    Line::from("  - Groups clients by the vendor of their MAC address"),
+                    Line::from(""),
+                    Line::from("Charts:"),
+                    Line::from("  c      - Toggle the chart cursor"),
+                    Line::from("  ←/→    - Move the cursor to an older/newer sample"),
+                    Line::from("  Esc    - Hide the cursor"),
+                    Line::from("  +/-    - Zoom in/out"),
+                    Line::from("  [/]    - Pan a zoomed chart to older/newer samples"),
+                    Line::from("  0      - Reset zoom"),
+                    Line::from("  ↑/↓    - Focus the client history or throughput chart"),
+                    Line::from("  b      - Break link speed down per device (stacked)"),
+                    Line::from("  w      - Split link speed into wired and wireless (AP) load"),
+                    Line::from("  f      - Toggle full-screen for the focused chart"),
+                    Line::from("  </>    - Shrink/grow the summary and table above the charts"),
                 ],
-                _ => vec![],
             }
         }
+        Mode::FirmwareUpdates => vec![
+            Line::from("UniFi Network TUI Help - Firmware Updates"),
+            Line::from(""),
+            Line::from("  ↑/↓    - Select device"),
+            Line::from("  Esc    - Back to overview"),
+        ],
+        Mode::SiteDashboard => vec![
+            Line::from("UniFi Network TUI Help - Site Dashboard"),
+            Line::from(""),
+            Line::from("  ↑/↓    - Select site"),
+            Line::from("  Enter  - Open the site's devices"),
+            Line::from("  s      - Sort by most problems or by name"),
+            Line::from("  Esc    - Back to overview"),
+        ],
         _ => vec![Line::from("Help not available for this view")],
     };
 
@@ -0,0 +1,234 @@
+use crate::app::{App, Mode, Tab};
+use crate::handlers::{handle_client_detail_input, handle_device_detail_input};
+use crate::ui::topology::topology::{
+    handle_topology_input, handle_topology_mouse, render_topology,
+};
+use crate::ui::widgets::client_stats::ClientStatsView;
+use crate::ui::{clients, dashboard, devices, firmware, site_dashboard, sites, stats};
+use crossterm::event::{KeyCode, KeyEvent, MouseEvent};
+use ratatui::layout::Rect;
+use ratatui::Frame;
+use std::future::Future;
+use std::pin::Pin;
+
+pub type PageFuture<'a> = Pin<Box<dyn Future<Output = anyhow::Result<()>> + 'a>>;
+
+/// A view shown in the content area, either as a tab of the overview or as a mode
+/// entered from one. Rendering and input for the content area go through this trait,
+/// with the page looked up in the tables below. A new view still needs its own `Tab`
+/// or `Mode` variant and an entry in one of the tables. Dialogs, overlays and prompts
+/// are not pages; `main.rs` routes input to them before the current page.
+pub trait Page: Sync {
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect);
+
+    /// Keys that were not consumed by dialogs, overlays or the global bindings.
+    fn handle_key<'a>(&self, _app: &'a mut App, _key: KeyEvent) -> PageFuture<'a> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Mouse events inside the content area, which is passed as `area`.
+    fn handle_mouse<'a>(
+        &self,
+        _app: &'a mut App,
+        _event: MouseEvent,
+        _area: Rect,
+    ) -> PageFuture<'a> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Called after every refresh of the application state, to rebuild any state the
+    /// page derives from it.
+    fn refresh(&self, _app: &mut App) {}
+}
+
+static TAB_PAGES: [(Tab, &dyn Page); 6] = [
+    (Tab::Dashboard, &DashboardPage),
+    (Tab::Sites, &SitesPage),
+    (Tab::Devices, &DevicesPage),
+    (Tab::Clients, &ClientsPage),
+    (Tab::Topology, &TopologyPage),
+    (Tab::Stats, &StatsPage),
+];
+
+static MODE_PAGES: [(Mode, &dyn Page); 5] = [
+    (Mode::DeviceDetail, &DeviceDetailPage),
+    (Mode::ClientDetail, &ClientDetailPage),
+    (Mode::FirmwareUpdates, &FirmwareUpdatesPage),
+    (Mode::SiteDashboard, &SiteDashboardPage),
+    (Mode::Help, &HelpPage),
+];
+
+pub fn for_tab(tab: Tab) -> Option<&'static dyn Page> {
+    TAB_PAGES
+        .iter()
+        .find(|(t, _)| *t == tab)
+        .map(|(_, page)| *page)
+}
+
+/// The page for the current mode, or for the current tab in the overview.
+pub fn current(app: &App) -> Option<&'static dyn Page> {
+    if app.mode == Mode::Overview {
+        return for_tab(app.current_tab);
+    }
+    MODE_PAGES
+        .iter()
+        .find(|(mode, _)| *mode == app.mode)
+        .map(|(_, page)| *page)
+}
+
+pub fn all() -> impl Iterator<Item = &'static dyn Page> {
+    let tabs = TAB_PAGES.iter().map(|(_, page)| *page);
+    tabs.chain(MODE_PAGES.iter().map(|(_, page)| *page))
+}
+
+struct DashboardPage;
+
+impl Page for DashboardPage {
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        dashboard::render_dashboard(f, app, area);
+    }
+}
+
+struct SitesPage;
+
+impl Page for SitesPage {
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        sites::render_sites(f, app, area);
+    }
+
+    fn handle_key<'a>(&self, app: &'a mut App, key: KeyEvent) -> PageFuture<'a> {
+        Box::pin(async move { sites::handle_sites_input(app, key) })
+    }
+}
+
+struct DevicesPage;
+
+impl Page for DevicesPage {
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        devices::render_devices(f, app, area);
+    }
+
+    fn handle_key<'a>(&self, app: &'a mut App, key: KeyEvent) -> PageFuture<'a> {
+        Box::pin(devices::handle_device_input(app, key))
+    }
+}
+
+struct ClientsPage;
+
+impl Page for ClientsPage {
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        clients::render_clients(f, app, area);
+    }
+
+    fn handle_key<'a>(&self, app: &'a mut App, key: KeyEvent) -> PageFuture<'a> {
+        Box::pin(clients::handle_client_input(app, key))
+    }
+}
+
+struct TopologyPage;
+
+impl Page for TopologyPage {
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        render_topology(f, app, area);
+    }
+
+    fn handle_key<'a>(&self, app: &'a mut App, key: KeyEvent) -> PageFuture<'a> {
+        Box::pin(handle_topology_input(app, key))
+    }
+
+    fn handle_mouse<'a>(&self, app: &'a mut App, event: MouseEvent, area: Rect) -> PageFuture<'a> {
+        Box::pin(handle_topology_mouse(app, event, area))
+    }
+
+    fn refresh(&self, app: &mut App) {
+        app.topology_view.update_from_state(
+            &app.state.filtered_devices,
+            &app.state.filtered_clients,
+            &app.state.device_details,
+        );
+    }
+}
+
+struct StatsPage;
+
+impl Page for StatsPage {
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        stats::render_stats(f, app, area);
+    }
+
+    fn handle_key<'a>(&self, app: &'a mut App, key: KeyEvent) -> PageFuture<'a> {
+        Box::pin(async move { stats::handle_stats_input(app, key) })
+    }
+}
+
+struct DeviceDetailPage;
+
+impl Page for DeviceDetailPage {
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        if app.selected_device_id.is_some() {
+            if let Some(view) = &app.device_stats_view {
+                view.render(f, area, &app.state);
+            }
+        }
+    }
+
+    fn handle_key<'a>(&self, app: &'a mut App, key: KeyEvent) -> PageFuture<'a> {
+        Box::pin(async move { Ok(handle_device_detail_input(app, key).await?) })
+    }
+}
+
+struct ClientDetailPage;
+
+impl Page for ClientDetailPage {
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        if let Some(client_id) = app.selected_client_id {
+            let share = app.pane_ratios.get("client_detail");
+            ClientStatsView::new(client_id, &app.state).render(f, area, share);
+        }
+    }
+
+    fn handle_key<'a>(&self, app: &'a mut App, key: KeyEvent) -> PageFuture<'a> {
+        Box::pin(async move { Ok(handle_client_detail_input(app, key).await?) })
+    }
+}
+
+struct FirmwareUpdatesPage;
+
+impl Page for FirmwareUpdatesPage {
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        firmware::render_firmware_updates(f, app, area);
+    }
+
+    fn handle_key<'a>(&self, app: &'a mut App, key: KeyEvent) -> PageFuture<'a> {
+        Box::pin(firmware::handle_firmware_input(app, key))
+    }
+}
+
+struct SiteDashboardPage;
+
+impl Page for SiteDashboardPage {
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        site_dashboard::render_site_dashboard(f, app, area);
+    }
+
+    fn handle_key<'a>(&self, app: &'a mut App, key: KeyEvent) -> PageFuture<'a> {
+        Box::pin(async move { site_dashboard::handle_site_dashboard_input(app, key) })
+    }
+}
+
+struct HelpPage;
+
+impl Page for HelpPage {
+    fn render(&self, f: &mut Frame, app: &mut App, area: Rect) {
+        super::render_help(f, app, area);
+    }
+
+    fn handle_key<'a>(&self, app: &'a mut App, key: KeyEvent) -> PageFuture<'a> {
+        Box::pin(async move {
+            if key.code == KeyCode::Esc {
+                app.mode = Mode::Overview;
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,218 @@
+use crate::app::App;
+use crate::ui::{dashboard, stats};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::Frame;
+
+/// A panel that can be placed on the Dashboard or Stats tab.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Panel {
+    Status,
+    Wan,
+    Offline,
+    Alerts,
+    TopAps,
+    LinkSpeed,
+    Summary,
+    Devices,
+    Vendors,
+    ClientHistory,
+    Throughput,
+}
+
+impl Panel {
+    const ALL: [Panel; 11] = [
+        Panel::Status,
+        Panel::Wan,
+        Panel::Offline,
+        Panel::Alerts,
+        Panel::TopAps,
+        Panel::LinkSpeed,
+        Panel::Summary,
+        Panel::Devices,
+        Panel::Vendors,
+        Panel::ClientHistory,
+        Panel::Throughput,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Panel::Status => "status",
+            Panel::Wan => "wan",
+            Panel::Offline => "offline",
+            Panel::Alerts => "alerts",
+            Panel::TopAps => "top_aps",
+            Panel::LinkSpeed => "link_speed",
+            Panel::Summary => "summary",
+            Panel::Devices => "devices",
+            Panel::Vendors => "vendors",
+            Panel::ClientHistory => "client_history",
+            Panel::Throughput => "throughput",
+        }
+    }
+
+    /// Rows of only fixed-height panels are as tall as the tallest one; a chart or
+    /// list in the row makes it share the remaining space instead.
+    fn height(self) -> Option<u16> {
+        match self {
+            Panel::Status => Some(3),
+            Panel::Wan | Panel::Offline | Panel::Alerts => Some(8),
+            Panel::Summary | Panel::Devices | Panel::Vendors => Some(10),
+            Panel::TopAps | Panel::LinkSpeed | Panel::ClientHistory | Panel::Throughput => None,
+        }
+    }
+
+    fn render(self, f: &mut Frame, app: &App, area: Rect) {
+        match self {
+            Panel::Status => dashboard::render_verdict(f, app, area),
+            Panel::Wan => dashboard::render_wan(f, app, area),
+            Panel::Offline => dashboard::render_offline_devices(f, app, area),
+            Panel::Alerts => dashboard::render_alerts(f, app, area),
+            Panel::TopAps => dashboard::render_top_aps(f, app, area),
+            Panel::LinkSpeed => dashboard::render_throughput(f, app, area),
+            Panel::Summary => stats::render_summary(f, app, area),
+            Panel::Devices => stats::render_device_table(f, app, area),
+            Panel::Vendors => stats::render_vendor_breakdown(f, app, area),
+            Panel::ClientHistory => stats::render_client_history(f, app, area),
+            Panel::Throughput => stats::render_network_throughput(f, app, area),
+        }
+    }
+}
+
+/// Rows of panels with their widths in percent, written in the config file as e.g.
+/// `summary:30 devices:45 vendors:25 / client_history throughput`. Rows are separated
+/// by `/`; panels in a row without a width share it evenly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PanelLayout {
+    rows: Vec<Vec<(Panel, Option<u16>)>>,
+}
+
+impl PanelLayout {
+    pub fn dashboard() -> Self {
+        Self {
+            rows: vec![
+                vec![(Panel::Status, None)],
+                vec![
+                    (Panel::Wan, Some(34)),
+                    (Panel::Offline, Some(33)),
+                    (Panel::Alerts, Some(33)),
+                ],
+                vec![(Panel::TopAps, Some(50)), (Panel::LinkSpeed, Some(50))],
+            ],
+        }
+    }
+
+    pub fn stats() -> Self {
+        Self {
+            rows: vec![
+                vec![
+                    (Panel::Summary, Some(30)),
+                    (Panel::Devices, Some(45)),
+                    (Panel::Vendors, Some(25)),
+                ],
+                vec![
+                    (Panel::ClientHistory, Some(50)),
+                    (Panel::Throughput, Some(50)),
+                ],
+            ],
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut rows = Vec::new();
+        for row in value.split('/') {
+            let mut panels = Vec::new();
+            for item in row.split_whitespace() {
+                let (name, width) = match item.split_once(':') {
+                    Some((name, width)) => {
+                        let width = width
+                            .parse()
+                            .ok()
+                            .filter(|w| (1..=100).contains(w))
+                            .ok_or_else(|| format!("invalid width `{}` for {}", width, name))?;
+                        (name, Some(width))
+                    }
+                    None => (item, None),
+                };
+                let panel = Panel::ALL
+                    .into_iter()
+                    .find(|p| p.name() == name)
+                    .ok_or_else(|| {
+                        format!(
+                            "unknown panel `{}` (expected one of {})",
+                            name,
+                            Panel::ALL.map(Panel::name).join(", ")
+                        )
+                    })?;
+                panels.push((panel, width));
+            }
+            if panels.is_empty() {
+                return Err("empty row".to_string());
+            }
+            rows.push(panels);
+        }
+        Ok(Self { rows })
+    }
+
+    /// `share` is the percentage of the height for the rows of fixed-height panels,
+    /// see [`scale_fixed_rows`].
+    pub fn render(&self, f: &mut Frame, app: &App, area: Rect, share: Option<u16>) {
+        let heights: Vec<Constraint> = self
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|(panel, _)| panel.height())
+                    .try_fold(0, |tallest, height| Some(tallest.max(height?)))
+                    .map_or(Constraint::Min(0), Constraint::Length)
+            })
+            .collect();
+        let row_areas = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(scale_fixed_rows(heights, area.height, share))
+            .split(area);
+
+        for (row, row_area) in self.rows.iter().zip(row_areas.iter()) {
+            let widths: Vec<Constraint> = row
+                .iter()
+                .map(|(_, width)| match width {
+                    Some(percent) => Constraint::Percentage(*percent),
+                    None => Constraint::Ratio(1, row.len() as u32),
+                })
+                .collect();
+            let panel_areas = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(widths)
+                .split(*row_area);
+            for ((panel, _), panel_area) in row.iter().zip(panel_areas.iter()) {
+                panel.render(f, app, *panel_area);
+            }
+        }
+    }
+}
+
+/// Stretches or squeezes the `Length` rows so that together they take `share` percent
+/// of `height`, leaving the rest to the other rows. Without a share nothing changes.
+pub fn scale_fixed_rows(rows: Vec<Constraint>, height: u16, share: Option<u16>) -> Vec<Constraint> {
+    let Some(share) = share else {
+        return rows;
+    };
+    let fixed: u32 = rows
+        .iter()
+        .map(|row| match row {
+            Constraint::Length(h) => u32::from(*h),
+            _ => 0,
+        })
+        .sum();
+    if fixed == 0 {
+        return rows;
+    }
+    let target = u32::from(height) * u32::from(share) / 100;
+    rows.into_iter()
+        .map(|row| match row {
+            Constraint::Length(h) => {
+                Constraint::Length((u32::from(h) * target / fixed).max(1) as u16)
+            }
+            other => other,
+        })
+        .collect()
+}
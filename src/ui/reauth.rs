@@ -0,0 +1,60 @@
+use crate::app::App;
+use crate::ui::centered_rect;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+pub fn render_reauth_prompt(f: &mut Frame, app: &App, area: Rect) {
+    if let Some(input) = &app.reauth_prompt {
+        let prompt_area = centered_rect(60, 7, area);
+
+        let text = vec![
+            Line::from("The controller rejected the API key."),
+            Line::from("Enter a new key to reconnect:"),
+            Line::styled(
+                format!("> {}", "*".repeat(input.chars().count())),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Line::from(""),
+            Line::from("Enter: Reconnect | Esc: Dismiss"),
+        ];
+
+        let widget = Paragraph::new(text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Red))
+                .title("Authentication Failed"),
+        );
+
+        f.render_widget(Clear, prompt_area);
+        f.render_widget(widget, prompt_area);
+    }
+}
+
+pub async fn handle_reauth_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
+    let Some(input) = app.reauth_prompt.as_mut() else {
+        return Ok(());
+    };
+
+    match key.code {
+        KeyCode::Esc => {
+            app.reauth_prompt = None;
+        }
+        KeyCode::Char(c) => {
+            input.push(c);
+        }
+        KeyCode::Backspace => {
+            input.pop();
+        }
+        KeyCode::Enter if !input.is_empty() => {
+            if let Some(api_key) = app.reauth_prompt.take() {
+                app.reauthenticate(api_key);
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
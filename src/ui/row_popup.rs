@@ -0,0 +1,147 @@
+use crate::app::{App, Mode, Tab};
+use crate::oui;
+use crate::script::{client_record, device_record, ScriptTarget};
+use crate::ui::centered_rect;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+use unifi_rs::models::client::ClientOverview;
+
+/// Untruncated values of the highlighted row, for names and addresses that narrow
+/// columns cut off. Follows the selection until toggled off with `i`.
+pub fn render_row_popup(f: &mut Frame, app: &App, area: Rect) {
+    if app.mode != Mode::Overview {
+        return;
+    }
+    let Some(fields) = row_fields(app) else {
+        return;
+    };
+
+    let text: Vec<Line> = fields
+        .into_iter()
+        .map(|(label, value)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<16}", label),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(value),
+            ])
+        })
+        .collect();
+
+    let popup_area = centered_rect(70, text.len() as u16 + 2, area);
+    let widget = Paragraph::new(text).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Row (i: Close)"),
+    );
+
+    f.render_widget(Clear, popup_area);
+    f.render_widget(widget, popup_area);
+}
+
+fn row_fields(app: &App) -> Option<Vec<(String, String)>> {
+    let field = |label: &str, value: String| (label.to_string(), value);
+    let fields = match app.current_tab {
+        Tab::Sites => {
+            let site = app
+                .sites_table_state
+                .selected()
+                .and_then(|i| app.state.filtered_sites.get(i))?;
+            vec![
+                field(
+                    "Name",
+                    site.name.clone().unwrap_or_else(|| "Unnamed".to_string()),
+                ),
+                field("ID", site.id.to_string()),
+            ]
+        }
+        Tab::Devices => {
+            let device = app
+                .devices_table_state
+                .selected()
+                .and_then(|i| app.state.filtered_devices.get(i))?;
+            let firmware = app
+                .state
+                .device_details
+                .get(&device.id)
+                .map_or("N/A".to_string(), |d| d.firmware_version.clone());
+            let mut fields = vec![
+                field("Name", device.name.clone()),
+                field("Model", device.model.clone()),
+                field("MAC", device.mac_address.clone()),
+                field("IP", device.ip_address.clone()),
+                field("Status", format!("{:?}", device.state)),
+                field("Firmware", firmware),
+                field("ID", device.id.to_string()),
+            ];
+            let record = device_record(&app.state, device);
+            fields.extend(
+                app.scripts
+                    .columns(ScriptTarget::Device)
+                    .iter()
+                    .map(|column| (column.name.clone(), column.cell(&record))),
+            );
+            fields
+        }
+        Tab::Clients => {
+            let client = app
+                .clients_table_state
+                .selected()
+                .and_then(|i| app.state.filtered_clients.get(i))?;
+            let (name, ip, mac, uplink, kind, connected_at, id) = match client {
+                ClientOverview::Wired(c) => (
+                    c.base.name.as_deref(),
+                    c.base.ip_address.as_deref(),
+                    c.mac_address.as_str(),
+                    c.uplink_device_id,
+                    "Wired",
+                    c.base.connected_at,
+                    c.base.id,
+                ),
+                ClientOverview::Wireless(c) => (
+                    c.base.name.as_deref(),
+                    c.base.ip_address.as_deref(),
+                    c.mac_address.as_str(),
+                    c.uplink_device_id,
+                    "Wireless",
+                    c.base.connected_at,
+                    c.base.id,
+                ),
+                _ => return None,
+            };
+            let uplink = app
+                .state
+                .devices
+                .iter()
+                .find(|d| d.id == uplink)
+                .map_or("Unknown".to_string(), |d| d.name.clone());
+            let mut fields = vec![
+                field("Name", app.state.aliases.display_name(mac, name)),
+                field("IP", ip.unwrap_or("Unknown").to_string()),
+                field("MAC", mac.to_string()),
+                field("Vendor", oui::vendor(mac).to_string()),
+                field("Type", kind.to_string()),
+                field("Uplink", uplink),
+                field(
+                    "Connected at",
+                    connected_at.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+                ),
+                field("ID", id.to_string()),
+            ];
+            let record = client_record(&app.state, client);
+            fields.extend(
+                app.scripts
+                    .columns(ScriptTarget::Client)
+                    .iter()
+                    .map(|column| (column.name.clone(), column.cell(&record))),
+            );
+            fields
+        }
+        Tab::Dashboard | Tab::Topology | Tab::Stats => return None,
+    };
+    Some(fields)
+}
@@ -0,0 +1,270 @@
+use crate::app::{App, Mode, Tab};
+use crate::state::SiteSummary;
+use crate::ui::widgets::format_network_speed;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
+use ratatui::Frame;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum SiteDashboardSort {
+    #[default]
+    Problems,
+    Name,
+}
+
+struct SiteRow {
+    id: Uuid,
+    name: String,
+    summary: Option<SiteSummary>,
+    alerts: usize,
+    updates: usize,
+    wan: Option<(i64, i64)>,
+}
+
+impl SiteRow {
+    fn problems(&self) -> usize {
+        self.alerts + self.summary.map_or(0, |s| s.offline())
+    }
+}
+
+fn site_rows(app: &App) -> Vec<SiteRow> {
+    let summaries = app.state.site_summaries();
+    let site_of = |device_id: &Uuid| app.state.device_sites.get(device_id).copied();
+
+    let mut rows: Vec<SiteRow> = app
+        .state
+        .sites
+        .iter()
+        .map(|site| {
+            let alerts = app
+                .alerts
+                .active()
+                .filter(|alert| alert.site_id == Some(site.id))
+                .count();
+            let updates = app
+                .state
+                .pending_firmware_updates()
+                .iter()
+                .filter(|d| site_of(&d.id) == Some(site.id))
+                .count();
+            let wan = app
+                .state
+                .devices
+                .iter()
+                .filter(|d| site_of(&d.id) == Some(site.id) && app.state.is_gateway(d.id))
+                .filter_map(|d| app.state.device_stats.get(&d.id)?.uplink.as_ref())
+                .map(|u| (u.rx_rate_bps, u.tx_rate_bps))
+                .next();
+
+            SiteRow {
+                id: site.id,
+                name: site.name.clone().unwrap_or_else(|| "Unnamed".to_string()),
+                summary: summaries.get(&site.id).copied(),
+                alerts,
+                updates,
+                wan,
+            }
+        })
+        .collect();
+
+    match app.site_dashboard_sort {
+        SiteDashboardSort::Problems => rows.sort_by(|a, b| {
+            b.problems()
+                .cmp(&a.problems())
+                .then_with(|| b.updates.cmp(&a.updates))
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        }),
+        SiteDashboardSort::Name => {
+            rows.sort_by_key(|row| row.name.to_lowercase());
+        }
+    }
+    rows
+}
+
+pub fn render_site_dashboard(f: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .split(area);
+
+    let rows = site_rows(app);
+
+    let offline: usize = rows
+        .iter()
+        .filter_map(|row| row.summary)
+        .map(|s| s.offline())
+        .sum();
+    let clients: usize = rows
+        .iter()
+        .filter_map(|row| row.summary)
+        .map(|s| s.clients)
+        .sum();
+    let problem_sites = rows.iter().filter(|row| row.problems() > 0).count();
+    let mut totals = format!(
+        "{} sites · {} with problems · {} devices offline · {} alerts · {} clients",
+        rows.len(),
+        problem_sites,
+        offline,
+        app.alerts.active_count(),
+        clients
+    );
+    if let Some(site) = &app.state.selected_site {
+        totals.push_str(&format!(
+            " (only {} is loaded, Esc in Sites shows all)",
+            site.site_name
+        ));
+    }
+    f.render_widget(
+        Paragraph::new(totals).block(Block::default().borders(Borders::ALL).title("All Sites")),
+        chunks[0],
+    );
+
+    let header = Row::new(
+        [
+            "",
+            "Site",
+            "Alerts",
+            "Offline",
+            "Devices",
+            "Clients",
+            "Updates",
+            "WAN ↓/↑",
+        ]
+        .map(|title| Cell::from(title).style(Style::default().add_modifier(Modifier::BOLD))),
+    );
+
+    let table_rows: Vec<Row> = rows
+        .iter()
+        .map(|row| {
+            let count = |value: fn(&SiteSummary) -> usize| {
+                row.summary
+                    .map_or("-".to_string(), |s| value(&s).to_string())
+            };
+            let highlight = |value: usize, color: Color| {
+                let style = if value > 0 {
+                    Style::default().fg(color)
+                } else {
+                    Style::default()
+                };
+                Cell::from(value.to_string()).style(style)
+            };
+            let offline = match row.summary {
+                Some(s) => highlight(s.offline(), Color::Red),
+                None => Cell::from("-"),
+            };
+            let wan = row.wan.map_or("-".to_string(), |(rx, tx)| {
+                format!(
+                    "{} / {}",
+                    format_network_speed(rx),
+                    format_network_speed(tx)
+                )
+            });
+
+            Row::new(vec![
+                health_dot(row),
+                Cell::from(row.name.clone()),
+                highlight(row.alerts, Color::Red),
+                offline,
+                Cell::from(count(|s| s.devices)),
+                Cell::from(count(|s| s.clients)),
+                highlight(row.updates, Color::Yellow),
+                Cell::from(wan),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(1),
+        Constraint::Percentage(30),
+        Constraint::Length(7),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Min(20),
+    ];
+
+    let sort = match app.site_dashboard_sort {
+        SiteDashboardSort::Problems => "most problems first",
+        SiteDashboardSort::Name => "by name",
+    };
+    let table = Table::new(table_rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Site Dashboard - {}", sort)),
+        )
+        .row_highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol("➤ ");
+
+    f.render_stateful_widget(table, chunks[1], &mut app.site_dashboard_table_state);
+
+    let help_text = vec![Line::from(
+        "↑/↓: Select | Enter: Open site | s: Toggle sort | ESC: Back",
+    )];
+    let help =
+        Paragraph::new(help_text).block(Block::default().borders(Borders::ALL).title("Controls"));
+    f.render_widget(help, chunks[2]);
+}
+
+fn health_dot(row: &SiteRow) -> Cell<'static> {
+    let color = match row.summary {
+        _ if row.alerts > 0 => Color::Red,
+        Some(s) if s.devices == 0 => Color::DarkGray,
+        Some(s) if s.online == s.devices => Color::Green,
+        Some(s) if s.online == 0 => Color::Red,
+        Some(_) => Color::Yellow,
+        None => Color::DarkGray,
+    };
+    Cell::from("●").style(Style::default().fg(color))
+}
+
+pub fn handle_site_dashboard_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
+    let rows = site_rows(app);
+
+    match key.code {
+        KeyCode::Down => {
+            let i = match app.site_dashboard_table_state.selected() {
+                Some(i) if i + 1 < rows.len() => i + 1,
+                _ => 0,
+            };
+            app.site_dashboard_table_state.select(Some(i));
+        }
+        KeyCode::Up => {
+            let i = match app.site_dashboard_table_state.selected() {
+                Some(0) | None => rows.len().saturating_sub(1),
+                Some(i) => i - 1,
+            };
+            app.site_dashboard_table_state.select(Some(i));
+        }
+        KeyCode::Char('s') => {
+            app.site_dashboard_sort = match app.site_dashboard_sort {
+                SiteDashboardSort::Problems => SiteDashboardSort::Name,
+                SiteDashboardSort::Name => SiteDashboardSort::Problems,
+            };
+        }
+        KeyCode::Enter => {
+            if let Some(row) = app
+                .site_dashboard_table_state
+                .selected()
+                .and_then(|i| rows.get(i))
+            {
+                app.state.set_site_context(Some(row.id));
+                app.mode = Mode::Overview;
+                app.current_tab = Tab::Devices;
+            }
+        }
+        KeyCode::Esc => app.mode = Mode::Overview,
+        _ => {}
+    }
+    Ok(())
+}
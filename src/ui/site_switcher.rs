@@ -0,0 +1,155 @@
+use crate::app::App;
+use crate::ui::centered_rect;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+use ratatui::Frame;
+use uuid::Uuid;
+
+/// Popup for changing the site context from any view (Ctrl+S).
+#[derive(Default)]
+pub struct SiteSwitcher {
+    pub query: String,
+    pub selected: usize,
+}
+
+/// Scores `candidate` against `query` as a case-insensitive subsequence match.
+/// Consecutive characters and matches at the start of a word score higher.
+fn fuzzy_score(candidate: &str, query: &str) -> Option<i32> {
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous: Option<usize> = None;
+
+    for q in query.to_lowercase().chars() {
+        let offset = candidate[position..].iter().position(|&c| c == q)?;
+        let index = position + offset;
+        score += 1;
+        if previous.is_some_and(|p| p + 1 == index) {
+            score += 3;
+        }
+        if index == 0 || !candidate[index - 1].is_alphanumeric() {
+            score += 2;
+        }
+        previous = Some(index);
+        position = index + 1;
+    }
+    Some(score)
+}
+
+/// "All Sites" followed by every site matching the query, best match first.
+fn entries(app: &App, query: &str) -> Vec<(Option<Uuid>, String)> {
+    let mut scored: Vec<(i32, Option<Uuid>, String)> =
+        std::iter::once((None, "All Sites".to_string()))
+            .chain(app.state.sites.iter().map(|site| {
+                (
+                    Some(site.id),
+                    site.name.clone().unwrap_or_else(|| "Unnamed".to_string()),
+                )
+            }))
+            .filter_map(|(id, name)| Some((fuzzy_score(&name, query)?, id, name)))
+            .collect();
+    if !query.is_empty() {
+        scored.sort_by_key(|(score, _, _)| std::cmp::Reverse(*score));
+    }
+    scored.into_iter().map(|(_, id, name)| (id, name)).collect()
+}
+
+pub fn render_site_switcher(f: &mut Frame, app: &App, area: Rect) {
+    let Some(switcher) = &app.site_switcher else {
+        return;
+    };
+    let popup = centered_rect(50, 16, area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(popup);
+
+    let current = app.state.selected_site.as_ref().map(|s| s.site_id);
+    let items: Vec<ListItem> = entries(app, &switcher.query)
+        .into_iter()
+        .map(|(id, name)| {
+            let mut spans = vec![Span::raw(name)];
+            if id == current {
+                spans.push(Span::styled(
+                    " (current)",
+                    Style::default().fg(Color::Green),
+                ));
+            }
+            if let Some(index) = id.and_then(|id| app.state.favorites.position(id)) {
+                if index < 9 {
+                    spans.push(Span::styled(
+                        format!(" ★{}", index + 1),
+                        Style::default().fg(Color::Yellow),
+                    ));
+                }
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let input = Paragraph::new(format!("> {}", switcher.query)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Switch Site (Enter: Select | Esc: Cancel)"),
+    );
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL))
+        .highlight_style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .bg(Color::DarkGray),
+        )
+        .highlight_symbol("➤ ");
+    let mut state = ListState::default().with_selected(Some(switcher.selected));
+
+    f.render_widget(Clear, popup);
+    f.render_widget(input, chunks[0]);
+    f.render_stateful_widget(list, chunks[1], &mut state);
+}
+
+pub fn handle_site_switcher_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
+    let Some(query) = app.site_switcher.as_ref().map(|s| s.query.clone()) else {
+        return Ok(());
+    };
+    let count = entries(app, &query).len();
+    let Some(switcher) = app.site_switcher.as_mut() else {
+        return Ok(());
+    };
+
+    match key.code {
+        KeyCode::Esc => app.site_switcher = None,
+        KeyCode::Down => {
+            switcher.selected = if switcher.selected + 1 < count {
+                switcher.selected + 1
+            } else {
+                0
+            };
+        }
+        KeyCode::Up => {
+            switcher.selected = match switcher.selected {
+                0 => count.saturating_sub(1),
+                i => i - 1,
+            };
+        }
+        KeyCode::Char(c) => {
+            switcher.query.push(c);
+            switcher.selected = 0;
+        }
+        KeyCode::Backspace => {
+            switcher.query.pop();
+            switcher.selected = 0;
+        }
+        KeyCode::Enter => {
+            if let Some(switcher) = app.site_switcher.take() {
+                if let Some((site_id, _)) = entries(app, &switcher.query).get(switcher.selected) {
+                    app.switch_site(*site_id);
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
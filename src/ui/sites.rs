@@ -1,4 +1,6 @@
-use crate::app::App;
+use crate::app::{App, SortOrder};
+use crate::state::SiteSummary;
+use crate::ui::widgets::highlight_matches;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::prelude::Line;
@@ -6,15 +8,16 @@ use ratatui::style::{Color, Modifier, Style};
 use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
 use ratatui::Frame;
 
-pub fn render_sites(f: &mut Frame, app: &App, area: Rect) {
+pub fn render_sites(f: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
         .split(area);
 
+    let summaries = app.state.site_summaries();
     let sites: Vec<Row> = app
         .state
-        .sites
+        .filtered_sites
         .iter()
         .map(|site| {
             let is_selected = app
@@ -29,42 +32,103 @@ pub fn render_sites(f: &mut Frame, app: &App, area: Rect) {
                 Style::default()
             };
 
+            // Sites outside the current site context have no data loaded.
+            let summary = summaries.get(&site.id);
+            let count = |value: fn(&SiteSummary) -> usize| {
+                summary.map_or("-".to_string(), |s| value(s).to_string())
+            };
+
+            let favorite = match app.state.favorites.position(site.id) {
+                Some(index) if index < 9 => format!("★{}", index + 1),
+                Some(_) => "★".to_string(),
+                None => String::new(),
+            };
+
+            // A site that failed to load keeps its error until a later refresh succeeds.
+            let status = match (app.state.site_errors.get(&site.id), summary) {
+                (Some(error), _) => Cell::from(*error).style(Style::default().fg(Color::Red)),
+                (None, Some(_)) => Cell::from("ok").style(Style::default().fg(Color::Green)),
+                (None, None) => Cell::from("-"),
+            };
+
             let cells = vec![
-                Cell::from(site.id.to_string()),
-                Cell::from(site.name.as_deref().unwrap_or("Unnamed")),
+                health_dot(summary),
+                Cell::from(favorite).style(Style::default().fg(Color::Yellow)),
+                Cell::from(highlight_matches(
+                    site.name.clone().unwrap_or_else(|| "Unnamed".to_string()),
+                    &app.search_query,
+                )),
+                status,
+                Cell::from(count(|s| s.devices)),
+                Cell::from(count(|s| s.online)),
+                Cell::from(count(SiteSummary::offline)),
+                Cell::from(count(|s| s.clients)),
+                Cell::from(highlight_matches(site.id.to_string(), &app.search_query)),
             ];
             Row::new(cells).style(style)
         })
         .collect();
 
-    let header = Row::new(vec![
-        Cell::from("ID").style(Style::default().add_modifier(Modifier::BOLD)),
-        Cell::from("Name").style(Style::default().add_modifier(Modifier::BOLD)),
-    ]);
+    let header = Row::new(
+        [
+            "", "", "Name", "Status", "Devices", "Online", "Offline", "Clients", "ID",
+        ]
+        .map(|title| Cell::from(title).style(Style::default().add_modifier(Modifier::BOLD))),
+    );
 
-    let widths = [Constraint::Percentage(30), Constraint::Percentage(70)];
+    let widths = [
+        Constraint::Length(1),
+        Constraint::Length(2),
+        Constraint::Percentage(25),
+        Constraint::Length(13),
+        Constraint::Length(8),
+        Constraint::Length(7),
+        Constraint::Length(8),
+        Constraint::Length(8),
+        Constraint::Min(36),
+    ];
+
+    let sort = match app.site_sort_order {
+        SortOrder::Ascending => " - Name ↑",
+        SortOrder::Descending => " - Name ↓",
+        SortOrder::None => "",
+    };
+    let title = format!("Sites [{}]{}", app.state.filtered_sites.len(), sort);
 
     let table = Table::new(sites, widths)
         .header(header)
-        .block(Block::default().borders(Borders::ALL).title("Sites"))
+        .block(Block::default().borders(Borders::ALL).title(title))
         .row_highlight_style(Style::default().bg(Color::Gray));
 
-    f.render_stateful_widget(table, chunks[0], &mut app.sites_table_state.clone());
+    f.render_stateful_widget(table, chunks[0], &mut app.sites_table_state);
 
     let help_text = vec![Line::from(
-        "↑/↓: Select site | Enter: View site | Esc: Show all sites",
+        "↑/↓: Select | Enter: View | s: Sort | f: Favorite | 1-9: Jump | Esc: All sites",
     )];
     let help =
         Paragraph::new(help_text).block(Block::default().borders(Borders::ALL).title("Quick Help"));
     f.render_widget(help, chunks[1]);
 }
 
+/// Green when every device is online, red when none is, yellow in between and
+/// grey when the site has no devices or its data is not loaded.
+fn health_dot(summary: Option<&SiteSummary>) -> Cell<'static> {
+    let color = match summary {
+        Some(s) if s.devices == 0 => Color::DarkGray,
+        Some(s) if s.online == s.devices => Color::Green,
+        Some(s) if s.online == 0 => Color::Red,
+        Some(_) => Color::Yellow,
+        None => Color::DarkGray,
+    };
+    Cell::from("●").style(Style::default().fg(color))
+}
+
 pub fn handle_sites_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
     match key.code {
         KeyCode::Down => {
             let i = match app.sites_table_state.selected() {
                 Some(i) => {
-                    if i >= app.state.sites.len().saturating_sub(1) {
+                    if i >= app.state.filtered_sites.len().saturating_sub(1) {
                         0
                     } else {
                         i + 1
@@ -78,7 +142,7 @@ pub fn handle_sites_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
             let i = match app.sites_table_state.selected() {
                 Some(i) => {
                     if i == 0 {
-                        app.state.sites.len().saturating_sub(1)
+                        app.state.filtered_sites.len().saturating_sub(1)
                     } else {
                         i - 1
                     }
@@ -89,11 +153,32 @@ pub fn handle_sites_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
         }
         KeyCode::Enter => {
             if let Some(idx) = app.sites_table_state.selected() {
-                if let Some(site) = app.state.sites.get(idx) {
+                if let Some(site) = app.state.filtered_sites.get(idx) {
                     app.state.set_site_context(Some(site.id));
                 }
             }
         }
+        KeyCode::Char('s') => {
+            app.site_sort_order = match app.site_sort_order {
+                SortOrder::None => SortOrder::Ascending,
+                SortOrder::Ascending => SortOrder::Descending,
+                SortOrder::Descending => SortOrder::None,
+            };
+            if matches!(app.site_sort_order, SortOrder::None) {
+                app.state.search(&app.search_query);
+            } else {
+                app.sort_sites();
+            }
+        }
+        KeyCode::Char('f') => {
+            if let Some(site) = app
+                .sites_table_state
+                .selected()
+                .and_then(|idx| app.state.filtered_sites.get(idx))
+            {
+                app.toggle_favorite_site(site.id);
+            }
+        }
         KeyCode::Esc => {
             app.sites_table_state.select(None);
             app.state.set_site_context(None);
@@ -1,49 +1,84 @@
 use crate::app::App;
-use crate::state::NetworkStats;
+use crate::oui;
+use crate::state::{DeviceMetrics, NetworkStats};
+use crate::ui::widgets::chart::{
+    cursor_dataset, cursor_line, focus_border, render_cursor_legend, render_empty_chart,
+    sample_bounds, scale_max, time_labels, ChartCursor, ChartWindow, YScale,
+};
 use crate::ui::widgets::format_network_speed;
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use chrono::Local;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::Line;
 use ratatui::widgets::{
     Axis, Block, Borders, Cell, Chart, Dataset, GraphType, Paragraph, Row, Table,
 };
 use ratatui::{symbols, Frame};
+use std::collections::HashMap;
 use unifi_rs::device::DeviceState;
 use unifi_rs::models::client::ClientOverview;
+use uuid::Uuid;
 
-pub fn render_stats(f: &mut Frame, app: &App, area: Rect) {
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints(
-            [
-                Constraint::Length(10), // Summary + Device Stats Table
-                Constraint::Min(0),     // Network Graphs
-            ]
-            .as_ref(),
-        )
-        .split(area);
+const BREAKDOWN_COLORS: [Color; 6] = [
+    Color::Green,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Yellow,
+    Color::Red,
+];
 
-    render_summary_and_device_table(f, app, chunks[0]);
-    render_network_graphs(f, app, chunks[1]);
+pub fn render_stats(f: &mut Frame, app: &App, area: Rect) {
+    let share = app.pane_ratios.get("stats");
+    app.stats_layout.render(f, app, area, share);
 }
 
-fn render_summary_and_device_table(f: &mut Frame, app: &App, area: Rect) {
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints(
-            [
-                Constraint::Percentage(30), // Summary
-                Constraint::Percentage(70), // Device Table
-            ]
-            .as_ref(),
+/// Clients grouped by the vendor of their MAC address, most common first, to keep an
+/// eye on IoT sprawl and spot devices from unexpected vendors.
+pub(super) fn render_vendor_breakdown(f: &mut Frame, app: &App, area: Rect) {
+    let mut counts: HashMap<&'static str, usize> = HashMap::new();
+    for client in &app.state.clients {
+        let mac = match client {
+            ClientOverview::Wired(c) => &c.mac_address,
+            ClientOverview::Wireless(c) => &c.mac_address,
+            _ => continue,
+        };
+        *counts.entry(oui::vendor(mac)).or_default() += 1;
+    }
+
+    let mut vendors: Vec<_> = counts.into_iter().collect();
+    vendors.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+    let rows: Vec<Row> = vendors
+        .iter()
+        .map(|(vendor, count)| {
+            let style = match *vendor {
+                "Unknown" => Style::default().fg(Color::Yellow),
+                "Private MAC" => Style::default().fg(Color::DarkGray),
+                _ => Style::default(),
+            };
+            Row::new(vec![Cell::from(*vendor), Cell::from(count.to_string())]).style(style)
+        })
+        .collect();
+
+    let widths = [Constraint::Min(12), Constraint::Length(7)];
+
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new(vec!["Vendor", "Clients"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
         )
-        .split(area);
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Clients by Vendor [{}]", vendors.len())),
+        );
 
-    render_summary(f, app, chunks[0]);
-    render_device_table(f, app, chunks[1]);
+    f.render_widget(table, area);
 }
 
-fn render_summary(f: &mut Frame, app: &App, area: Rect) {
+pub(super) fn render_summary(f: &mut Frame, app: &App, area: Rect) {
     let online_devices = app
         .state
         .devices
@@ -104,7 +139,7 @@ fn render_summary(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(summary, area);
 }
 
-fn render_device_table(f: &mut Frame, app: &App, area: Rect) {
+pub(super) fn render_device_table(f: &mut Frame, app: &App, area: Rect) {
     let header = Row::new(vec!["Device", "CPU", "Memory", "Traffic"])
         .style(Style::default().add_modifier(Modifier::BOLD));
 
@@ -164,24 +199,19 @@ fn render_device_table(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(table, area);
 }
 
-fn render_network_graphs(f: &mut Frame, app: &App, area: Rect) {
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints(
-            [
-                Constraint::Percentage(50), // Client History
-                Constraint::Percentage(50), // Network Throughput
-            ]
-            .as_ref(),
-        )
-        .split(area);
-
-    render_client_history(f, app, chunks[0]);
-    render_network_throughput(f, app, chunks[1]);
+pub fn render_focused_chart(f: &mut Frame, app: &App, area: Rect) {
+    match app.stats_focus {
+        0 => render_client_history(f, app, area),
+        _ => render_network_throughput(f, app, area),
+    }
 }
-fn render_client_history(f: &mut Frame, app: &App, area: Rect) {
-    let client_history: Vec<&NetworkStats> = app.state.stats_history.iter().collect();
+
+pub(super) fn render_client_history(f: &mut Frame, app: &App, area: Rect) {
+    let range = app.stats_window.range(app.state.stats_history.len());
+    let client_history: Vec<&NetworkStats> = app.state.stats_history.range(range).collect();
+    let title = app.stats_window.title("Client History");
     if client_history.is_empty() {
+        render_empty_chart(f, area, title, focus_border(app.stats_focus == 0));
         return;
     }
 
@@ -203,12 +233,13 @@ fn render_client_history(f: &mut Frame, app: &App, area: Rect) {
         .map(|(i, s)| (i as f64, s.wired_clients as f64))
         .collect();
 
-    let max_y = client_history
-        .iter()
-        .map(|s| s.client_count as f64)
-        .fold(0.0, f64::max);
+    let (max_y, clipped) = scale_max(client_history.iter().map(|s| s.client_count as f64));
+
+    let cursor = app.stats_cursor.and_then(|c| c.index(client_history.len()));
+    let y_scale = YScale::new(max_y);
+    let cursor_points = cursor.map(|i| cursor_line(i as f64, y_scale.max()));
 
-    let datasets = vec![
+    let mut datasets = vec![
         Dataset::default()
             .name("Total")
             .marker(symbols::Marker::Dot)
@@ -228,40 +259,338 @@ fn render_client_history(f: &mut Frame, app: &App, area: Rect) {
             .style(Style::default().fg(Color::Blue))
             .data(&wired_data),
     ];
+    if let Some(points) = &cursor_points {
+        datasets.push(cursor_dataset(points));
+    }
 
-    let max_y_label = format!("{}", max_y as i32);
-    let y_axis_labels = vec![Line::from("0"), Line::from(max_y_label.as_str())];
+    let y_axis_labels = y_scale.labels(|clients| format!("{}", clients as i64));
 
-    let x_axis_labels = vec![Line::from("5m ago"), Line::from("Now")];
+    let x_axis_labels = history_time_labels(&client_history);
 
     let chart = Chart::new(datasets)
         .block(
             Block::default()
-                .title("Client History")
+                .title(clipped_title(title, clipped))
                 .borders(Borders::ALL)
-                .border_style(Style::default()),
+                .border_style(focus_border(app.stats_focus == 0)),
         )
         .x_axis(
             Axis::default()
                 .title("Time")
                 .style(Style::default())
-                .bounds([0.0, (client_history.len() - 1) as f64])
+                .bounds(sample_bounds(client_history.len()))
                 .labels(x_axis_labels),
         )
         .y_axis(
             Axis::default()
                 .title("Clients")
                 .style(Style::default())
-                .bounds([0.0, max_y * 1.1])
+                .bounds(y_scale.bounds())
                 .labels(y_axis_labels),
         );
 
     f.render_widget(chart, area);
+
+    if let Some(stats) = cursor.map(|i| client_history[i]) {
+        render_cursor_legend(
+            f,
+            area,
+            vec![
+                Line::from(cursor_timestamp(stats)),
+                Line::styled(
+                    format!("Total {}", stats.client_count),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Line::styled(
+                    format!("Wireless {}", stats.wireless_clients),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Line::styled(
+                    format!("Wired {}", stats.wired_clients),
+                    Style::default().fg(Color::Blue),
+                ),
+            ],
+        );
+    }
 }
 
-fn render_network_throughput(f: &mut Frame, app: &App, area: Rect) {
-    let stats_history: Vec<&NetworkStats> = app.state.stats_history.iter().collect();
+/// Link speed stacked per device: each line is the running total up to that device,
+/// so the gap below a line is that device's share. The busiest devices in the
+/// visible window get their own band and the rest are summed into "Other".
+fn render_throughput_breakdown(f: &mut Frame, app: &App, area: Rect) {
+    let range = app.stats_window.range(app.state.stats_history.len());
+    let history: Vec<&NetworkStats> = app.state.stats_history.range(range).collect();
+    let title = app
+        .stats_window
+        .title("Network Link Speed by Device (stacked)");
+    if history.is_empty() {
+        render_empty_chart(f, area, title, focus_border(app.stats_focus == 1));
+        return;
+    }
+
+    let mut totals: HashMap<Uuid, (String, i64)> = HashMap::new();
+    for metrics in history.iter().flat_map(|stats| &stats.device_stats) {
+        totals
+            .entry(metrics.device_id)
+            .or_insert_with(|| (metrics.device_name.clone(), 0))
+            .1 += device_rate(metrics);
+    }
+    let mut ranked: Vec<(Uuid, String, i64)> = totals
+        .into_iter()
+        .map(|(id, (name, total))| (id, name, total))
+        .collect();
+    ranked.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.1.cmp(&b.1)));
+
+    let shown = if ranked.len() > BREAKDOWN_COLORS.len() {
+        BREAKDOWN_COLORS.len() - 1
+    } else {
+        ranked.len()
+    };
+    let mut names: Vec<String> = ranked[..shown]
+        .iter()
+        .map(|(_, name, _)| name.clone())
+        .collect();
+    if ranked.len() > shown {
+        names.push("Other".to_string());
+    }
+
+    let band_rates: Vec<Vec<i64>> = history
+        .iter()
+        .map(|stats| {
+            let mut rates = vec![0; names.len()];
+            for metrics in &stats.device_stats {
+                let band = ranked[..shown]
+                    .iter()
+                    .position(|(id, _, _)| *id == metrics.device_id)
+                    .unwrap_or(names.len() - 1);
+                rates[band] += device_rate(metrics);
+            }
+            rates
+        })
+        .collect();
+
+    let stacked: Vec<Vec<(f64, f64)>> = (0..names.len())
+        .map(|band| {
+            band_rates
+                .iter()
+                .enumerate()
+                .map(|(i, rates)| (i as f64, rates[..=band].iter().sum::<i64>() as f64))
+                .collect()
+        })
+        .collect();
+
+    let (max_total, clipped) =
+        scale_max(stacked.last().into_iter().flatten().map(|(_, rate)| *rate));
+    let y_scale = YScale::new(max_total);
+
+    let cursor = app.stats_cursor.and_then(|c| c.index(history.len()));
+    let cursor_points = cursor.map(|i| cursor_line(i as f64, y_scale.max()));
+
+    let mut datasets: Vec<Dataset> = names
+        .iter()
+        .zip(&stacked)
+        .zip(BREAKDOWN_COLORS)
+        .map(|((name, data), color)| {
+            Dataset::default()
+                .name(name.clone())
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(color))
+                .data(data)
+        })
+        .collect();
+    if let Some(points) = &cursor_points {
+        datasets.push(cursor_dataset(points));
+    }
+
+    let chart = Chart::new(datasets)
+        .block(
+            Block::default()
+                .title(clipped_title(title, clipped))
+                .borders(Borders::ALL)
+                .border_style(focus_border(app.stats_focus == 1)),
+        )
+        .x_axis(
+            Axis::default()
+                .title("Time")
+                .bounds(sample_bounds(history.len()))
+                .labels(history_time_labels(&history)),
+        )
+        .y_axis(
+            Axis::default()
+                .title("Speed")
+                .bounds(y_scale.bounds())
+                .labels(y_scale.labels(|bps| format_network_speed(bps as i64))),
+        );
+
+    f.render_widget(chart, area);
+
+    if let Some(i) = cursor {
+        let mut lines = vec![Line::from(cursor_timestamp(history[i]))];
+        lines.extend(names.iter().zip(&band_rates[i]).zip(BREAKDOWN_COLORS).map(
+            |((name, rate), color)| {
+                Line::styled(
+                    format!("{} {}", name, format_network_speed(*rate)),
+                    Style::default().fg(color),
+                )
+            },
+        ));
+        render_cursor_legend(f, area, lines);
+    }
+}
+
+fn clipped_title(title: String, clipped: bool) -> String {
+    if clipped {
+        format!("{} - spikes clipped", title)
+    } else {
+        title
+    }
+}
+
+fn device_rate(metrics: &DeviceMetrics) -> i64 {
+    metrics.tx_rate.unwrap_or(0) + metrics.rx_rate.unwrap_or(0)
+}
+
+fn history_time_labels<'a>(history: &[&NetworkStats]) -> Vec<Line<'a>> {
+    match (history.first(), history.last()) {
+        (Some(first), Some(last)) => time_labels(first.timestamp, last.timestamp),
+        _ => Vec::new(),
+    }
+}
+
+fn cursor_timestamp(stats: &NetworkStats) -> String {
+    stats
+        .timestamp
+        .with_timezone(&Local)
+        .format("%H:%M:%S")
+        .to_string()
+}
+
+/// Link speed (up and down combined) of devices serving wireless clients against the
+/// rest, separating RF load on the access points from backbone load.
+fn render_throughput_by_medium(f: &mut Frame, app: &App, area: Rect) {
+    let range = app.stats_window.range(app.state.stats_history.len());
+    let stats_history: Vec<&NetworkStats> = app.state.stats_history.range(range).collect();
+    let title = app
+        .stats_window
+        .title("Network Link Speed - Wired vs Wireless");
+    if stats_history.is_empty() {
+        render_empty_chart(f, area, title, focus_border(app.stats_focus == 1));
+        return;
+    }
+
+    let medium_data = |wireless: bool| -> Vec<(f64, f64)> {
+        stats_history
+            .iter()
+            .enumerate()
+            .map(|(i, stats)| {
+                let total: i64 = stats
+                    .device_stats
+                    .iter()
+                    .filter(|m| m.wireless == wireless)
+                    .map(|m| m.tx_rate.unwrap_or(0) + m.rx_rate.unwrap_or(0))
+                    .sum();
+                (i as f64, total as f64)
+            })
+            .collect()
+    };
+    let wired_data = medium_data(false);
+    let wireless_data = medium_data(true);
+
+    let (max_throughput, clipped) = scale_max(
+        wired_data
+            .iter()
+            .chain(wireless_data.iter())
+            .map(|(_, rate)| *rate),
+    );
+
+    let cursor = app.stats_cursor.and_then(|c| c.index(stats_history.len()));
+    let y_scale = YScale::new(max_throughput);
+    let cursor_points = cursor.map(|i| cursor_line(i as f64, y_scale.max()));
+
+    let mut datasets = vec![
+        Dataset::default()
+            .name("Wired")
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Blue))
+            .data(&wired_data),
+        Dataset::default()
+            .name("Wireless")
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Yellow))
+            .data(&wireless_data),
+    ];
+    if let Some(points) = &cursor_points {
+        datasets.push(cursor_dataset(points));
+    }
+
+    let y_labels = y_scale.labels(|bps| format_network_speed(bps as i64));
+
+    let x_labels = history_time_labels(&stats_history);
+
+    let chart = Chart::new(datasets)
+        .block(
+            Block::default()
+                .title(clipped_title(title, clipped))
+                .borders(Borders::ALL)
+                .border_style(focus_border(app.stats_focus == 1)),
+        )
+        .x_axis(
+            Axis::default()
+                .title("Time")
+                .style(Style::default())
+                .bounds(sample_bounds(stats_history.len()))
+                .labels(x_labels),
+        )
+        .y_axis(
+            Axis::default()
+                .title("Speed")
+                .style(Style::default())
+                .bounds(y_scale.bounds())
+                .labels(y_labels),
+        );
+
+    f.render_widget(chart, area);
+
+    if let Some(i) = cursor {
+        render_cursor_legend(
+            f,
+            area,
+            vec![
+                Line::from(cursor_timestamp(stats_history[i])),
+                Line::styled(
+                    format!("Wired {}", format_network_speed(wired_data[i].1 as i64)),
+                    Style::default().fg(Color::Blue),
+                ),
+                Line::styled(
+                    format!(
+                        "Wireless {}",
+                        format_network_speed(wireless_data[i].1 as i64)
+                    ),
+                    Style::default().fg(Color::Yellow),
+                ),
+            ],
+        );
+    }
+}
+
+pub(super) fn render_network_throughput(f: &mut Frame, app: &App, area: Rect) {
+    if app.stats_breakdown {
+        render_throughput_breakdown(f, app, area);
+        return;
+    }
+    if app.stats_by_medium {
+        render_throughput_by_medium(f, app, area);
+        return;
+    }
+
+    let range = app.stats_window.range(app.state.stats_history.len());
+    let stats_history: Vec<&NetworkStats> = app.state.stats_history.range(range).collect();
+    let title = app.stats_window.title("Network Link Speed (All Devices)");
     if stats_history.is_empty() {
+        render_empty_chart(f, area, title, focus_border(app.stats_focus == 1));
         return;
     }
 
@@ -291,13 +620,14 @@ fn render_network_throughput(f: &mut Frame, app: &App, area: Rect) {
         })
         .collect();
 
-    let max_throughput = tx_data
-        .iter()
-        .chain(rx_data.iter())
-        .map(|(_, rate)| *rate)
-        .fold(0.0, f64::max);
+    let (max_throughput, clipped) =
+        scale_max(tx_data.iter().chain(rx_data.iter()).map(|(_, rate)| *rate));
+
+    let cursor = app.stats_cursor.and_then(|c| c.index(stats_history.len()));
+    let y_scale = YScale::new(max_throughput);
+    let cursor_points = cursor.map(|i| cursor_line(i as f64, y_scale.max()));
 
-    let datasets = vec![
+    let mut datasets = vec![
         Dataset::default()
             .name("TX")
             .marker(symbols::Marker::Dot)
@@ -311,37 +641,101 @@ fn render_network_throughput(f: &mut Frame, app: &App, area: Rect) {
             .style(Style::default().fg(Color::Blue))
             .data(&rx_data),
     ];
+    if let Some(points) = &cursor_points {
+        datasets.push(cursor_dataset(points));
+    }
 
-    let max_label = format_network_speed(max_throughput as i64).to_string();
-    let y_labels = vec![Line::from("0"), Line::from(max_label.as_str())];
+    let y_labels = y_scale.labels(|bps| format_network_speed(bps as i64));
 
-    let x_labels = vec![
-        Line::from("5m ago"),
-        Line::from("2.5m ago"),
-        Line::from("now"),
-    ];
+    let x_labels = history_time_labels(&stats_history);
 
     let chart = Chart::new(datasets)
         .block(
             Block::default()
-                .title("Network Link Speed (All Devices)")
+                .title(clipped_title(title, clipped))
                 .borders(Borders::ALL)
-                .border_style(Style::default()),
+                .border_style(focus_border(app.stats_focus == 1)),
         )
         .x_axis(
             Axis::default()
                 .title("Time")
                 .style(Style::default())
-                .bounds([0.0, (stats_history.len() - 1) as f64])
+                .bounds(sample_bounds(stats_history.len()))
                 .labels(x_labels),
         )
         .y_axis(
             Axis::default()
                 .title("Speed")
                 .style(Style::default())
-                .bounds([0.0, max_throughput * 1.1])
+                .bounds(y_scale.bounds())
                 .labels(y_labels),
         );
 
     f.render_widget(chart, area);
+
+    if let Some(i) = cursor {
+        render_cursor_legend(
+            f,
+            area,
+            vec![
+                Line::from(cursor_timestamp(stats_history[i])),
+                Line::styled(
+                    format!("TX {}", format_network_speed(tx_data[i].1 as i64)),
+                    Style::default().fg(Color::Green),
+                ),
+                Line::styled(
+                    format!("RX {}", format_network_speed(rx_data[i].1 as i64)),
+                    Style::default().fg(Color::Blue),
+                ),
+            ],
+        );
+    }
+}
+
+pub fn handle_stats_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
+    let total = app.state.stats_history.len();
+    match key.code {
+        KeyCode::Char('+') | KeyCode::Char('=') => app.stats_window.zoom_in(total),
+        KeyCode::Char('-') => app.stats_window.zoom_out(total),
+        KeyCode::Char('[') => app.stats_window.pan_older(total),
+        KeyCode::Char(']') => app.stats_window.pan_newer(),
+        KeyCode::Char('0') => app.stats_window = ChartWindow::default(),
+        KeyCode::Up | KeyCode::Down => app.stats_focus = 1 - app.stats_focus,
+        KeyCode::Char('f') => app.chart_fullscreen = true,
+        KeyCode::Char('b') => {
+            app.stats_breakdown = !app.stats_breakdown;
+            app.stats_by_medium = false;
+        }
+        KeyCode::Char('w') => {
+            app.stats_by_medium = !app.stats_by_medium;
+            app.stats_breakdown = false;
+        }
+        _ => {}
+    }
+
+    let len = app.stats_window.range(total).len();
+    if let Some(cursor) = app.stats_cursor.as_mut() {
+        cursor.clamp(len);
+    }
+    match key.code {
+        KeyCode::Char('c') => {
+            app.stats_cursor = match app.stats_cursor {
+                Some(_) => None,
+                None => Some(ChartCursor::default()),
+            };
+        }
+        KeyCode::Left => {
+            if let Some(cursor) = app.stats_cursor.as_mut() {
+                cursor.older(len);
+            }
+        }
+        KeyCode::Right => {
+            if let Some(cursor) = app.stats_cursor.as_mut() {
+                cursor.newer();
+            }
+        }
+        KeyCode::Esc => app.stats_cursor = None,
+        _ => {}
+    }
+    Ok(())
 }
@@ -1,11 +1,29 @@
 use crate::app::App;
 use ratatui::layout::Rect;
-use ratatui::style::Style;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 use unifi_rs::device::DeviceState;
 
 pub fn render_status_bar(f: &mut Frame, app: &App, area: Rect) {
+    if let Some(confirm) = app.pending_confirm() {
+        let prompt = Paragraph::new(format!("▶ {}", confirm.prompt)).style(
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+        f.render_widget(prompt, area);
+        return;
+    }
+    if let Some(query) = &app.jump_query {
+        let prompt = Paragraph::new(format!("Jump to: {}▏ (Enter/Esc to finish)", query))
+            .style(Style::default().fg(Color::Black).bg(Color::Cyan));
+        f.render_widget(prompt, area);
+        return;
+    }
+
     let online_devices = app
         .state
         .devices
@@ -13,28 +31,134 @@ pub fn render_status_bar(f: &mut Frame, app: &App, area: Rect) {
         .filter(|d| matches!(d.state, DeviceState::Online))
         .count();
 
-    let status = format!(
-        "{} | Devices: {} ({} online) | Clients: {} | {}",
+    let pending_updates = app.state.pending_firmware_updates().len();
+
+    let mut segments = vec![connection_segment(app)];
+    segments.push(Span::styled(
         app.state
             .selected_site
             .as_ref()
-            .map_or("All Sites", |s| &s.site_name),
-        app.state.devices.len(),
-        online_devices,
-        app.state.clients.len(),
-        format_uptime(app.state.last_update.elapsed()),
-    );
+            .map_or("All Sites".to_string(), |s| s.site_name.clone()),
+        Style::default().add_modifier(Modifier::BOLD),
+    ));
+
+    let offline_devices = app.state.devices.len() - online_devices;
+    segments.push(Span::styled(
+        format!(
+            "Devices: {} ({} online)",
+            app.state.devices.len(),
+            online_devices
+        ),
+        if offline_devices > 0 {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        },
+    ));
+    segments.push(Span::raw(format!("Clients: {}", app.state.clients.len())));
+
+    if let Some(filter) = filter_label(app) {
+        segments.push(Span::styled(filter, Style::default().fg(Color::Cyan)));
+    }
+    if pending_updates > 0 {
+        segments.push(Span::raw(format!("Updates: {}", pending_updates)));
+    }
+
+    let alert_count = app.alerts.unacknowledged_count();
+    segments.push(if alert_count > 0 {
+        Span::styled(
+            format!("Alerts: {}", alert_count),
+            Style::default()
+                .fg(Color::White)
+                .bg(Color::Red)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        let acknowledged = app.alerts.active_count();
+        Span::styled(
+            if acknowledged > 0 {
+                format!("Alerts: 0 ({} acknowledged)", acknowledged)
+            } else {
+                "Alerts: 0".to_string()
+            },
+            Style::default().fg(Color::Green),
+        )
+    });
 
-    let status_bar = Paragraph::new(status).style(Style::default());
+    let running_jobs = app.tasks.running_count();
+    if running_jobs > 0 {
+        segments.push(Span::raw(format!("Jobs: {} running", running_jobs)));
+    }
+    if let Some(maintenance) = app.maintenance.as_ref().filter(|m| m.is_active()) {
+        segments.push(Span::styled(
+            maintenance.label(),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    segments.push(Span::styled(
+        refresh_countdown(app),
+        Style::default().fg(Color::DarkGray),
+    ));
 
-    f.render_widget(status_bar, area);
+    let separator = Span::styled(" │ ", Style::default().fg(Color::DarkGray));
+    let mut spans = Vec::with_capacity(segments.len() * 2);
+    for (i, segment) in segments.into_iter().enumerate() {
+        if i > 0 {
+            spans.push(separator.clone());
+        }
+        spans.push(segment);
+    }
+
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
 }
 
-fn format_uptime(duration: std::time::Duration) -> String {
-    let uptime = duration.as_secs();
-    let hours = uptime / 3600;
-    let minutes = (uptime % 3600) / 60;
-    let seconds = uptime % 60;
+/// Whether the last refreshes reached the controller. A rejected key or rate limiting
+/// takes precedence; otherwise the data is stale once three refresh intervals have
+/// passed without an update.
+fn connection_segment(app: &App) -> Span<'static> {
+    let (label, color) = if app.reauth_prompt.is_some() {
+        ("● API key rejected".to_string(), Color::Red)
+    } else if let Some(banner) = &app.banner {
+        (format!("⚠ {}", banner), Color::Yellow)
+    } else if app.state.last_update.elapsed() > app.state.refresh_interval * 3 {
+        ("● Stale".to_string(), Color::Red)
+    } else if !app.state.site_errors.is_empty() {
+        ("● Partial".to_string(), Color::Yellow)
+    } else {
+        ("● Connected".to_string(), Color::Green)
+    };
+    Span::styled(label, Style::default().fg(color))
+}
 
-    format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+fn filter_label(app: &App) -> Option<String> {
+    let mut filters = Vec::new();
+    if !app.search_query.is_empty() {
+        filters.push(format!("/{}", app.search_query));
+    }
+    if app.state.only_2ghz_clients {
+        filters.push("2.4 GHz only".to_string());
+    }
+    for filter in [&app.state.device_filter, &app.state.client_filter]
+        .into_iter()
+        .flatten()
+    {
+        filters.push(filter.name.clone());
+    }
+    (!filters.is_empty()).then(|| format!("Filter: {}", filters.join(", ")))
+}
+
+/// Seconds until the next refresh, rounded up so a refresh that just happened shows
+/// the full interval.
+fn refresh_countdown(app: &App) -> String {
+    let remaining = app
+        .state
+        .refresh_interval
+        .saturating_sub(app.state.last_update.elapsed())
+        .as_secs_f64()
+        .ceil() as u64;
+    if remaining == 0 {
+        "Refreshing…".to_string()
+    } else {
+        format!("Refresh in {}s", remaining)
+    }
 }
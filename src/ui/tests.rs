@@ -0,0 +1,269 @@
+//! Renders known app states into a [`TestBackend`] and compares the screen against
+//! snapshots in `src/ui/snapshots`. A missing or different snapshot fails the test;
+//! set `UPDATE_SNAPSHOTS=1` to write new snapshots or accept intentional changes.
+//! Key handling that depends on loaded data is tested here too.
+
+use crate::app::{App, DialogType, Tab};
+use crate::files;
+use crate::script::client_record;
+use crate::source::fixtures::{client, details, device, site};
+use crate::source::MockSource;
+use crate::state::{fetch_snapshot, AppState, DetailFetch};
+use crate::ui::clients::handle_client_input;
+use crate::ui::devices::handle_device_input;
+use crate::ui::{page, render};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+use unifi_rs::UnifiClientBuilder;
+use uuid::Uuid;
+
+const WIDTH: u16 = 120;
+const HEIGHT: u16 = 40;
+
+/// An app with no controller data, that never touches the disk. A refresh is always
+/// due, so the status bar reads "Refreshing…" however long the test takes.
+async fn empty_app() -> App {
+    files::disable();
+    let client = UnifiClientBuilder::new("https://unifi.invalid".to_string())
+        .api_key("test")
+        .build()
+        .expect("client");
+    let state = AppState::new(client).await.expect("state");
+    let mut app = App::new(state).await.expect("app");
+    app.state.last_update = Instant::now() - app.state.refresh_interval;
+    app
+}
+
+/// One site with a gateway, a switch behind it and an access point behind that,
+/// loaded through a [`MockSource`] with the site selected.
+async fn loaded_app() -> App {
+    let mut app = empty_app().await;
+    let home = Uuid::new_v4();
+    let gateway = device("Gateway", "UDM-Pro");
+    let switch = device("Office Switch", "USW-24-PoE");
+    let ap = device("Hallway AP", "U6-Pro");
+    app.state.source = Arc::new(MockSource {
+        sites: vec![site(home, "Home")],
+        devices: HashMap::from([(home, vec![gateway.clone(), switch.clone(), ap.clone()])]),
+        clients: HashMap::from([(
+            home,
+            vec![
+                client("Laptop", "WIRELESS", ap.id),
+                client("Phone", "WIRELESS", ap.id),
+                client("Printer", "WIRED", switch.id),
+            ],
+        )]),
+        details: HashMap::from([
+            (gateway.id, details(&gateway, None)),
+            (switch.id, details(&switch, Some(gateway.id))),
+            (ap.id, details(&ap, Some(switch.id))),
+        ]),
+        ..Default::default()
+    });
+    app.state.load().await.expect("load");
+    app.state.set_site_context(Some(home));
+    app.state.load().await.expect("load site");
+    for page in page::all() {
+        page.refresh(&mut app);
+    }
+    app.state.last_update = Instant::now() - app.state.refresh_interval;
+    app
+}
+
+fn render_to_string(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, HEIGHT)).expect("terminal");
+    terminal.draw(|f| render(app, f)).expect("draw");
+
+    let buffer = terminal.backend().buffer();
+    let mut lines = Vec::with_capacity(HEIGHT as usize);
+    for y in 0..buffer.area.height {
+        let line: String = (0..buffer.area.width)
+            .map(|x| buffer[(x, y)].symbol())
+            .collect();
+        lines.push(line.trim_end().to_string());
+    }
+    lines.join("\n") + "\n"
+}
+
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/ui/snapshots")
+        .join(format!("{}.snap", name));
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).expect("snapshot dir");
+        std::fs::write(&path, actual).expect("write snapshot");
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "snapshot {} is missing, run with UPDATE_SNAPSHOTS=1 to create it\n{}",
+            name, actual
+        )
+    });
+    assert!(
+        expected == actual,
+        "snapshot {} differs, rerun with UPDATE_SNAPSHOTS=1 if the change is \
+         intended\n--- expected\n{}\n--- actual\n{}",
+        name,
+        expected,
+        actual
+    );
+}
+
+#[tokio::test]
+async fn tab_bar_shows_every_tab() {
+    let mut app = empty_app().await;
+    let screen = render_to_string(&mut app);
+    let tab_bar: String = screen.lines().take(3).collect();
+    for tab in Tab::ALL {
+        assert!(
+            tab_bar.contains(tab.title()),
+            "{} missing from tab bar",
+            tab.title()
+        );
+    }
+}
+
+#[tokio::test]
+async fn tabs_with_no_data() {
+    let mut app = empty_app().await;
+    for tab in Tab::ALL {
+        app.current_tab = tab;
+        let screen = render_to_string(&mut app);
+        assert_snapshot(&format!("tab_{}", tab.title().to_lowercase()), &screen);
+    }
+}
+
+#[tokio::test]
+async fn tabs_with_a_loaded_site() {
+    let mut app = loaded_app().await;
+    for tab in [Tab::Devices, Tab::Clients, Tab::Topology] {
+        app.current_tab = tab;
+        let screen = render_to_string(&mut app);
+        assert_snapshot(&format!("loaded_{}", tab.title().to_lowercase()), &screen);
+    }
+}
+
+#[tokio::test]
+async fn help_screen() {
+    let mut app = empty_app().await;
+    app.toggle_help();
+    assert_snapshot("help", &render_to_string(&mut app));
+}
+
+#[tokio::test]
+async fn alerts_panel_with_no_alerts() {
+    let mut app = empty_app().await;
+    app.toggle_alerts();
+    assert_snapshot("alerts_panel", &render_to_string(&mut app));
+}
+
+#[tokio::test]
+async fn restarting_the_gateway_in_low_power_needs_its_name() {
+    let mut app = empty_app().await;
+    let home = Uuid::new_v4();
+    let gateway = device("Gateway", "UDM-Pro");
+    let switch = device("Switch", "USW-24-PoE");
+    app.state.source = Arc::new(MockSource {
+        sites: vec![site(home, "Home")],
+        devices: HashMap::from([(home, vec![gateway.clone(), switch.clone()])]),
+        details: HashMap::from([
+            (gateway.id, details(&gateway, None)),
+            (switch.id, details(&switch, Some(gateway.id))),
+        ]),
+        ..Default::default()
+    });
+    app.state.load().await.expect("load");
+    app.state.set_site_context(Some(home));
+
+    let snapshot = fetch_snapshot(
+        app.state.source.clone(),
+        Some(home),
+        DetailFetch::Open(None),
+    );
+    app.state
+        .finish_refresh(snapshot.await)
+        .expect("low-power refresh");
+    assert!(!app.state.device_details.contains_key(&switch.id));
+
+    let row = app
+        .state
+        .filtered_devices
+        .iter()
+        .position(|d| d.id == gateway.id);
+    app.devices_table_state.select(row);
+    handle_device_input(&mut app, KeyEvent::from(KeyCode::Char('r')))
+        .await
+        .expect("restart key");
+
+    let dialog = app.dialog.as_ref().expect("restart dialog");
+    let DialogType::TypedConfirmation { expected } = &dialog.dialog_type else {
+        panic!("restarting the gateway only asked for a plain confirmation");
+    };
+    assert_eq!(expected, "Gateway");
+}
+
+fn device_names(app: &App) -> Vec<&str> {
+    app.state
+        .filtered_devices
+        .iter()
+        .map(|d| d.name.as_str())
+        .collect()
+}
+
+fn client_names(app: &App) -> Vec<String> {
+    app.state
+        .filtered_clients
+        .iter()
+        .map(|c| client_record(&app.state, c)["name"].to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn sort_key_cycles_device_order() {
+    let mut app = loaded_app().await;
+    let sort = KeyEvent::from(KeyCode::Char('s'));
+
+    handle_device_input(&mut app, sort).await.expect("sort");
+    assert_eq!(
+        device_names(&app),
+        ["Gateway", "Hallway AP", "Office Switch"]
+    );
+
+    handle_device_input(&mut app, sort).await.expect("sort");
+    assert_eq!(
+        device_names(&app),
+        ["Office Switch", "Hallway AP", "Gateway"]
+    );
+}
+
+#[tokio::test]
+async fn sort_key_cycles_client_order() {
+    let mut app = loaded_app().await;
+    let sort = KeyEvent::from(KeyCode::Char('s'));
+
+    handle_client_input(&mut app, sort).await.expect("sort");
+    assert_eq!(client_names(&app), ["Laptop", "Phone", "Printer"]);
+
+    handle_client_input(&mut app, sort).await.expect("sort");
+    assert_eq!(client_names(&app), ["Printer", "Phone", "Laptop"]);
+}
+
+#[tokio::test]
+async fn sorting_survives_a_search() {
+    let mut app = loaded_app().await;
+    let sort = KeyEvent::from(KeyCode::Char('s'));
+    handle_device_input(&mut app, sort).await.expect("sort");
+    handle_device_input(&mut app, sort).await.expect("sort");
+
+    app.state.search("pro");
+    app.sort_devices();
+    assert_eq!(device_names(&app), ["Hallway AP", "Gateway"]);
+}
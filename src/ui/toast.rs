@@ -0,0 +1,57 @@
+use crate::app::App;
+use crate::toast::ToastLevel;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+const TOAST_WIDTH: u16 = 50;
+const TOAST_HEIGHT: u16 = 3;
+const MAX_VISIBLE: usize = 4;
+
+pub fn render_toasts(f: &mut Frame, app: &App, area: Rect) {
+    let width = TOAST_WIDTH.min(area.width);
+    // Stack upwards from just above the status bar in the bottom-right corner.
+    let mut bottom = area.bottom().saturating_sub(1);
+
+    for toast in app.state.toasts.visible(MAX_VISIBLE) {
+        if bottom < area.y + TOAST_HEIGHT {
+            break;
+        }
+        let toast_area = Rect::new(
+            area.right().saturating_sub(width),
+            bottom - TOAST_HEIGHT,
+            width,
+            TOAST_HEIGHT,
+        );
+        bottom -= TOAST_HEIGHT;
+
+        let color = level_color(toast.level);
+        let title = if toast.count > 1 {
+            format!("{} (x{})", toast.level.label(), toast.count)
+        } else {
+            toast.level.label().to_string()
+        };
+
+        let widget = Paragraph::new(toast.message.as_str())
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(color))
+                    .title(title),
+            );
+
+        f.render_widget(Clear, toast_area);
+        f.render_widget(widget, toast_area);
+    }
+}
+
+fn level_color(level: ToastLevel) -> Color {
+    match level {
+        ToastLevel::Info => Color::Cyan,
+        ToastLevel::Success => Color::Green,
+        ToastLevel::Warning => Color::Yellow,
+        ToastLevel::Error => Color::Red,
+    }
+}
@@ -1,3 +1,4 @@
 mod node;
+mod painter;
 pub mod topology;
 pub mod topology_view;
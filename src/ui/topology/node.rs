@@ -44,6 +44,8 @@ impl NetworkNode {
         match &self.node_type {
             NodeType::Device { device_type, state } => {
                 let color = match state {
+                    // Gateways keep their own color while healthy so the root stands out
+                    DeviceState::Online if *device_type == DeviceType::Gateway => Color::Cyan,
                     DeviceState::Online => Color::Green,
                     DeviceState::Offline => Color::Red,
                     _ => Color::Yellow,
@@ -0,0 +1,53 @@
+use ratatui::style::Color;
+use ratatui::widgets::canvas::{Context, Line, Points};
+
+enum Shape {
+    Line(Line),
+    Points {
+        coords: Vec<(f64, f64)>,
+        color: Color,
+    },
+    Label {
+        x: f64,
+        y: f64,
+        text: String,
+    },
+}
+
+/// Canvas shapes recorded once and replayed on every frame, so node geometry is only
+/// computed again when the topology or the viewport changes.
+#[derive(Default)]
+pub struct Painter {
+    shapes: Vec<Shape>,
+}
+
+impl Painter {
+    pub fn draw_line(&mut self, line: Line) {
+        self.shapes.push(Shape::Line(line));
+    }
+
+    pub fn draw_points(&mut self, coords: Vec<(f64, f64)>, color: Color) {
+        self.shapes.push(Shape::Points { coords, color });
+    }
+
+    pub fn print(&mut self, x: f64, y: f64, text: impl Into<String>) {
+        self.shapes.push(Shape::Label {
+            x,
+            y,
+            text: text.into(),
+        });
+    }
+
+    pub fn paint(&self, ctx: &mut Context) {
+        for shape in &self.shapes {
+            match shape {
+                Shape::Line(line) => ctx.draw(line),
+                Shape::Points { coords, color } => ctx.draw(&Points {
+                    coords,
+                    color: *color,
+                }),
+                Shape::Label { x, y, text } => ctx.print(*x, *y, text.clone()),
+            }
+        }
+    }
+}
@@ -1,16 +1,30 @@
 use crate::app::App;
 use crate::ui::topology::node::NodeType;
 use crossterm::event::{KeyCode, KeyEvent, MouseEvent};
-use ratatui::prelude::{Modifier, Style};
+use ratatui::prelude::{Color, Modifier, Style};
 use ratatui::widgets::canvas::Canvas;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     symbols,
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
+const CLIENT_LIST_WIDTH: u16 = 32;
+
+/// Splits the topology area into the map and, in client focus mode, the client list.
+fn split_map_area(app: &App, area: Rect) -> (Rect, Option<Rect>) {
+    if app.topology_view.client_focus().is_none() {
+        return (area, None);
+    }
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(CLIENT_LIST_WIDTH)])
+        .split(area);
+    (chunks[0], Some(chunks[1]))
+}
+
 pub fn render_topology(f: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -33,6 +47,7 @@ pub fn render_topology(f: &mut Frame, app: &mut App, area: Rect) {
         .title("Network Map")
         .style(Style::default().remove_modifier(Modifier::RAPID_BLINK));
 
+    app.topology_view.prepare();
     let canvas = Canvas::default()
         .block(topology_block)
         .x_bounds([0.0, 100.0])
@@ -42,7 +57,11 @@ pub fn render_topology(f: &mut Frame, app: &mut App, area: Rect) {
             app.topology_view.render(ctx);
         });
 
-    f.render_widget(canvas, chunks[1]);
+    let (map_area, list_area) = split_map_area(app, chunks[1]);
+    f.render_widget(canvas, map_area);
+    if let Some(list_area) = list_area {
+        render_client_list(f, app, list_area);
+    }
 
     let selected_info = if let Some(node) = app.topology_view.get_selected_node() {
         match &node.node_type {
@@ -57,22 +76,108 @@ pub fn render_topology(f: &mut Frame, app: &mut App, area: Rect) {
         "No node selected".to_string()
     };
 
-    let help_text = vec![Line::from(vec![
-        Span::raw(selected_info),
-        Span::raw(" | "),
-        Span::raw("Mouse: Drag nodes | "),
-        Span::raw("+/-: Zoom | "),
-        Span::raw("r: Reset view | "),
-        Span::raw("Enter: Focus | "),
-        Span::raw("Esc: Back"),
-    ])];
+    let help_text = if app.topology_view.client_focus().is_some() {
+        vec![Line::from(vec![
+            Span::raw(selected_info),
+            Span::raw(" | "),
+            Span::raw("↑/↓: Select client | "),
+            Span::raw("←/→: Device | "),
+            Span::raw("Enter: Client details | "),
+            Span::raw("c/Esc: Close list"),
+        ])]
+    } else {
+        vec![Line::from(vec![
+            Span::raw(selected_info),
+            Span::raw(" | "),
+            Span::raw("Mouse: Drag nodes | "),
+            Span::raw("←/→: Device | "),
+            Span::raw("c: Clients | "),
+            Span::raw("+/-: Zoom | "),
+            Span::raw("r: Reset view | "),
+            Span::raw("L: Re-layout | "),
+            Span::raw("Enter: Focus | "),
+            Span::raw("Esc: Back"),
+        ])]
+    };
 
     let status_bar = Paragraph::new(help_text).block(Block::default().borders(Borders::ALL));
     f.render_widget(status_bar, chunks[2]);
 }
 
+fn render_client_list(f: &mut Frame, app: &App, area: Rect) {
+    let clients = app.topology_view.device_clients();
+    let items: Vec<ListItem> = clients
+        .iter()
+        .map(|node| {
+            let (_, color) = node.get_style();
+            ListItem::new(Line::from(vec![
+                Span::styled("● ", Style::default().fg(color)),
+                Span::raw(node.name.clone()),
+            ]))
+        })
+        .collect();
+
+    let device_name = app
+        .topology_view
+        .get_selected_node()
+        .map(|node| match node.node_type {
+            NodeType::Device { .. } => node.name.clone(),
+            NodeType::Client { .. } => "Uplink".to_string(),
+        })
+        .unwrap_or_default();
+    let title = format!("Clients - {} [{}]", device_name, clients.len());
+
+    if items.is_empty() {
+        let empty = Paragraph::new("No clients connected")
+            .style(Style::default().fg(Color::DarkGray))
+            .block(Block::default().borders(Borders::ALL).title(title));
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(
+            Style::default()
+                .bg(Color::Gray)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+
+    let mut list_state = ListState::default();
+    list_state.select(app.topology_view.client_focus());
+    f.render_stateful_widget(list, area, &mut list_state);
+}
+
 pub async fn handle_topology_input(app: &mut App, event: KeyEvent) -> anyhow::Result<()> {
+    if app.topology_view.client_focus().is_some() {
+        match event.code {
+            KeyCode::Up | KeyCode::Char('k') => app.topology_view.move_client_focus(false),
+            KeyCode::Down | KeyCode::Char('j') => app.topology_view.move_client_focus(true),
+            KeyCode::Left | KeyCode::Char('h') => app.topology_view.select_adjacent_device(false),
+            KeyCode::Right | KeyCode::Char('l') => app.topology_view.select_adjacent_device(true),
+            KeyCode::Enter => {
+                if let Some(client_id) = app.topology_view.focused_client() {
+                    app.select_client(Some(client_id));
+                }
+            }
+            KeyCode::Char('c') | KeyCode::Esc => app.topology_view.exit_client_focus(),
+            _ => {}
+        }
+        return Ok(());
+    }
+
     match event.code {
+        KeyCode::Left | KeyCode::Up => {
+            app.topology_view.select_adjacent_device(false);
+        }
+        KeyCode::Right | KeyCode::Down => {
+            app.topology_view.select_adjacent_device(true);
+        }
+        KeyCode::Char('c') => {
+            app.topology_view.toggle_client_focus();
+        }
         KeyCode::Char('+') | KeyCode::Char('=') => {
             app.topology_view.zoom_in();
         }
@@ -82,6 +187,9 @@ pub async fn handle_topology_input(app: &mut App, event: KeyEvent) -> anyhow::Re
         KeyCode::Char('r') => {
             app.topology_view.reset_view();
         }
+        KeyCode::Char('L') => {
+            app.topology_view.relayout();
+        }
         KeyCode::Enter => {
             if let Some(node) = app.topology_view.get_selected_node() {
                 match node.node_type {
@@ -107,6 +215,7 @@ pub async fn handle_topology_mouse(
     event: MouseEvent,
     area: Rect,
 ) -> anyhow::Result<()> {
-    app.topology_view.handle_mouse_event(event, area);
+    let (map_area, _) = split_map_area(app, area);
+    app.topology_view.handle_mouse_event(event, map_area);
     Ok(())
 }
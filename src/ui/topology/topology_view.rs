@@ -1,15 +1,20 @@
+use crate::state::{has_gateway_feature, is_gateway_model};
 use crate::ui::topology::node::{ClientType, DeviceType, NetworkNode, NodeType};
+use crate::ui::topology::painter::Painter;
 use crossterm::event::{MouseEvent, MouseEventKind};
 use ratatui::{
     layout::Rect,
     style::Color,
-    widgets::canvas::{Context, Line, Points},
+    widgets::canvas::{Context, Line},
 };
-use std::collections::HashMap;
-use unifi_rs::device::{DeviceDetails, DeviceOverview};
+use std::collections::{HashMap, HashSet};
+use unifi_rs::device::{DeviceDetails, DeviceOverview, DeviceState};
 use unifi_rs::models::client::ClientOverview;
 use uuid::Uuid;
 
+/// Space kept free around the tree on every side of the canvas.
+const LAYOUT_MARGIN: f64 = 10.0;
+
 pub struct TopologyView {
     nodes: HashMap<Uuid, NetworkNode>,
     selected_node: Option<Uuid>,
@@ -18,6 +23,10 @@ pub struct TopologyView {
     pan_offset: (f64, f64),
     zoom: f64,
     canvas_dimensions: (f64, f64),
+    client_focus: Option<usize>,
+    /// Nodes the user dragged; they keep their position across refreshes.
+    pinned: HashSet<Uuid>,
+    painted: Option<Painter>,
 }
 
 impl TopologyView {
@@ -30,6 +39,9 @@ impl TopologyView {
             pan_offset: (0.0, 0.0),
             zoom: 1.0,
             canvas_dimensions: (100.0, 100.0),
+            client_focus: None,
+            pinned: HashSet::new(),
+            painted: None,
         }
     }
 }
@@ -42,11 +54,18 @@ impl TopologyView {
         clients: &[ClientOverview],
         device_details: &HashMap<Uuid, DeviceDetails>,
     ) {
-        self.nodes.clear();
-        
+        let previous = std::mem::take(&mut self.nodes);
+        self.invalidate();
+
         // Create nodes for devices
         for device in devices {
-            let device_type = if device.features.contains(&"accessPoint".to_string()) {
+            let parent_id = device_details
+                .get(&device.id)
+                .and_then(|d| d.uplink.as_ref().map(|u| u.device_id));
+
+            let device_type = if is_gateway(device, parent_id.is_some()) {
+                DeviceType::Gateway
+            } else if device.features.contains(&"accessPoint".to_string()) {
                 DeviceType::AccessPoint
             } else if device.features.contains(&"switching".to_string()) {
                 DeviceType::Switch
@@ -54,10 +73,6 @@ impl TopologyView {
                 DeviceType::Other
             };
 
-            let parent_id = device_details
-                .get(&device.id)
-                .and_then(|d| d.uplink.as_ref().map(|u| u.device_id));
-
             self.nodes.insert(
                 device.id,
                 NetworkNode {
@@ -120,52 +135,151 @@ impl TopologyView {
             }
         }
 
-        self.initialize_layout();
+        // Only lay the tree out again when devices or clients came, went or moved uplinks;
+        // otherwise every node keeps the position it had before the refresh.
+        let structure_changed = self.nodes.len() != previous.len()
+            || self.nodes.values().any(|node| {
+                previous
+                    .get(&node.id)
+                    .is_none_or(|old| old.parent_id != node.parent_id)
+            });
+        if structure_changed {
+            self.initialize_layout();
+        }
+        for node in self.nodes.values_mut() {
+            if let Some(old) = previous.get(&node.id) {
+                if !structure_changed || self.pinned.contains(&node.id) {
+                    node.x = old.x;
+                    node.y = old.y;
+                }
+            }
+        }
+
+        let nodes = &self.nodes;
+        self.pinned.retain(|id| nodes.contains_key(id));
+        self.selected_node = self.selected_node.filter(|id| nodes.contains_key(id));
+        self.dragging_node = self.dragging_node.filter(|id| nodes.contains_key(id));
     }
 
     pub fn initialize_layout(&mut self) {
+        self.invalidate();
+
         // Find  root nodes (nodes without a parent or with a parent that doesn't exist) like our gateway device
-        let root_nodes: Vec<Uuid> = self
+        let mut root_nodes: Vec<Uuid> = self
             .nodes
             .values()
-            .filter(|n| n.parent_id.is_none() || !self.nodes.contains_key(&n.parent_id.unwrap()))
+            .filter(|n| n.parent_id.is_none_or(|p| !self.nodes.contains_key(&p)))
             .map(|n| n.id)
             .collect();
+        // Gateways lead the tree, the rest follow by name
+        root_nodes.sort_by_key(|id| {
+            let node = &self.nodes[id];
+            let gateway = matches!(
+                node.node_type,
+                NodeType::Device {
+                    device_type: DeviceType::Gateway,
+                    ..
+                }
+            );
+            (!gateway, node.name.to_lowercase())
+        });
 
-        // Place root nodes at the top of the canvas to mimic unifi tree layout
-        let root_spacing = 100.0 / (root_nodes.len() + 1) as f64;
-        for (i, id) in root_nodes.iter().enumerate() {
-            if let Some(node) = self.nodes.get_mut(id) {
-                node.x = root_spacing * (i + 1) as f64;
-                node.y = 20.0;
-            }
+        // Every leaf gets its own horizontal slot so siblings never overlap, and the
+        // vertical step shrinks with depth so deep chains still fit on the canvas.
+        let mut leaves = HashMap::new();
+        let mut max_depth = 0;
+        let mut visited = HashSet::new();
+        for id in &root_nodes {
+            self.measure_subtree(*id, 0, &mut leaves, &mut max_depth, &mut visited);
         }
 
-        // iter through root nodes and layout their children
+        let total_leaves: usize = root_nodes.iter().map(|id| leaves[id]).sum();
+        let slot_width = (100.0 - 2.0 * LAYOUT_MARGIN) / total_leaves.max(1) as f64;
+        let level_height = (100.0 - 2.0 * LAYOUT_MARGIN) / max_depth.max(1) as f64;
+
+        let mut next_slot = 0;
+        let mut visited = HashSet::new();
         for root_id in root_nodes {
-            self.layout_children(root_id, 1);
+            self.layout_children(
+                root_id,
+                0,
+                (slot_width, level_height),
+                &leaves,
+                &mut next_slot,
+                &mut visited,
+            );
         }
     }
 
-    fn layout_children(&mut self, node_id: Uuid, depth: usize) {
-        if let Some(node) = self.nodes.get(&node_id) {
-            let children = node.children.clone();
-            let child_count = children.len();
+    /// Records the number of leaves under each node and the deepest level reached.
+    fn measure_subtree(
+        &self,
+        node_id: Uuid,
+        depth: usize,
+        leaves: &mut HashMap<Uuid, usize>,
+        max_depth: &mut usize,
+        visited: &mut HashSet<Uuid>,
+    ) -> usize {
+        // Guard against uplink loops reported by the controller
+        if !visited.insert(node_id) {
+            return 0;
+        }
+        *max_depth = (*max_depth).max(depth);
 
-            if child_count > 0 {
-                let parent_x = node.x;
-                let spacing = 100.0 / (child_count + 1) as f64;
-                let y = 20.0 + (depth as f64 * 20.0);
+        let children = self
+            .nodes
+            .get(&node_id)
+            .map(|n| n.children.clone())
+            .unwrap_or_default();
+        let count = children
+            .iter()
+            .map(|child| self.measure_subtree(*child, depth + 1, leaves, max_depth, visited))
+            .sum::<usize>()
+            .max(1);
+        leaves.insert(node_id, count);
+        count
+    }
 
-                for (i, child_id) in children.iter().enumerate() {
-                    if let Some(child) = self.nodes.get_mut(child_id) {
-                        child.x = parent_x - 50.0 + (spacing * (i + 1) as f64);
-                        child.y = y;
-                    }
-                    self.layout_children(*child_id, depth + 1);
-                }
-            }
+    fn layout_children(
+        &mut self,
+        node_id: Uuid,
+        depth: usize,
+        (slot_width, level_height): (f64, f64),
+        leaves: &HashMap<Uuid, usize>,
+        next_slot: &mut usize,
+        visited: &mut HashSet<Uuid>,
+    ) {
+        if !visited.insert(node_id) {
+            return;
+        }
+        let Some(node) = self.nodes.get(&node_id) else {
+            return;
+        };
+
+        // Centre the node over the slots taken by its subtree
+        let mut children = node.children.clone();
+        children.sort_by_key(|id| self.nodes.get(id).map(|n| n.name.to_lowercase()));
+        let width = leaves.get(&node_id).copied().unwrap_or(1);
+        let x = LAYOUT_MARGIN + slot_width * (*next_slot as f64 + width as f64 / 2.0);
+        let y = LAYOUT_MARGIN + level_height * depth as f64;
+
+        if let Some(node) = self.nodes.get_mut(&node_id) {
+            node.x = x;
+            node.y = y;
         }
+
+        let start_slot = *next_slot;
+        for child_id in children {
+            self.layout_children(
+                child_id,
+                depth + 1,
+                (slot_width, level_height),
+                leaves,
+                next_slot,
+                visited,
+            );
+        }
+        *next_slot = start_slot + width;
     }
 }
 
@@ -174,6 +288,7 @@ impl TopologyView {
     pub fn handle_mouse_event(&mut self, event: MouseEvent, area: Rect) {
         match event.kind {
             MouseEventKind::Down(_) => {
+                self.invalidate();
                 let canvas_x = (event.column.saturating_sub(area.x + 1) as f64 * 100.0)
                     / (area.width.saturating_sub(2) as f64);
                 let canvas_y = (event.row.saturating_sub(area.y + 1) as f64 * 100.0)
@@ -187,6 +302,7 @@ impl TopologyView {
                 self.dragging_node = None;
             }
             MouseEventKind::Drag(_) => {
+                self.invalidate();
                 let dx = (event.column as i32 - self.last_mouse_pos.0 as i32) as f64;
                 let dy = (event.row as i32 - self.last_mouse_pos.1 as i32) as f64;
 
@@ -195,6 +311,7 @@ impl TopologyView {
                 let world_dy = -dy * self.canvas_dimensions.1 / (area.height as f64 * self.zoom);
 
                 if let Some(id) = self.dragging_node {
+                    self.pinned.insert(id);
                     if let Some(node) = self.nodes.get_mut(&id) {
                         node.x = (node.x + world_dx).clamp(0.0, self.canvas_dimensions.0);
                         node.y = (node.y + world_dy).clamp(0.0, self.canvas_dimensions.1);
@@ -246,39 +363,93 @@ impl TopologyView {
 
 /// Rendering
 impl TopologyView {
+    /// Records the shapes for the current nodes and viewport unless they are already cached.
+    pub fn prepare(&mut self) {
+        if self.painted.is_none() {
+            let mut painter = Painter::default();
+            self.paint_nodes(&mut painter);
+            self.painted = Some(painter);
+        }
+    }
+
     pub fn render(&self, ctx: &mut Context) {
+        match &self.painted {
+            Some(painter) => painter.paint(ctx),
+            None => {
+                let mut painter = Painter::default();
+                self.paint_nodes(&mut painter);
+                painter.paint(ctx);
+            }
+        }
+    }
+
+    /// Drops the cached shapes; the next frame records them again.
+    fn invalidate(&mut self) {
+        self.painted = None;
+    }
+
+    fn paint_nodes(&self, painter: &mut Painter) {
+        // Devices that lost their uplink are tied back to the gateway with a broken link
+        let gateway = self
+            .nodes
+            .values()
+            .find(|n| {
+                matches!(
+                    n.node_type,
+                    NodeType::Device {
+                        device_type: DeviceType::Gateway,
+                        ..
+                    }
+                )
+            })
+            .map(|n| self.to_view(n));
+
         // start by drawing the connections between nodes first since tree layout is top-down
         for node in self.nodes.values() {
-            if let Some(parent_id) = node.parent_id {
-                if let Some(parent) = self.nodes.get(&parent_id) {
-                    let (x1, y1) = (
-                        (node.x - self.pan_offset.0) * self.zoom,
-                        (node.y - self.pan_offset.1) * self.zoom,
-                    );
-                    let (x2, y2) = (
-                        (parent.x - self.pan_offset.0) * self.zoom,
-                        (parent.y - self.pan_offset.1) * self.zoom,
-                    );
-
-                    let color = match node.node_type {
-                        NodeType::Client {
-                            client_type: ClientType::Wireless,
-                        } => Color::Yellow,
-                        NodeType::Client {
-                            client_type: ClientType::Wired,
-                        } => Color::Blue,
-                        _ => Color::Gray,
-                    };
-
-                    ctx.draw(&Line {
-                        x1,
-                        y1,
-                        x2,
-                        y2,
-                        color,
-                    });
+            let (x1, y1) = self.to_view(node);
+
+            let Some(parent) = node.parent_id.and_then(|id| self.nodes.get(&id)) else {
+                // A device with no known uplink that is not itself the gateway
+                let orphaned = match &node.node_type {
+                    NodeType::Device { device_type, .. } => *device_type != DeviceType::Gateway,
+                    NodeType::Client { .. } => false,
+                };
+                if orphaned {
+                    match gateway {
+                        Some((x2, y2)) => dashed_line(painter, (x1, y1), (x2, y2), Color::Red),
+                        None => {
+                            let stub = (x1, y1 - 6.0 * self.zoom);
+                            dashed_line(painter, (x1, y1), stub, Color::Red);
+                            painter.print(stub.0, stub.1, "?");
+                        }
+                    }
                 }
+                continue;
+            };
+            let (x2, y2) = self.to_view(parent);
+
+            if is_offline(node) || is_offline(parent) {
+                dashed_line(painter, (x1, y1), (x2, y2), Color::Red);
+                continue;
             }
+
+            let color = match node.node_type {
+                NodeType::Client {
+                    client_type: ClientType::Wireless,
+                } => Color::Yellow,
+                NodeType::Client {
+                    client_type: ClientType::Wired,
+                } => Color::Blue,
+                _ => Color::Gray,
+            };
+
+            painter.draw_line(Line {
+                x1,
+                y1,
+                x2,
+                y2,
+                color,
+            });
         }
 
         // Draw nodes on top of connections
@@ -286,13 +457,20 @@ impl TopologyView {
             let selected = Some(*id) == self.selected_node;
 
             let (shape, color) = node.get_style();
-            self.draw_node(ctx, node, shape, color, selected);
+            self.draw_node(painter, node, shape, color, selected);
         }
     }
 
+    fn to_view(&self, node: &NetworkNode) -> (f64, f64) {
+        (
+            (node.x - self.pan_offset.0) * self.zoom,
+            (node.y - self.pan_offset.1) * self.zoom,
+        )
+    }
+
     fn draw_node(
         &self,
-        ctx: &mut Context,
+        painter: &mut Painter,
         node: &NetworkNode,
         shape: &str,
         color: Color,
@@ -308,12 +486,21 @@ impl TopologyView {
                 for i in 0..3 {
                     let radius = size - (i as f64 * 0.5 * self.zoom);
                     let points = circle(x, y, radius);
-                    ctx.draw(&Points {
-                        coords: &points,
-                        color,
-                    });
+                    painter.draw_points(points, color);
                 }
             }
+            "gateway" => {
+                let points = [(x, y + size), (x + size, y), (x, y - size), (x - size, y)];
+                square(painter, color, &points);
+                let inner = size * 0.5;
+                let points = [
+                    (x, y + inner),
+                    (x + inner, y),
+                    (x, y - inner),
+                    (x - inner, y),
+                ];
+                square(painter, color, &points);
+            }
             "switch" => {
                 let points = [
                     (x - size, y - size / 2.0),
@@ -321,23 +508,17 @@ impl TopologyView {
                     (x + size, y + size / 2.0),
                     (x - size, y + size / 2.0),
                 ];
-                square(ctx, color, &points);
+                square(painter, color, &points);
             }
             "wireless" => {
-                ctx.draw(&Points {
-                    coords: &[(x, y)],
-                    color,
-                });
+                painter.draw_points(vec![(x, y)], color);
                 let points: Vec<(f64, f64)> = (0..8)
                     .map(|i| {
                         let angle = (i as f64) * std::f64::consts::PI / 4.0;
                         (x + angle.cos() * size * 0.8, y + angle.sin() * size * 0.8)
                     })
                     .collect();
-                ctx.draw(&Points {
-                    coords: &points,
-                    color,
-                });
+                painter.draw_points(points, color);
             }
             "wired" => {
                 let points = [
@@ -346,31 +527,116 @@ impl TopologyView {
                     (x + size * 0.5, y + size * 0.5),
                     (x - size * 0.5, y + size * 0.5),
                 ];
-                square(ctx, color, &points);
+                square(painter, color, &points);
             }
             _ => {
                 let points = circle(x, y, size);
-                ctx.draw(&Points {
-                    coords: &points,
-                    color,
-                });
+                painter.draw_points(points, color);
             }
         }
 
         // Selected we found a hit
         if selected {
             // Inidcate to the user that the node is selected
-            ctx.draw(&Points {
-                coords: &[(x, y)],
-                color: Color::White,
-            });
+            painter.draw_points(vec![(x, y)], Color::White);
         }
 
         // The node label 
         let label_y = y + size * 2.0;
         let label = node.name.clone();
         let label_x = x - (label.len() as f64 * 0.4 * self.zoom);
-        ctx.print(label_x, label_y, label);
+        painter.print(label_x, label_y, label);
+    }
+}
+
+/// Keyboard Navigation
+impl TopologyView {
+    /// Moves the selection to the next or previous device, ordered by name.
+    pub fn select_adjacent_device(&mut self, forward: bool) {
+        let mut devices: Vec<&NetworkNode> = self
+            .nodes
+            .values()
+            .filter(|n| matches!(n.node_type, NodeType::Device { .. }))
+            .collect();
+        if devices.is_empty() {
+            return;
+        }
+        devices.sort_by_cached_key(|d| d.name.to_lowercase());
+
+        let current = self
+            .selected_device()
+            .and_then(|id| devices.iter().position(|n| n.id == id));
+        let next = match current {
+            Some(i) if forward => (i + 1) % devices.len(),
+            Some(i) => (i + devices.len() - 1) % devices.len(),
+            None => 0,
+        };
+        self.selected_node = Some(devices[next].id);
+        self.invalidate();
+        if self.client_focus.is_some() {
+            self.client_focus = Some(0);
+        }
+    }
+
+    /// The selected device, or the uplink device when a client is selected.
+    fn selected_device(&self) -> Option<Uuid> {
+        let node = self.get_selected_node()?;
+        match node.node_type {
+            NodeType::Device { .. } => Some(node.id),
+            NodeType::Client { .. } => node.parent_id,
+        }
+    }
+
+    /// Clients connected to the selected device, ordered by name.
+    pub fn device_clients(&self) -> Vec<&NetworkNode> {
+        let Some(device) = self.selected_device().and_then(|id| self.nodes.get(&id)) else {
+            return Vec::new();
+        };
+        let mut clients: Vec<&NetworkNode> = device
+            .children
+            .iter()
+            .filter_map(|id| self.nodes.get(id))
+            .filter(|n| matches!(n.node_type, NodeType::Client { .. }))
+            .collect();
+        clients.sort_by_cached_key(|c| c.name.to_lowercase());
+        clients
+    }
+
+    pub fn toggle_client_focus(&mut self) {
+        self.client_focus = match self.client_focus {
+            Some(_) => None,
+            None if self.selected_device().is_some() => Some(0),
+            None => None,
+        };
+    }
+
+    pub fn exit_client_focus(&mut self) {
+        self.client_focus = None;
+    }
+
+    /// Index of the highlighted client in the side list, clamped to the current clients.
+    pub fn client_focus(&self) -> Option<usize> {
+        let index = self.client_focus?;
+        let count = self.device_clients().len();
+        Some(index.min(count.saturating_sub(1)))
+    }
+
+    pub fn move_client_focus(&mut self, forward: bool) {
+        let count = self.device_clients().len();
+        if let Some(index) = self.client_focus() {
+            if count > 0 {
+                self.client_focus = Some(if forward {
+                    (index + 1) % count
+                } else {
+                    (index + count - 1) % count
+                });
+            }
+        }
+    }
+
+    pub fn focused_client(&self) -> Option<Uuid> {
+        let index = self.client_focus()?;
+        self.device_clients().get(index).map(|n| n.id)
     }
 }
 
@@ -382,14 +648,24 @@ impl TopologyView {
 
     pub fn zoom_in(&mut self) {
         self.zoom = (self.zoom * 1.2).min(5.0);
+        self.invalidate();
     }
 
     pub fn zoom_out(&mut self) {
         self.zoom = (self.zoom / 1.2).max(0.2);
+        self.invalidate();
     }
+
+    /// Recomputes node positions, discarding dragged positions but keeping zoom and pan.
+    pub fn relayout(&mut self) {
+        self.pinned.clear();
+        self.initialize_layout();
+    }
+
     pub fn reset_view(&mut self) {
         self.zoom = 1.0;
         self.pan_offset = (0.0, 0.0);
+        self.pinned.clear();
         self.initialize_layout();
 
         let mut min_x = f64::MAX;
@@ -411,6 +687,41 @@ impl TopologyView {
     }
 }
 
+fn is_gateway(device: &DeviceOverview, has_uplink: bool) -> bool {
+    is_gateway_model(device) || (!has_uplink && has_gateway_feature(device))
+}
+
+fn is_offline(node: &NetworkNode) -> bool {
+    matches!(
+        node.node_type,
+        NodeType::Device {
+            state: DeviceState::Offline,
+            ..
+        }
+    )
+}
+
+/// Draws a line as alternating dashes of roughly two canvas units.
+fn dashed_line(painter: &mut Painter, from: (f64, f64), to: (f64, f64), color: Color) {
+    let length = ((to.0 - from.0).powi(2) + (to.1 - from.1).powi(2)).sqrt();
+    let segments = ((length / 2.0).ceil() as usize).max(1);
+    let point = |i: usize| {
+        let t = i as f64 / segments as f64;
+        (from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t)
+    };
+    for i in (0..segments).step_by(2) {
+        let (x1, y1) = point(i);
+        let (x2, y2) = point(i + 1);
+        painter.draw_line(Line {
+            x1,
+            y1,
+            x2,
+            y2,
+            color,
+        });
+    }
+}
+
 fn circle(x: f64, y: f64, size: f64) -> Vec<(f64, f64)> {
     let points: Vec<(f64, f64)> = (0..16)
         .map(|i| {
@@ -421,9 +732,9 @@ fn circle(x: f64, y: f64, size: f64) -> Vec<(f64, f64)> {
     points
 }
 
-fn square(ctx: &mut Context, color: Color, points: &[(f64, f64); 4]) {
+fn square(painter: &mut Painter, color: Color, points: &[(f64, f64); 4]) {
     for i in 0..points.len() {
-        ctx.draw(&Line {
+        painter.draw_line(Line {
             x1: points[i].0,
             y1: points[i].1,
             x2: points[(i + 1) % points.len()].0,
@@ -0,0 +1,67 @@
+use crate::app::App;
+use crate::ui::centered_rect;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, Cell, Clear, Row, Table};
+use ratatui::Frame;
+
+pub fn render_traceroute_panel(f: &mut Frame, app: &App, area: Rect) {
+    let Some(traceroute) = &app.traceroute else {
+        return;
+    };
+    let panel_area = centered_rect(70, 20, area);
+
+    let header = Row::new(vec!["Hop", "Address", "RTT"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = traceroute
+        .hops
+        .iter()
+        .map(|hop| {
+            let rtt = match hop.rtt_ms {
+                Some(ms) => Cell::from(format!("{:.1} ms", ms)).style(match ms {
+                    ms if ms < 20.0 => Style::default().fg(Color::Green),
+                    ms if ms < 100.0 => Style::default().fg(Color::Yellow),
+                    _ => Style::default().fg(Color::Red),
+                }),
+                None => Cell::from("*").style(Style::default().fg(Color::DarkGray)),
+            };
+            Row::new(vec![
+                Cell::from(hop.ttl.to_string()),
+                Cell::from(hop.address.clone().unwrap_or_else(|| "*".to_string())),
+                rtt,
+            ])
+        })
+        .collect();
+
+    let status = match (&traceroute.error, traceroute.finished) {
+        (Some(error), _) => error.clone(),
+        (None, true) => "Done".to_string(),
+        (None, false) => "Running...".to_string(),
+    };
+    let title = format!(
+        "Traceroute to {} ({}) - {} (Esc: Close)",
+        traceroute.label, traceroute.target, status
+    );
+
+    let widths = [
+        Constraint::Length(5),
+        Constraint::Percentage(60),
+        Constraint::Percentage(30),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(Clear, panel_area);
+    f.render_widget(table, panel_area);
+}
+
+pub async fn handle_traceroute_input(app: &mut App, key: KeyEvent) -> anyhow::Result<()> {
+    if key.code == KeyCode::Esc {
+        app.traceroute = None;
+    }
+    Ok(())
+}
@@ -0,0 +1,246 @@
+use chrono::{DateTime, Utc};
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::symbols;
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Clear, Dataset, GraphType, Paragraph};
+use ratatui::Frame;
+use std::ops::Range;
+
+/// Fewest samples a zoomed chart shows.
+const MIN_WINDOW: usize = 8;
+/// Fewest samples before short spikes are left out of the Y scale.
+const MIN_SAMPLES_FOR_CLIPPING: usize = 20;
+
+/// Inspection cursor shared by the history charts. It is kept as an offset from
+/// the newest sample so it stays on the same spot while new samples arrive.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ChartCursor {
+    pub offset: usize,
+}
+
+impl ChartCursor {
+    /// Index of the sample under the cursor in a series of `len` samples.
+    pub fn index(&self, len: usize) -> Option<usize> {
+        len.checked_sub(self.offset + 1)
+    }
+
+    pub fn older(&mut self, len: usize) {
+        if self.offset + 1 < len {
+            self.offset += 1;
+        }
+    }
+
+    pub fn newer(&mut self) {
+        self.offset = self.offset.saturating_sub(1);
+    }
+
+    /// Keeps the cursor on a sample after the series shrank to `len` samples.
+    pub fn clamp(&mut self, len: usize) {
+        self.offset = self.offset.min(len.saturating_sub(1));
+    }
+}
+
+/// Zoom and pan state of a history chart, counted in samples back from the newest
+/// one so a zoomed window keeps following new data unless it has been panned.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ChartWindow {
+    /// Number of samples shown; `None` shows the whole buffer.
+    pub width: Option<usize>,
+    /// Samples hidden at the newest end.
+    pub pan: usize,
+}
+
+impl ChartWindow {
+    /// Range of the samples visible in a series of `len` samples.
+    pub fn range(&self, len: usize) -> Range<usize> {
+        let width = self.width.unwrap_or(len).min(len);
+        let end = len - self.pan.min(len - width);
+        end - width..end
+    }
+
+    pub fn zoom_in(&mut self, len: usize) {
+        let width = self.width.unwrap_or(len);
+        if width > MIN_WINDOW {
+            self.width = Some((width / 2).max(MIN_WINDOW));
+        }
+    }
+
+    pub fn zoom_out(&mut self, len: usize) {
+        if let Some(width) = self.width {
+            if width * 2 >= len {
+                *self = Self::default();
+            } else {
+                self.width = Some(width * 2);
+            }
+        }
+    }
+
+    pub fn pan_older(&mut self, len: usize) {
+        if let Some(width) = self.width {
+            self.pan = (self.pan + Self::step(width)).min(len.saturating_sub(width));
+        }
+    }
+
+    pub fn pan_newer(&mut self) {
+        if let Some(width) = self.width {
+            self.pan = self.pan.saturating_sub(Self::step(width));
+        }
+    }
+
+    fn step(width: usize) -> usize {
+        (width / 4).max(1)
+    }
+
+    /// Chart title with the zoom level appended while zoomed.
+    pub fn title(&self, title: &str) -> String {
+        match (self.width, self.pan) {
+            (None, _) => title.to_string(),
+            (Some(width), 0) => format!("{} [{} samples]", title, width),
+            (Some(width), pan) => format!("{} [{} samples, {} back]", title, width, pan),
+        }
+    }
+}
+
+/// X axis labels for the oldest, middle and newest visible samples.
+pub fn time_labels<'a>(first: DateTime<Utc>, last: DateTime<Utc>) -> Vec<Line<'a>> {
+    let middle = first + (last - first) / 2;
+    [first, middle, last]
+        .into_iter()
+        .map(|timestamp| Line::from(age_label(timestamp)))
+        .collect()
+}
+
+fn age_label(timestamp: DateTime<Utc>) -> String {
+    let seconds = (Utc::now() - timestamp).num_seconds();
+    if seconds < 5 {
+        "now".to_string()
+    } else if seconds < 120 {
+        format!("{}s ago", seconds)
+    } else {
+        format!("{}m ago", seconds / 60)
+    }
+}
+
+/// Y axis rounded up to a step of 1, 2, 2.5 or 5 times a power of ten, giving four or
+/// five evenly spaced labels that read as round numbers.
+#[derive(Debug, Clone, Copy)]
+pub struct YScale {
+    step: f64,
+    intervals: usize,
+}
+
+impl YScale {
+    pub fn new(max_value: f64) -> Self {
+        // Steps stay whole numbers, as counts and bit rates are.
+        let max_value = max_value.max(4.0);
+        let magnitude = 10f64.powf((max_value / 4.0).log10().floor());
+        let step = [1.0, 2.0, 2.5, 5.0, 10.0]
+            .into_iter()
+            .map(|m| m * magnitude)
+            .filter(|step| step.fract() == 0.0)
+            .find(|step| step * 4.0 >= max_value)
+            .unwrap_or(10.0 * magnitude);
+        let intervals = ((max_value / step).ceil() as usize).max(3);
+        Self { step, intervals }
+    }
+
+    pub fn max(&self) -> f64 {
+        self.step * self.intervals as f64
+    }
+
+    pub fn bounds(&self) -> [f64; 2] {
+        [0.0, self.max()]
+    }
+
+    pub fn labels<'a>(&self, format: impl Fn(f64) -> String) -> Vec<Line<'a>> {
+        (0..=self.intervals)
+            .map(|i| Line::from(format(i as f64 * self.step)))
+            .collect()
+    }
+}
+
+/// Largest value the Y scale has to fit. When the peak is more than twice the 95th
+/// percentile it is treated as a spike: the scale follows the percentile instead and
+/// the second value is `true` so the chart can say its peaks are clipped.
+pub fn scale_max(values: impl IntoIterator<Item = f64>) -> (f64, bool) {
+    let mut values: Vec<f64> = values.into_iter().filter(|v| v.is_finite()).collect();
+    values.sort_by(f64::total_cmp);
+    let Some(&max) = values.last() else {
+        return (0.0, false);
+    };
+    if values.len() < MIN_SAMPLES_FOR_CLIPPING {
+        return (max, false);
+    }
+
+    let p95 = values[((values.len() - 1) as f64 * 0.95).round() as usize];
+    if p95 > 0.0 && max > p95 * 2.0 {
+        (p95 * 1.25, true)
+    } else {
+        (max, false)
+    }
+}
+
+/// X axis bounds for `len` samples, kept non-empty while there is only one sample.
+pub fn sample_bounds(len: usize) -> [f64; 2] {
+    [0.0, len.saturating_sub(1).max(1) as f64]
+}
+
+/// Placeholder drawn in place of a chart that has no samples yet.
+pub fn render_empty_chart(f: &mut Frame, area: Rect, title: String, border_style: Style) {
+    let placeholder = Paragraph::new(vec![
+        Line::from(""),
+        Line::from("No samples yet"),
+        Line::styled(
+            "History fills in with every refresh",
+            Style::default().fg(Color::DarkGray),
+        ),
+    ])
+    .alignment(Alignment::Center)
+    .block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(border_style),
+    );
+    f.render_widget(placeholder, area);
+}
+
+/// Two points forming a vertical line at `x`, to be drawn with [`cursor_dataset`].
+pub fn cursor_line(x: f64, max_y: f64) -> [(f64, f64); 2] {
+    [(x, 0.0), (x, max_y)]
+}
+
+pub fn cursor_dataset(line: &[(f64, f64)]) -> Dataset<'_> {
+    Dataset::default()
+        .marker(symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(Color::White))
+        .data(line)
+}
+
+pub fn focus_border(focused: bool) -> Style {
+    if focused {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default()
+    }
+}
+
+/// Draws the values under the cursor in a small box at the top-left of the chart.
+pub fn render_cursor_legend(f: &mut Frame, chart_area: Rect, lines: Vec<Line>) {
+    let width = lines.iter().map(|l| l.width()).max().unwrap_or(0) as u16 + 2;
+    let height = lines.len() as u16 + 2;
+    let area = Rect::new(
+        chart_area.x + 1,
+        chart_area.y + 1,
+        width.min(chart_area.width.saturating_sub(2)),
+        height.min(chart_area.height.saturating_sub(2)),
+    );
+
+    f.render_widget(Clear, area);
+    f.render_widget(
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL)),
+        area,
+    );
+}
@@ -1,12 +1,14 @@
-use crate::state::AppState;
+use crate::oui;
+use crate::state::{frequency_label, AppState};
+use crate::ui::panels::scale_fixed_rows;
 use chrono::{DateTime, Utc};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
 use ratatui::Frame;
-use unifi_rs::common::{FrequencyBand, PortState};
-use unifi_rs::device::DeviceState;
+use unifi_rs::common::PortState;
+use unifi_rs::device::{DeviceOverview, DeviceState};
 use unifi_rs::models::client::{ClientOverview, WiredClientOverview, WirelessClientOverview};
 use uuid::Uuid;
 
@@ -23,22 +25,34 @@ impl<'a> ClientStatsView<'a> {
         }
     }
 
-    pub fn render(&self, f: &mut Frame, area: Rect) {
+    /// `info_share` is the percentage of the height for the boxes above the charts.
+    pub fn render(&self, f: &mut Frame, area: Rect, info_share: Option<u16>) {
         if let Some(client) = self.app_state.clients.iter().find(|c| match c {
             ClientOverview::Wireless(w) => w.base.id == self.client_id,
             ClientOverview::Wired(w) => w.base.id == self.client_id,
             _ => false,
         }) {
+            let (uplink_device_id, mac_address, name) = match client {
+                ClientOverview::Wireless(c) => {
+                    (c.uplink_device_id, &c.mac_address, c.base.name.as_deref())
+                }
+                ClientOverview::Wired(c) => {
+                    (c.uplink_device_id, &c.mac_address, c.base.name.as_deref())
+                }
+                _ => return,
+            };
+            let chain = self.app_state.uplink_chain(uplink_device_id);
+            let path_height = chain.len() as u16 + 3;
+
+            let rows = vec![
+                Constraint::Length(11),          // Connection info
+                Constraint::Length(12),          // Device/Radio info or Port status
+                Constraint::Length(path_height), // Uplink path
+                Constraint::Min(0),              // Network stats and charts
+            ];
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
-                .constraints(
-                    [
-                        Constraint::Length(9),  // Connection info
-                        Constraint::Length(12), // Device/Radio info or Port status
-                        Constraint::Min(0),     // Network stats and charts
-                    ]
-                    .as_ref(),
-                )
+                .constraints(scale_fixed_rows(rows, area.height, info_share))
                 .split(area);
 
             match client {
@@ -52,9 +66,46 @@ impl<'a> ClientStatsView<'a> {
                 }
                 _ => {}
             }
+
+            let name = self.app_state.aliases.display_name(mac_address, name);
+            self.render_uplink_path(f, chunks[2], name, &chain);
         }
     }
 
+    fn render_uplink_path(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        client_name: String,
+        chain: &[&DeviceOverview],
+    ) {
+        let mut lines = vec![Line::from(Span::styled(
+            client_name,
+            Style::default().add_modifier(Modifier::BOLD),
+        ))];
+        for (depth, device) in chain.iter().enumerate() {
+            let state_style = match device.state {
+                DeviceState::Online => Style::default().fg(Color::Green),
+                DeviceState::Offline => Style::default().fg(Color::Red),
+                _ => Style::default().fg(Color::Yellow),
+            };
+            let mut spans = vec![
+                Span::raw(format!("{}└─ ", "   ".repeat(depth))),
+                Span::styled(&device.name, Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(" ({}) ", device.model)),
+                Span::styled(format!("{:?}", device.state), state_style),
+            ];
+            if self.app_state.is_gateway(device.id) {
+                spans.push(Span::styled(" gateway", Style::default().fg(Color::Cyan)));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        let path = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title("Uplink Path"));
+        f.render_widget(path, area);
+    }
+
     fn format_duration(connected_at: DateTime<Utc>) -> (String, Style) {
         let duration = Utc::now().signed_duration_since(connected_at);
         let hours = duration.num_hours();
@@ -89,7 +140,9 @@ impl<'a> ClientStatsView<'a> {
             Line::from(vec![
                 Span::styled("Name: ", Style::default()),
                 Span::styled(
-                    client.base.name.as_deref().unwrap_or("Unnamed"),
+                    self.app_state
+                        .aliases
+                        .display_name(&client.mac_address, client.base.name.as_deref()),
                     Style::default().add_modifier(Modifier::BOLD),
                 ),
                 Span::raw(" ("),
@@ -100,6 +153,17 @@ impl<'a> ClientStatsView<'a> {
                 Span::styled("MAC Address: ", Style::default()),
                 Span::styled(&client.mac_address, Style::default()),
             ]),
+            Line::from(vec![
+                Span::styled("Vendor: ", Style::default()),
+                Span::styled(oui::vendor(&client.mac_address), Style::default()),
+            ]),
+            Line::from(vec![
+                Span::styled("OS / Category: ", Style::default()),
+                Span::styled(
+                    "not reported by the UniFi Network API",
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]),
             Line::from(vec![
                 Span::styled("IP Address: ", Style::default()),
                 Span::styled(
@@ -123,6 +187,15 @@ impl<'a> ClientStatsView<'a> {
                 Span::styled("Session Duration: ", Style::default()),
                 Span::styled(duration, duration_style),
             ]),
+            // The Integration API's client overview stops at MAC, IP and uplink; PHY rates and
+            // RSSI are only in the legacy stat/sta endpoint, which unifi-rs does not wrap.
+            Line::from(vec![
+                Span::styled("PHY Rate: ", Style::default()),
+                Span::styled(
+                    "not reported by the UniFi Network API",
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]),
         ];
 
         let connection_block = Block::default()
@@ -148,7 +221,9 @@ impl<'a> ClientStatsView<'a> {
             Line::from(vec![
                 Span::styled("Name: ", Style::default()),
                 Span::styled(
-                    client.base.name.as_deref().unwrap_or("Unnamed"),
+                    self.app_state
+                        .aliases
+                        .display_name(&client.mac_address, client.base.name.as_deref()),
                     Style::default().add_modifier(Modifier::BOLD),
                 ),
                 Span::raw(" ("),
@@ -159,6 +234,17 @@ impl<'a> ClientStatsView<'a> {
                 Span::styled("MAC Address: ", Style::default()),
                 Span::styled(&client.mac_address, Style::default()),
             ]),
+            Line::from(vec![
+                Span::styled("Vendor: ", Style::default()),
+                Span::styled(oui::vendor(&client.mac_address), Style::default()),
+            ]),
+            Line::from(vec![
+                Span::styled("OS / Category: ", Style::default()),
+                Span::styled(
+                    "not reported by the UniFi Network API",
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]),
             Line::from(vec![
                 Span::styled("IP Address: ", Style::default()),
                 Span::styled(
@@ -260,13 +346,10 @@ impl<'a> ClientStatsView<'a> {
                         .radios
                         .iter()
                         .map(|radio| {
-                            let freq =
-                                radio.frequency_ghz.as_ref().map_or("Unknown", |f| match f {
-                                    FrequencyBand::Band2_4GHz => "2.4 GHz",
-                                    FrequencyBand::Band5GHz => "5 GHz",
-                                    FrequencyBand::Band6GHz => "6 GHz",
-                                    FrequencyBand::Band60GHz => "60 GHz",
-                                });
+                            let freq = radio
+                                .frequency_ghz
+                                .as_ref()
+                                .map_or("Unknown", frequency_label);
 
                             let channel = radio.channel.map_or("--".to_string(), |c| c.to_string());
                             let width = radio
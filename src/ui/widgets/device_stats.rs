@@ -1,5 +1,11 @@
+use crate::probe::format_latency;
 use crate::state::AppState;
+use crate::ui::widgets::chart::{
+    cursor_dataset, cursor_line, focus_border, render_cursor_legend, time_labels, ChartCursor,
+    ChartWindow, YScale,
+};
 use crate::ui::widgets::format_network_speed;
+use chrono::{DateTime, Local, Utc};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::symbols;
@@ -9,12 +15,16 @@ use ratatui::widgets::{
 };
 use ratatui::Frame;
 use unifi_rs::common::{FrequencyBand, PortState, WlanStandard};
-use unifi_rs::device::DeviceState;
+use unifi_rs::device::{DeviceDetails, DeviceState};
 use uuid::Uuid;
 
 pub struct DeviceStatsView {
     pub device_id: Uuid,
     pub current_tab: usize,
+    pub cursor: Option<ChartCursor>,
+    pub window: ChartWindow,
+    /// Performance chart that full-screen mode expands: 0 link speed, 1 latency.
+    pub focus: usize,
 }
 
 impl DeviceStatsView {
@@ -22,9 +32,85 @@ impl DeviceStatsView {
         Self {
             device_id,
             current_tab: initial_tab,
+            cursor: None,
+            window: ChartWindow::default(),
+            focus: 0,
+        }
+    }
+
+    pub fn toggle_focus(&mut self, app_state: &AppState) {
+        if app_state.latency.is_some() {
+            self.focus = 1 - self.focus;
         }
     }
 
+    pub fn render_focused_chart(&self, f: &mut Frame, area: Rect, app_state: &AppState) {
+        match self.focus {
+            1 => self.render_latency(f, area, app_state),
+            _ => self.render_link_history(f, area, app_state),
+        }
+    }
+
+    /// X axis bounds and labels for `len` visible samples. Unzoomed charts keep
+    /// the fixed five-minute scale so they fill up from the left as data arrives.
+    fn time_axis(
+        &self,
+        len: usize,
+        span: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    ) -> ([f64; 2], Vec<Line<'static>>) {
+        match (self.window.width, span) {
+            (Some(_), Some((first, last))) => (
+                [0.0, len.saturating_sub(1) as f64],
+                time_labels(first, last),
+            ),
+            _ => ([0.0, 59.0], vec![Line::from("5m ago"), Line::from("now")]),
+        }
+    }
+
+    /// Number of samples the cursor can move across on the performance tab.
+    pub fn history_len(&self, app_state: &AppState) -> usize {
+        let throughput = app_state
+            .network_history
+            .get(&self.device_id)
+            .map_or(0, |h| h.len());
+        let latency = app_state
+            .latency
+            .as_ref()
+            .and_then(|monitor| monitor.history(&self.device_id))
+            .map_or(0, |h| h.len());
+        throughput.max(latency)
+    }
+
+    fn is_access_point(device: &DeviceDetails) -> bool {
+        device
+            .features
+            .as_ref()
+            .map(|f| f.access_point.is_some())
+            .unwrap_or(false)
+    }
+
+    fn tab_titles(device: &DeviceDetails) -> Vec<&'static str> {
+        if Self::is_access_point(device) {
+            vec!["Overview", "Performance", "Wireless", "Ports", "Events"]
+        } else {
+            vec!["Overview", "Performance", "Ports", "Events"]
+        }
+    }
+
+    /// Number of tabs for this device, for cycling through them.
+    pub fn tab_count(&self, app_state: &AppState) -> usize {
+        app_state
+            .device_details
+            .get(&self.device_id)
+            .map_or(1, |device| Self::tab_titles(device).len())
+    }
+
+    /// Title of the tab being shown, for the breadcrumb.
+    pub fn tab_title(&self, app_state: &AppState) -> Option<&'static str> {
+        let device = app_state.device_details.get(&self.device_id)?;
+        Self::tab_titles(device).get(self.current_tab).copied()
+    }
+
     pub fn render(&self, f: &mut Frame, area: Rect, app_state: &AppState) {
         let device = if let Some(device) = app_state.device_details.get(&self.device_id) {
             device
@@ -60,28 +146,26 @@ impl DeviceStatsView {
             }
         });
 
-        let header_text = vec![Line::from(vec![
+        let mut header_spans = vec![
             Span::styled(title, Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(" | "),
             Span::styled(status_text, status_style),
             Span::raw(" | "),
             Span::raw(format!("Uptime: {}", uptime)),
-        ])];
+        ];
+        if app_state.refreshing_devices.contains(&self.device_id) {
+            header_spans.push(Span::raw(" | "));
+            header_spans.push(Span::styled(
+                "Refreshing…",
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        let header_text = vec![Line::from(header_spans)];
 
         let header = Paragraph::new(header_text).block(Block::default().borders(Borders::ALL));
         f.render_widget(header, chunks[0]);
 
-        let is_access_point = device
-            .features
-            .as_ref()
-            .map(|f| f.access_point.is_some())
-            .unwrap_or(false);
-
-        let titles = if is_access_point {
-            vec!["Overview", "Performance", "Wireless", "Ports"]
-        } else {
-            vec!["Overview", "Performance", "Ports"]
-        };
+        let titles = Self::tab_titles(device);
 
         let tabs = Tabs::new(titles.iter().map(|t| Line::from(*t)).collect::<Vec<_>>())
             .block(Block::default().borders(Borders::ALL))
@@ -91,21 +175,12 @@ impl DeviceStatsView {
 
         f.render_widget(tabs, chunks[1]);
 
-        match self.current_tab {
-            0 => self.render_overview(f, chunks[2], app_state),
-            1 => self.render_performance(f, chunks[2], app_state),
-            2 => {
-                if is_access_point {
-                    self.render_wireless(f, chunks[2], app_state)
-                } else {
-                    self.render_ports(f, chunks[2], app_state)
-                }
-            }
-            3 => {
-                if is_access_point {
-                    self.render_ports(f, chunks[2], app_state)
-                }
-            }
+        match titles.get(self.current_tab).copied() {
+            Some("Overview") => self.render_overview(f, chunks[2], app_state),
+            Some("Performance") => self.render_performance(f, chunks[2], app_state),
+            Some("Wireless") => self.render_wireless(f, chunks[2], app_state),
+            Some("Ports") => self.render_ports(f, chunks[2], app_state),
+            Some("Events") => self.render_events(f, chunks[2], app_state),
             _ => {}
         }
     }
@@ -242,14 +317,18 @@ impl DeviceStatsView {
     }
 
     fn render_performance(&self, f: &mut Frame, area: Rect, app_state: &AppState) {
+        let latency_height = if app_state.latency.is_some() { 10 } else { 0 };
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3), // Current throughput
-                Constraint::Min(0),    // Graph
+                Constraint::Length(3),              // Current throughput
+                Constraint::Min(0),                 // Graph
+                Constraint::Length(latency_height), // Latency probe
             ])
             .split(area);
 
+        self.render_latency(f, chunks[2], app_state);
+
         if let Some(stats) = app_state.device_stats.get(&self.device_id) {
             if let Some(uplink) = &stats.uplink {
                 let current_text = vec![Line::from(vec![
@@ -271,66 +350,186 @@ impl DeviceStatsView {
             }
         }
 
-        if let Some(history) = app_state.network_history.get(&self.device_id) {
-            let history_vec: Vec<_> = history.iter().collect();
+        self.render_link_history(f, chunks[1], app_state);
+    }
 
-            if !history_vec.is_empty() {
-                let tx_data: Vec<(f64, f64)> = history_vec
-                    .iter()
-                    .enumerate()
-                    .map(|(i, point)| (i as f64, point.tx_rate as f64))
-                    .collect();
+    fn render_link_history(&self, f: &mut Frame, area: Rect, app_state: &AppState) {
+        let Some(history) = app_state.network_history.get(&self.device_id) else {
+            return;
+        };
+        let range = self.window.range(history.len());
+        let history_vec: Vec<_> = history.range(range).collect();
+        if history_vec.is_empty() {
+            return;
+        }
 
-                let rx_data: Vec<(f64, f64)> = history_vec
-                    .iter()
-                    .enumerate()
-                    .map(|(i, point)| (i as f64, point.rx_rate as f64))
-                    .collect();
+        let tx_data: Vec<(f64, f64)> = history_vec
+            .iter()
+            .enumerate()
+            .map(|(i, point)| (i as f64, point.tx_rate as f64))
+            .collect();
+
+        let rx_data: Vec<(f64, f64)> = history_vec
+            .iter()
+            .enumerate()
+            .map(|(i, point)| (i as f64, point.rx_rate as f64))
+            .collect();
+
+        let max_rate = history_vec
+            .iter()
+            .map(|point| point.tx_rate.max(point.rx_rate) as f64)
+            .fold(0.0, f64::max);
+
+        let y_scale = YScale::new(max_rate);
+        let y_labels = y_scale.labels(|bps| format_network_speed(bps as i64));
+
+        let span = history_vec.first().zip(history_vec.last());
+        let (x_bounds, x_labels) = self.time_axis(
+            history_vec.len(),
+            span.map(|(first, last)| (first.timestamp, last.timestamp)),
+        );
+        let cursor = self.cursor.and_then(|c| c.index(history_vec.len()));
+        let cursor_points = cursor.map(|i| cursor_line(i as f64, y_scale.max()));
+
+        let mut datasets = vec![
+            Dataset::default()
+                .name("TX")
+                .marker(symbols::Marker::Dot)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Green))
+                .data(&tx_data),
+            Dataset::default()
+                .name("RX")
+                .marker(symbols::Marker::Dot)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Blue))
+                .data(&rx_data),
+        ];
+        if let Some(points) = &cursor_points {
+            datasets.push(cursor_dataset(points));
+        }
 
-                let max_rate = history_vec
-                    .iter()
-                    .map(|point| point.tx_rate.max(point.rx_rate) as f64)
-                    .fold(0.0, f64::max);
-
-                let max_label = format_network_speed(max_rate as i64);
-                let y_labels = [Line::from("0"), Line::from(max_label)];
-
-                let datasets = vec![
-                    Dataset::default()
-                        .name("TX")
-                        .marker(symbols::Marker::Dot)
-                        .graph_type(GraphType::Line)
-                        .style(Style::default().fg(Color::Green))
-                        .data(&tx_data),
-                    Dataset::default()
-                        .name("RX")
-                        .marker(symbols::Marker::Dot)
-                        .graph_type(GraphType::Line)
-                        .style(Style::default().fg(Color::Blue))
-                        .data(&rx_data),
-                ];
+        let chart = Chart::new(datasets)
+            .block(
+                Block::default()
+                    .title(self.window.title("Link Speed History"))
+                    .borders(Borders::ALL)
+                    .border_style(focus_border(self.focus == 0)),
+            )
+            .x_axis(
+                Axis::default()
+                    .title("Time")
+                    .bounds(x_bounds)
+                    .labels(x_labels),
+            )
+            .y_axis(
+                Axis::default()
+                    .title("Speed")
+                    .labels(y_labels)
+                    .bounds(y_scale.bounds()),
+            );
 
-                let chart = Chart::new(datasets)
-                    .block(
-                        Block::default()
-                            .title("Link Speed History")
-                            .borders(Borders::ALL),
-                    )
-                    .x_axis(
-                        Axis::default()
-                            .title("Time")
-                            .bounds([0.0, 59.0])
-                            .labels(vec![Line::from("5m ago"), Line::from("now")]),
-                    )
-                    .y_axis(
-                        Axis::default()
-                            .title("Speed")
-                            .labels(y_labels)
-                            .bounds([0.0, max_rate * 1.1]),
-                    );
-
-                f.render_widget(chart, chunks[1]);
-            }
+        f.render_widget(chart, area);
+
+        if let Some(point) = cursor.map(|i| history_vec[i]) {
+            render_cursor_legend(
+                f,
+                area,
+                vec![
+                    Line::from(
+                        point
+                            .timestamp
+                            .with_timezone(&Local)
+                            .format("%H:%M:%S")
+                            .to_string(),
+                    ),
+                    Line::styled(
+                        format!("TX {}", format_network_speed(point.tx_rate)),
+                        Style::default().fg(Color::Green),
+                    ),
+                    Line::styled(
+                        format!("RX {}", format_network_speed(point.rx_rate)),
+                        Style::default().fg(Color::Blue),
+                    ),
+                ],
+            );
+        }
+    }
+
+    fn render_latency(&self, f: &mut Frame, area: Rect, app_state: &AppState) {
+        let Some(history) = app_state
+            .latency
+            .as_ref()
+            .and_then(|monitor| monitor.history(&self.device_id))
+        else {
+            return;
+        };
+
+        let visible: Vec<_> = history.range(self.window.range(history.len())).collect();
+        let data: Vec<(f64, f64)> = visible
+            .iter()
+            .enumerate()
+            .filter_map(|(i, sample)| sample.latency.map(|l| (i as f64, l.as_secs_f64() * 1000.0)))
+            .collect();
+        let timeouts = history.iter().filter(|s| s.latency.is_none()).count();
+        let max_ms = data.iter().map(|(_, ms)| *ms).fold(1.0, f64::max);
+        let y_scale = YScale::new(max_ms);
+        let y_labels = y_scale.labels(|ms| format!("{} ms", ms));
+
+        let title = format!(
+            "Latency from this host (last: {}, {} timeouts)",
+            format_latency(history.back().and_then(|s| s.latency)),
+            timeouts
+        );
+
+        let span = visible.first().zip(visible.last());
+        let (x_bounds, x_labels) = self.time_axis(
+            visible.len(),
+            span.map(|(first, last)| (first.timestamp, last.timestamp)),
+        );
+        let cursor = self.cursor.and_then(|c| c.index(visible.len()));
+        let cursor_points = cursor.map(|i| cursor_line(i as f64, y_scale.max()));
+
+        let mut datasets = vec![Dataset::default()
+            .name("RTT")
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Magenta))
+            .data(&data)];
+        if let Some(points) = &cursor_points {
+            datasets.push(cursor_dataset(points));
+        }
+
+        let chart = Chart::new(datasets)
+            .block(
+                Block::default()
+                    .title(self.window.title(&title))
+                    .borders(Borders::ALL)
+                    .border_style(focus_border(self.focus == 1)),
+            )
+            .x_axis(Axis::default().bounds(x_bounds).labels(x_labels))
+            .y_axis(Axis::default().labels(y_labels).bounds(y_scale.bounds()));
+
+        f.render_widget(chart, area);
+
+        if let Some(sample) = cursor.map(|i| visible[i]) {
+            render_cursor_legend(
+                f,
+                area,
+                vec![
+                    Line::from(
+                        sample
+                            .timestamp
+                            .with_timezone(&Local)
+                            .format("%H:%M:%S")
+                            .to_string(),
+                    ),
+                    Line::styled(
+                        format!("RTT {}", format_latency(sample.latency)),
+                        Style::default().fg(Color::Magenta),
+                    ),
+                ],
+            );
         }
     }
 
@@ -490,4 +689,53 @@ impl DeviceStatsView {
             }
         }
     }
+
+    // The Integration API has no event feed, so these are the changes unifi-tui
+    // noticed between refreshes (state changes and alerts), newest first.
+    fn render_events(&self, f: &mut Frame, area: Rect, app_state: &AppState) {
+        let block = Block::default().title("Events").borders(Borders::ALL);
+        let Some(events) = app_state
+            .device_events
+            .get(&self.device_id)
+            .filter(|events| !events.is_empty())
+        else {
+            let empty = Paragraph::new(vec![
+                Line::from("No events seen for this device since unifi-tui started."),
+                Line::from(Span::styled(
+                    "Controller events are not exposed by the UniFi Network API yet.",
+                    Style::default().fg(Color::DarkGray),
+                )),
+            ])
+            .block(block);
+            f.render_widget(empty, area);
+            return;
+        };
+
+        let header = Row::new(vec!["Time", "Event", "Details"])
+            .style(Style::default().add_modifier(Modifier::BOLD));
+        let rows: Vec<Row> = events
+            .iter()
+            .rev()
+            .map(|event| {
+                let style = match event.kind {
+                    "alert" => Style::default().fg(Color::Red),
+                    "device_state" => Style::default().fg(Color::Yellow),
+                    _ => Style::default(),
+                };
+                Row::new(vec![
+                    Cell::from(event.at.format("%Y-%m-%d %H:%M:%S").to_string()),
+                    Cell::from(event.kind).style(style),
+                    Cell::from(event.message.clone()),
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(19),
+            Constraint::Length(14),
+            Constraint::Min(0),
+        ];
+        let table = Table::new(rows, widths).header(header).block(block);
+        f.render_widget(table, area);
+    }
 }
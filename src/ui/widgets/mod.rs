@@ -1,8 +1,12 @@
+pub mod chart;
 pub mod client_stats;
 pub mod device_stats;
 
 pub use device_stats::DeviceStatsView;
 
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+
 pub fn format_network_speed(bps: i64) -> String {
     if bps >= 1_000_000_000 {
         format!("{:.2} Gbps", bps as f64 / 1_000_000_000.0)
@@ -14,3 +18,33 @@ pub fn format_network_speed(bps: i64) -> String {
         format!("{} bps", bps)
     }
 }
+
+/// Splits `text` into spans with every case-insensitive occurrence of `query`
+/// highlighted, for marking search matches in table cells.
+pub fn highlight_matches(text: String, query: &str) -> Line<'static> {
+    let lower = text.to_lowercase();
+    let query = query.to_lowercase();
+    // Lowercasing can change byte lengths outside ASCII, which would put the
+    // match offsets out of step with the original text.
+    if query.is_empty() || lower.len() != text.len() {
+        return Line::from(text);
+    }
+
+    let style = Style::default().fg(Color::Black).bg(Color::Yellow);
+    let mut spans = Vec::new();
+    let mut start = 0;
+    for (index, matched) in lower.match_indices(&query) {
+        if index > start {
+            spans.push(Span::raw(text[start..index].to_string()));
+        }
+        spans.push(Span::styled(
+            text[index..index + matched.len()].to_string(),
+            style,
+        ));
+        start = index + matched.len();
+    }
+    if start < text.len() {
+        spans.push(Span::raw(text[start..].to_string()));
+    }
+    Line::from(spans)
+}
@@ -0,0 +1,66 @@
+use std::io;
+use std::process::{Command, Stdio};
+
+/// The UniFi Network web UI of the controller serving the Integration API at `api_url`.
+///
+/// Only the scheme and host of `api_url` are kept, since the API lives under a path
+/// such as `/proxy/network/integrations`. Consoles running UniFi OS serve the app under
+/// `/network/`; a standalone Network Application redirects from its root. There is no
+/// deep link to a device or client: the web UI addresses sites by a short name that
+/// the API does not return.
+pub fn network_app_url(api_url: &str) -> Option<String> {
+    let (scheme, rest) = api_url.split_once("://")?;
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    if host.is_empty() {
+        return None;
+    }
+    let app_path = if path.starts_with("proxy/network") {
+        "/network/"
+    } else {
+        "/"
+    };
+    Some(format!("{}://{}{}", scheme, host, app_path))
+}
+
+/// Opens `url` with the platform's default handler without waiting for it.
+pub fn open(url: &str) -> io::Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+
+    command
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unifi_os_links_to_network_app() {
+        assert_eq!(
+            network_app_url("https://192.168.1.1/proxy/network/integrations").as_deref(),
+            Some("https://192.168.1.1/network/")
+        );
+    }
+
+    #[test]
+    fn standalone_controller_links_to_root() {
+        assert_eq!(
+            network_app_url("https://unifi.local:8443/integrations/").as_deref(),
+            Some("https://unifi.local:8443/")
+        );
+        assert_eq!(network_app_url("unifi.local"), None);
+    }
+}